@@ -0,0 +1,714 @@
+//! Pure-Rust arbitrary-precision transcendentals (`high_precision_pure` feature).
+//!
+//! Inputs are taken as exact rationals and evaluated in fixed point: a value `x`
+//! is held as the integer `x · 10^p`. Each function reduces its argument into a
+//! range where a Taylor or `atanh` series converges quickly, sums the series,
+//! and rounds the result to the number of decimal digits implied by
+//! `get_default_precision()`. When a result comes out with fewer significant
+//! digits than requested (cancellation near a root, tiny inputs), it is
+//! recomputed with a larger `p`.
+//!
+//! Every function returns `None` when it can't produce a result, so callers can
+//! fall back to f64 the same way they do when `rug` conversion fails.
+
+use crate::NumericValue;
+use bigdecimal::num_bigint::BigInt;
+use bigdecimal::{BigDecimal, RoundingMode};
+use num_traits::{One, Signed, ToPrimitive, Zero};
+use std::num::NonZeroU64;
+
+/// Extra digits carried beyond the requested precision
+const GUARD_DIGITS: u64 = 10;
+
+/// Give up (and fall back to f64) when argument reduction needs more digits than this
+const MAX_EXTRA_DIGITS: u64 = 10_000;
+
+/// Largest power of two `exp` will scale by (2^16384 is past any float format)
+const MAX_EXP_BITS: i64 = 1 << 14;
+
+/// Roots above this degree go through exp(ln(x) / n) instead of an integer root
+const MAX_INTEGER_ROOT: u32 = 64;
+
+/// Attempts at increasing working precision before giving up on a result
+const MAX_ATTEMPTS: usize = 4;
+
+/// log10(2), used to convert between bits and decimal digits
+const LOG10_2: f64 = std::f64::consts::LOG10_2;
+
+fn pow10(n: u64) -> BigInt {
+    BigInt::from(10).pow(n as u32)
+}
+
+/// Lower estimate of the number of decimal digits in `n`
+fn digit_count(n: &BigInt) -> u64 {
+    (n.bits() as f64 * LOG10_2) as u64
+}
+
+/// Decimal digits equivalent to a precision in bits
+fn digits_for_bits(bits: u32) -> u64 {
+    ((f64::from(bits) * LOG10_2).ceil() as u64).max(1)
+}
+
+/// Exact value of a finite `NumericValue` as `num / den` with `den > 0`
+struct Exact {
+    num: BigInt,
+    den: BigInt,
+}
+
+impl Exact {
+    fn of(value: &NumericValue) -> Option<Exact> {
+        let (num, den) = match value {
+            NumericValue::Rational(r, _) => (BigInt::from(*r.numer()), BigInt::from(*r.denom())),
+            NumericValue::BigRational(r) => (r.numer().clone(), r.denom().clone()),
+            NumericValue::Decimal(d) => (BigInt::from(d.mantissa()), pow10(u64::from(d.scale()))),
+            NumericValue::BigDecimal(bd) => {
+                let (mantissa, scale) = bd.as_bigint_and_exponent();
+                if scale >= 0 {
+                    (mantissa, pow10(scale as u64))
+                } else {
+                    (mantissa * pow10(scale.unsigned_abs()), BigInt::one())
+                }
+            }
+            NumericValue::NegativeZero => (BigInt::zero(), BigInt::one()),
+            _ => return None,
+        };
+        Some(Exact { num, den })
+    }
+
+    fn is_zero(&self) -> bool {
+        self.num.is_zero()
+    }
+
+    fn is_negative(&self) -> bool {
+        self.num.is_negative()
+    }
+
+    /// Rough base-2 logarithm of the magnitude (within a couple of bits)
+    fn log2_estimate(&self) -> i64 {
+        self.num.bits() as i64 - self.den.bits() as i64
+    }
+
+    /// Digits before the decimal point, plus one
+    fn int_digits(&self) -> u64 {
+        ((self.log2_estimate() as f64 * LOG10_2).max(0.0) as u64) + 1
+    }
+
+    /// Zeros after the decimal point before the first significant digit of a
+    /// magnitude below one (0 otherwise)
+    fn leading_zeros(&self) -> u64 {
+        ((-self.log2_estimate()).max(0) as f64 * LOG10_2) as u64
+    }
+
+    fn is_integer(&self) -> bool {
+        (&self.num % &self.den).is_zero()
+    }
+
+    fn fixed(&self, fx: &Fixed) -> BigInt {
+        &self.num * &fx.one / &self.den
+    }
+}
+
+/// Fixed-point arithmetic with `p` digits after the decimal point
+struct Fixed {
+    p: u64,
+    one: BigInt,
+}
+
+impl Fixed {
+    fn new(p: u64) -> Fixed {
+        Fixed { p, one: pow10(p) }
+    }
+
+    fn mul(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        a * b / &self.one
+    }
+
+    fn div(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        a * &self.one / b
+    }
+
+    fn sqrt(&self, a: &BigInt) -> BigInt {
+        (a * &self.one).sqrt()
+    }
+
+    /// Round `a / b` to the nearest integer
+    fn round_div(&self, a: &BigInt, b: &BigInt) -> BigInt {
+        let half = b / 2u32;
+        if a.is_negative() {
+            (a - half) / b
+        } else {
+            (a + half) / b
+        }
+    }
+
+    fn to_bigdecimal(&self, a: BigInt) -> BigDecimal {
+        BigDecimal::new(a, self.p as i64)
+    }
+
+    /// atanh(1/n) = Σ 1 / ((2k+1) n^(2k+1))
+    fn atanh_inv(&self, n: u32) -> BigInt {
+        let n2 = BigInt::from(n) * n;
+        let mut power = &self.one / n;
+        let mut sum = power.clone();
+        let mut k = 1u32;
+        while !power.is_zero() {
+            power /= &n2;
+            sum += &power / (2 * k + 1);
+            k += 1;
+        }
+        sum
+    }
+
+    /// atan(1/n) = Σ (-1)^k / ((2k+1) n^(2k+1))
+    fn atan_inv(&self, n: u32) -> BigInt {
+        let n2 = BigInt::from(n) * n;
+        let mut power = &self.one / n;
+        let mut sum = power.clone();
+        let mut k = 1u32;
+        while !power.is_zero() {
+            power /= &n2;
+            if k % 2 == 1 {
+                sum -= &power / (2 * k + 1);
+            } else {
+                sum += &power / (2 * k + 1);
+            }
+            k += 1;
+        }
+        sum
+    }
+
+    /// ln 2 = 2 atanh(1/3)
+    fn ln2(&self) -> BigInt {
+        self.atanh_inv(3) * 2u32
+    }
+
+    /// π = 16 atan(1/5) - 4 atan(1/239) (Machin)
+    fn pi(&self) -> BigInt {
+        self.atan_inv(5) * 16u32 - self.atan_inv(239) * 4u32
+    }
+
+    /// e^x for any fixed-point x, scaled back by powers of two
+    fn exp(&self, x: &BigInt) -> Option<BigDecimal> {
+        // x = n ln2 + r with |r| <= ln2 / 2
+        let ln2 = self.ln2();
+        let n = self.round_div(x, &ln2).to_i64()?;
+        if n.abs() > MAX_EXP_BITS {
+            return None;
+        }
+        let r = x - &ln2 * n;
+
+        let mut term = self.one.clone();
+        let mut sum = term.clone();
+        let mut k = 1u32;
+        while !term.is_zero() {
+            term = self.mul(&term, &r) / k;
+            sum += &term;
+            k += 1;
+        }
+
+        // Multiply by 2^n exactly: 2^-n = 5^n / 10^n
+        Some(if n >= 0 {
+            self.to_bigdecimal(sum << n as usize)
+        } else {
+            let m = n.unsigned_abs();
+            BigDecimal::new(sum * BigInt::from(5).pow(m as u32), (self.p + m) as i64)
+        })
+    }
+
+    /// ln x for an exact positive x
+    fn ln(&self, x: &Exact) -> BigInt {
+        // x = y 2^k with y close to 1
+        let k = x.log2_estimate();
+        let mut num = &x.num * &self.one;
+        let mut den = x.den.clone();
+        if k >= 0 {
+            den <<= k as usize;
+        } else {
+            num <<= k.unsigned_abs() as usize;
+        }
+        let y = num / den;
+
+        // ln y = 2 atanh(z), z = (y - 1) / (y + 1)
+        let z = self.div(&(&y - &self.one), &(&y + &self.one));
+        let z2 = self.mul(&z, &z);
+        let mut power = z.clone();
+        let mut sum = z;
+        let mut k2 = 1u32;
+        while !power.is_zero() {
+            power = self.mul(&power, &z2);
+            sum += &power / (2 * k2 + 1);
+            k2 += 1;
+        }
+        sum * 2u32 + self.ln2() * k
+    }
+
+    /// sin and cos of |r| <= π/4
+    fn sin_cos(&self, r: &BigInt) -> (BigInt, BigInt) {
+        let r2 = self.mul(r, r);
+
+        let mut term = r.clone();
+        let mut sin = term.clone();
+        let mut k = 1u32;
+        while !term.is_zero() {
+            term = -self.mul(&term, &r2) / ((2 * k) * (2 * k + 1));
+            sin += &term;
+            k += 1;
+        }
+
+        let mut term = self.one.clone();
+        let mut cos = term.clone();
+        let mut k = 1u32;
+        while !term.is_zero() {
+            term = -self.mul(&term, &r2) / ((2 * k - 1) * (2 * k));
+            cos += &term;
+            k += 1;
+        }
+        (sin, cos)
+    }
+
+    /// Reduce x by multiples of π/2: returns the quadrant (0..4) and the remainder
+    fn quadrant(&self, x: &BigInt) -> (u8, BigInt) {
+        let half_pi = self.pi() / 2u32;
+        let k = self.round_div(x, &half_pi);
+        let r = x - &k * &half_pi;
+        let q = ((k % 4u32) + 4u32) % 4u32;
+        (q.to_u8().unwrap_or(0), r)
+    }
+
+    /// Fixed-point form of a BigDecimal (truncated to `p` digits)
+    fn of_bigdecimal(&self, v: &BigDecimal) -> BigInt {
+        let (mantissa, scale) = v.as_bigint_and_exponent();
+        let shift = self.p as i64 - scale;
+        if shift >= 0 {
+            mantissa * pow10(shift as u64)
+        } else {
+            mantissa / pow10(shift.unsigned_abs())
+        }
+    }
+
+    /// e^|x| and e^-|x| in fixed point
+    fn exp_pair(&self, x: &BigInt) -> Option<(BigInt, BigInt)> {
+        let up = self.of_bigdecimal(&self.exp(&x.abs())?);
+        let down = self.div(&self.one, &up);
+        Some((up, down))
+    }
+
+    fn atan(&self, x: &BigInt) -> BigInt {
+        let negative = x.is_negative();
+        let mut x = x.abs();
+
+        // atan x = π/2 - atan(1/x) for x > 1
+        let complement = x > self.one;
+        if complement {
+            x = self.div(&self.one, &x);
+        }
+
+        // Halve the angle twice: atan x = 2 atan(x / (1 + sqrt(1 + x²)))
+        for _ in 0..2 {
+            let root = self.sqrt(&(&self.one + self.mul(&x, &x)));
+            x = self.div(&x, &(&self.one + root));
+        }
+
+        let x2 = self.mul(&x, &x);
+        let mut power = x.clone();
+        let mut sum = x;
+        let mut k = 1u32;
+        while !power.is_zero() {
+            power = -self.mul(&power, &x2);
+            sum += &power / (2 * k + 1);
+            k += 1;
+        }
+        let mut result = sum * 4u32;
+
+        if complement {
+            result = self.pi() / 2u32 - result;
+        }
+        if negative { -result } else { result }
+    }
+}
+
+/// Evaluate `f` until its result carries the requested number of digits, or
+/// `None` (so the caller falls back) if it never does
+fn evaluate(
+    bits: u32,
+    extra: u64,
+    f: impl Fn(&Fixed) -> Option<BigDecimal>,
+) -> Option<NumericValue> {
+    if extra > MAX_EXTRA_DIGITS {
+        return None;
+    }
+    let digits = digits_for_bits(bits);
+    let target = digits + GUARD_DIGITS;
+    let mut p = target + extra;
+    for _ in 0..MAX_ATTEMPTS {
+        let value = f(&Fixed::new(p))?;
+        let significant = if value.is_zero() { 0 } else { value.digits() };
+        if significant >= target {
+            // Round half-even on the magnitude (`with_prec` rounds negative ties upward)
+            let precision = NonZeroU64::new(digits).unwrap_or(NonZeroU64::MIN);
+            return Some(NumericValue::BigDecimal(
+                value
+                    .with_precision_round(precision, RoundingMode::HalfEven)
+                    .normalized(),
+            ));
+        }
+        p += target - significant;
+    }
+    None
+}
+
+pub(crate) fn sqrt(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    if x.is_negative() {
+        return Some(NumericValue::NaN);
+    }
+    evaluate(bits, x.leading_zeros(), |fx| {
+        Some(fx.to_bigdecimal(fx.sqrt(&x.fixed(fx))))
+    })
+}
+
+pub(crate) fn nth_root(x: &NumericValue, n: u32, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    if x.is_negative() && n.is_multiple_of(2) {
+        return Some(NumericValue::NaN);
+    }
+    let magnitude = Exact {
+        num: x.num.abs(),
+        den: x.den.clone(),
+    };
+    evaluate(bits, x.leading_zeros(), |fx| {
+        let root = if n <= MAX_INTEGER_ROOT {
+            // (x · 10^(pn))^(1/n) = x^(1/n) · 10^p
+            let scaled = &magnitude.num * pow10(fx.p * u64::from(n)) / &magnitude.den;
+            fx.to_bigdecimal(scaled.nth_root(n))
+        } else {
+            fx.exp(&(fx.ln(&magnitude) / n))?
+        };
+        Some(if x.is_negative() { -root } else { root })
+    })
+}
+
+pub(crate) fn exp(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    evaluate(bits, x.int_digits(), |fx| fx.exp(&x.fixed(fx)))
+}
+
+/// e^x - 1, with the digits lost to cancellation carried up front for small x
+pub(crate) fn expm1(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    let extra = x.int_digits() + x.leading_zeros();
+    evaluate(bits, extra, |fx| {
+        Some(fx.exp(&x.fixed(fx))? - BigDecimal::one())
+    })
+}
+
+pub(crate) fn pi(bits: u32) -> Option<NumericValue> {
+    evaluate(bits, 0, |fx| Some(fx.to_bigdecimal(fx.pi())))
+}
+
+/// c·√π, for Γ at half-integers
+pub(crate) fn sqrt_pi_times(c: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let c = Exact::of(c)?;
+    evaluate(bits, c.leading_zeros(), |fx| {
+        let root = fx.sqrt(&fx.pi());
+        Some(fx.to_bigdecimal(root * &c.num / &c.den))
+    })
+}
+
+pub(crate) fn ln(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    log_with(x, bits, |_| None)
+}
+
+/// ln(1 + x), with 1 + x formed exactly so small x keeps its digits
+pub(crate) fn log1p(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    let one_plus = Exact {
+        num: &x.den + &x.num,
+        den: x.den.clone(),
+    };
+    if one_plus.is_negative() {
+        return Some(NumericValue::NaN);
+    }
+    if one_plus.is_zero() {
+        return Some(NumericValue::NegativeInfinity);
+    }
+    evaluate(bits, x.leading_zeros(), |fx| {
+        Some(fx.to_bigdecimal(fx.ln(&one_plus)))
+    })
+}
+
+pub(crate) fn log10(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let ten = Exact {
+        num: BigInt::from(10),
+        den: BigInt::one(),
+    };
+    log_with(x, bits, |fx| Some(fx.ln(&ten)))
+}
+
+pub(crate) fn log2(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    log_with(x, bits, |fx| Some(fx.ln2()))
+}
+
+/// ln x, divided by `ln base` when `base_ln` gives one
+fn log_with(
+    x: &NumericValue,
+    bits: u32,
+    base_ln: impl Fn(&Fixed) -> Option<BigInt>,
+) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    if x.is_negative() {
+        return Some(NumericValue::NaN);
+    }
+    if x.is_zero() {
+        return Some(NumericValue::NegativeInfinity);
+    }
+    evaluate(bits, 0, |fx| {
+        let ln = fx.ln(&x);
+        Some(fx.to_bigdecimal(match base_ln(fx) {
+            Some(divisor) => fx.div(&ln, &divisor),
+            None => ln,
+        }))
+    })
+}
+
+pub(crate) fn sin(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    let extra = x.int_digits() + x.leading_zeros();
+    evaluate(bits, extra, |fx| {
+        let (q, r) = fx.quadrant(&x.fixed(fx));
+        let (sin, cos) = fx.sin_cos(&r);
+        Some(fx.to_bigdecimal(match q {
+            0 => sin,
+            1 => cos,
+            2 => -sin,
+            _ => -cos,
+        }))
+    })
+}
+
+pub(crate) fn cos(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    evaluate(bits, x.int_digits(), |fx| {
+        let (q, r) = fx.quadrant(&x.fixed(fx));
+        let (sin, cos) = fx.sin_cos(&r);
+        Some(fx.to_bigdecimal(match q {
+            0 => cos,
+            1 => -sin,
+            2 => -cos,
+            _ => sin,
+        }))
+    })
+}
+
+pub(crate) fn tan(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    let extra = x.int_digits() + x.leading_zeros();
+    evaluate(bits, extra, |fx| {
+        let (q, r) = fx.quadrant(&x.fixed(fx));
+        let (sin, cos) = fx.sin_cos(&r);
+        let (num, den) = if q % 2 == 0 { (sin, cos) } else { (-cos, sin) };
+        if den.is_zero() {
+            return None;
+        }
+        Some(fx.to_bigdecimal(fx.div(&num, &den)))
+    })
+}
+
+pub(crate) fn atan(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    evaluate(bits, x.leading_zeros(), |fx| {
+        Some(fx.to_bigdecimal(fx.atan(&x.fixed(fx))))
+    })
+}
+
+pub(crate) fn asin(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    arcsine(x, bits, false)
+}
+
+pub(crate) fn acos(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    arcsine(x, bits, true)
+}
+
+/// asin x = atan(x / sqrt(1 - x²)); acos x = π/2 - asin x
+fn arcsine(x: &NumericValue, bits: u32, complement: bool) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    if x.num.abs() > x.den {
+        return Some(NumericValue::NaN);
+    }
+    // 1 - x² computed exactly, so the root is accurate near |x| = 1
+    let den2 = &x.den * &x.den;
+    let one_minus_sq = Exact {
+        num: &den2 - &x.num * &x.num,
+        den: den2,
+    };
+    evaluate(bits, x.leading_zeros(), |fx| {
+        let half_pi = fx.pi() / 2u32;
+        let root = fx.sqrt(&one_minus_sq.fixed(fx));
+        let asin = if root.is_zero() {
+            if x.is_negative() {
+                -&half_pi
+            } else {
+                half_pi.clone()
+            }
+        } else {
+            fx.atan(&fx.div(&x.fixed(fx), &root))
+        };
+        Some(fx.to_bigdecimal(if complement { half_pi - asin } else { asin }))
+    })
+}
+
+pub(crate) fn atan2(y: &NumericValue, x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let (y, x) = (Exact::of(y)?, Exact::of(x)?);
+    // A tiny y/x gives an angle about as small
+    let extra = if x.is_zero() {
+        0
+    } else {
+        y.leading_zeros() + x.int_digits()
+    };
+    evaluate(bits, extra, |fx| {
+        let result = if x.is_zero() {
+            let half_pi = fx.pi() / 2u32;
+            match y.num.sign() {
+                bigdecimal::num_bigint::Sign::Plus => half_pi,
+                bigdecimal::num_bigint::Sign::Minus => -half_pi,
+                bigdecimal::num_bigint::Sign::NoSign => BigInt::zero(),
+            }
+        } else {
+            let ratio = Exact {
+                num: &y.num * &x.den * x.num.signum(),
+                den: &y.den * x.num.abs(),
+            };
+            let angle = fx.atan(&ratio.fixed(fx));
+            if !x.is_negative() {
+                angle
+            } else if y.is_negative() {
+                angle - fx.pi()
+            } else {
+                angle + fx.pi()
+            }
+        };
+        Some(fx.to_bigdecimal(result))
+    })
+}
+
+/// base^exponent = e^(exponent · ln base), with the sign restored for negative
+/// bases and integer exponents
+pub(crate) fn pow(base: &NumericValue, exponent: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let (base, exponent) = (Exact::of(base)?, Exact::of(exponent)?);
+    if base.is_zero() {
+        return Some(if exponent.is_zero() {
+            NumericValue::one()
+        } else if exponent.is_negative() {
+            NumericValue::PositiveInfinity
+        } else {
+            NumericValue::zero()
+        });
+    }
+    let negate = if base.is_negative() {
+        if !exponent.is_integer() {
+            return Some(NumericValue::NaN);
+        }
+        (&exponent.num / &exponent.den) % 2u32 != BigInt::zero()
+    } else {
+        false
+    };
+    let magnitude = Exact {
+        num: base.num.abs(),
+        den: base.den.clone(),
+    };
+
+    // |exponent · ln base| can be large; carry enough digits through the reduction
+    let ln_digits = digit_count(&BigInt::from(magnitude.log2_estimate().unsigned_abs())) + 1;
+    let extra = exponent.int_digits() + ln_digits;
+    let result = evaluate(bits, extra, |fx| {
+        let t = fx.mul(&exponent.fixed(fx), &fx.ln(&magnitude));
+        fx.exp(&t)
+    })?;
+    Some(if negate { -result } else { result })
+}
+
+pub(crate) fn sinh(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    let extra = x.int_digits() + x.leading_zeros();
+    evaluate(bits, extra, |fx| {
+        let (up, down) = fx.exp_pair(&x.fixed(fx))?;
+        let magnitude = (up - down) / 2u32;
+        Some(fx.to_bigdecimal(if x.is_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }))
+    })
+}
+
+pub(crate) fn cosh(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    evaluate(bits, x.int_digits(), |fx| {
+        let (up, down) = fx.exp_pair(&x.fixed(fx))?;
+        Some(fx.to_bigdecimal((up + down) / 2u32))
+    })
+}
+
+pub(crate) fn tanh(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    let extra = x.int_digits() + x.leading_zeros();
+    evaluate(bits, extra, |fx| {
+        let (up, down) = fx.exp_pair(&x.fixed(fx))?;
+        let magnitude = fx.div(&(&up - &down), &(up + down));
+        Some(fx.to_bigdecimal(if x.is_negative() {
+            -magnitude
+        } else {
+            magnitude
+        }))
+    })
+}
+
+/// asinh x = sign(x) ln(|x| + sqrt(x² + 1))
+pub(crate) fn asinh(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    evaluate(bits, x.leading_zeros(), |fx| {
+        let magnitude = x.fixed(fx).abs();
+        let root = fx.sqrt(&(&fx.one + fx.mul(&magnitude, &magnitude)));
+        let ln = fx.ln(&Exact {
+            num: magnitude + root,
+            den: fx.one.clone(),
+        });
+        Some(fx.to_bigdecimal(if x.is_negative() { -ln } else { ln }))
+    })
+}
+
+/// acosh x = ln(x + sqrt(x² - 1)) for x >= 1
+pub(crate) fn acosh(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    if x.num < x.den {
+        return Some(NumericValue::NaN);
+    }
+    // x² - 1 computed exactly, so the root is accurate near x = 1
+    let den2 = &x.den * &x.den;
+    let sq_minus_one = Exact {
+        num: &x.num * &x.num - &den2,
+        den: den2,
+    };
+    evaluate(bits, 0, |fx| {
+        let root = fx.sqrt(&sq_minus_one.fixed(fx));
+        Some(fx.to_bigdecimal(fx.ln(&Exact {
+            num: x.fixed(fx) + root,
+            den: fx.one.clone(),
+        })))
+    })
+}
+
+/// atanh x = ln((1 + x) / (1 - x)) / 2 for |x| < 1
+pub(crate) fn atanh(x: &NumericValue, bits: u32) -> Option<NumericValue> {
+    let x = Exact::of(x)?;
+    if x.num.abs() >= x.den {
+        return None;
+    }
+    let ratio = Exact {
+        num: &x.den + &x.num,
+        den: &x.den - &x.num,
+    };
+    evaluate(bits, x.leading_zeros(), |fx| {
+        Some(fx.to_bigdecimal(fx.ln(&ratio) / 2u32))
+    })
+}
@@ -0,0 +1,223 @@
+//! Mathematical constants at arbitrary precision.
+//!
+//! Constants are computed to a number of significant digits - by default the
+//! digits implied by `get_default_precision()` - and cached per thread, so
+//! repeated calls at the same precision are a clone. With `high_precision` they
+//! come from MPFR, with `high_precision_pure` from the BigDecimal series, and
+//! otherwise they are Decimal's 28 decimal places.
+
+use crate::context;
+use crate::error_bound;
+use crate::provenance::Trail;
+use crate::{ApproximationType, Number, NumericValue};
+use std::cell::RefCell;
+use std::collections::HashMap;
+
+#[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
+use bigdecimal::{BigDecimal, RoundingMode};
+#[cfg(not(any(feature = "high_precision", feature = "high_precision_pure")))]
+use rust_decimal::Decimal;
+#[cfg(not(any(feature = "high_precision", feature = "high_precision_pure")))]
+use std::str::FromStr;
+
+/// Significant digits of the Decimal constants used without a high-precision
+/// feature (28 decimal places; ln 2 has one fewer)
+#[cfg(not(any(feature = "high_precision", feature = "high_precision_pure")))]
+const DECIMAL_DIGITS: u32 = 29;
+
+/// A mathematical constant that `Number::constant` can produce at any precision
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Constant {
+    /// π, the ratio of a circle's circumference to its diameter
+    Pi,
+    /// e, the base of the natural logarithm
+    E,
+    /// ln 2
+    Ln2,
+    /// ln 10
+    Ln10,
+    /// √2
+    Sqrt2,
+}
+
+impl Constant {
+    fn name(self) -> &'static str {
+        match self {
+            Constant::Pi => "pi",
+            Constant::E => "e",
+            Constant::Ln2 => "ln2",
+            Constant::Ln10 => "ln10",
+            Constant::Sqrt2 => "sqrt2",
+        }
+    }
+
+    /// The constant to 28 decimal places, correctly rounded
+    #[cfg(not(any(feature = "high_precision", feature = "high_precision_pure")))]
+    fn decimal(self) -> Decimal {
+        let digits = match self {
+            Constant::Pi => "3.1415926535897932384626433833",
+            Constant::E => "2.7182818284590452353602874714",
+            Constant::Ln2 => "0.6931471805599453094172321215",
+            Constant::Ln10 => "2.3025850929940456840179914547",
+            Constant::Sqrt2 => "1.4142135623730950488016887242",
+        };
+        Decimal::from_str(digits).expect("constant literal")
+    }
+}
+
+thread_local! {
+    /// Constants already computed on this thread, by significant digits
+    static CACHE: RefCell<HashMap<(Constant, u64), NumericValue>> = RefCell::new(HashMap::new());
+}
+
+/// Significant digits a constant gets at the current default precision
+fn default_digits() -> u64 {
+    #[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
+    {
+        let bits = crate::precision::get_default_precision();
+        ((f64::from(bits) * std::f64::consts::LOG10_2).ceil() as u64).max(1)
+    }
+    #[cfg(not(any(feature = "high_precision", feature = "high_precision_pure")))]
+    {
+        u64::from(DECIMAL_DIGITS)
+    }
+}
+
+/// Round to `digits` significant digits, half to even
+#[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
+fn round_to_digits(value: BigDecimal, digits: u64) -> NumericValue {
+    let digits = std::num::NonZeroU64::new(digits).unwrap_or(std::num::NonZeroU64::MIN);
+    NumericValue::from_bigdecimal(
+        value
+            .with_precision_round(digits, RoundingMode::HalfEven)
+            .normalized(),
+    )
+}
+
+#[cfg(feature = "high_precision")]
+fn compute(constant: Constant, digits: u64) -> NumericValue {
+    use rug::Float;
+    use rug::float::Constant as MpfrConstant;
+    use std::str::FromStr;
+
+    // Guard bits so rounding to `digits` afterwards is (nearly always) correct
+    let bits = (digits as f64 / std::f64::consts::LOG10_2).ceil() as u32 + 32;
+    let value = match constant {
+        Constant::Pi => Float::with_val(bits, MpfrConstant::Pi),
+        Constant::E => Float::with_val(bits, 1).exp(),
+        Constant::Ln2 => Float::with_val(bits, MpfrConstant::Log2),
+        Constant::Ln10 => Float::with_val(bits, 10).ln(),
+        Constant::Sqrt2 => Float::with_val(bits, 2).sqrt(),
+    };
+    let value = BigDecimal::from_str(&value.to_string()).unwrap_or_default();
+    round_to_digits(value, digits)
+}
+
+#[cfg(all(feature = "high_precision_pure", not(feature = "high_precision")))]
+fn compute(constant: Constant, digits: u64) -> NumericValue {
+    use crate::bigdecimal_math;
+
+    // Guard bits so rounding to `digits` afterwards is (nearly always) correct
+    let bits = (digits as f64 / std::f64::consts::LOG10_2).ceil() as u32 + 32;
+    let integer = |n: i64| NumericValue::from_rational(num_rational::Ratio::from_integer(n));
+    let value = match constant {
+        Constant::Pi => bigdecimal_math::pi(bits),
+        Constant::E => bigdecimal_math::exp(&integer(1), bits),
+        Constant::Ln2 => bigdecimal_math::ln(&integer(2), bits),
+        Constant::Ln10 => bigdecimal_math::ln(&integer(10), bits),
+        Constant::Sqrt2 => bigdecimal_math::sqrt(&integer(2), bits),
+    };
+    let Some(NumericValue::BigDecimal(value)) = value else {
+        unreachable!("the series always converge for these arguments");
+    };
+    round_to_digits(value, digits)
+}
+
+#[cfg(not(any(feature = "high_precision", feature = "high_precision_pure")))]
+fn compute(constant: Constant, digits: u64) -> NumericValue {
+    let value = constant.decimal();
+    let digits = u32::try_from(digits).unwrap_or(DECIMAL_DIGITS);
+    NumericValue::from_decimal(value.round_sf(digits).unwrap_or(value))
+}
+
+/// The constant to `digits` significant digits, from this thread's cache when possible
+fn cached(constant: Constant, digits: u64) -> NumericValue {
+    if let Some(value) = CACHE.with(|c| c.borrow().get(&(constant, digits)).cloned()) {
+        return value;
+    }
+    let value = compute(constant, digits);
+    CACHE.with(|c| c.borrow_mut().insert((constant, digits), value.clone()));
+    value
+}
+
+impl Number {
+    /// π at the current default precision, flagged `Transcendental`
+    #[allow(non_snake_case)]
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn PI() -> Number {
+        Number::constant(Constant::Pi)
+    }
+
+    /// e at the current default precision, flagged `Transcendental`
+    #[allow(non_snake_case)]
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn E() -> Number {
+        Number::constant(Constant::E)
+    }
+
+    /// ln 2 at the current default precision, flagged `Transcendental`
+    #[allow(non_snake_case)]
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn LN2() -> Number {
+        Number::constant(Constant::Ln2)
+    }
+
+    /// ln 10 at the current default precision, flagged `Transcendental`
+    #[allow(non_snake_case)]
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn LN10() -> Number {
+        Number::constant(Constant::Ln10)
+    }
+
+    /// √2 at the current default precision, flagged `Transcendental`
+    #[allow(non_snake_case)]
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn SQRT2() -> Number {
+        Number::constant(Constant::Sqrt2)
+    }
+
+    /// A constant to the digits implied by `get_default_precision()` (28 decimal
+    /// places without a high-precision feature).
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn constant(constant: Constant) -> Number {
+        Number::constant_with_digits(constant, default_digits())
+    }
+
+    /// A constant correctly rounded to `digits` significant digits.
+    ///
+    /// Without a high-precision feature the digits are capped at Decimal's 28
+    /// decimal places.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::{Constant, Number};
+    ///
+    /// let pi = Number::constant_with_digits(Constant::Pi, 10);
+    /// assert_eq!(pi.to_string(), "3.141592654");
+    /// assert!(pi.is_transcendental());
+    /// ```
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn constant_with_digits(constant: Constant, digits: u64) -> Number {
+        let digits = digits.max(1);
+        let operands = context::Operands::capture(&Number::from(digits), None);
+        let value = cached(constant, digits);
+        let err_bound = Some(error_bound::constant(&value, digits));
+        let result = Number {
+            value,
+            apprx: Some(ApproximationType::Transcendental),
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit(constant.name(), result, operands)
+    }
+}
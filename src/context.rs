@@ -0,0 +1,290 @@
+//! Thread-local arithmetic context.
+//!
+//! Decides what happens when an operator or math function produces an
+//! approximate result: allow it (the default), raise a sticky flag, or panic.
+//! The policy is set per `ApproximationType`, so e.g. transcendentals can be
+//! allowed while rational approximations are not.
+//!
+//! The context also holds the denominator limit used when demotion recovers an
+//! exact Rational from a Decimal result (see decision 011).
+//!
+//! Results are audited the same way as the `checked_*` methods: any result
+//! carrying an approximation flag counts, including one inherited from an
+//! approximate operand.
+
+use crate::core::{ApproximationType, CF_MAX_DENOM, Number};
+use crate::ops::checked::{ExactnessError, ExactnessErrorKind};
+use std::cell::{Cell, RefCell};
+use std::marker::PhantomData;
+
+/// What to do when an operation produces an approximate result.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ApproximationPolicy {
+    /// Return the approximate result (today's behaviour)
+    #[default]
+    Allow,
+    /// Return the approximate result and raise a sticky flag
+    Flag,
+    /// Panic with the `ExactnessError` describing the operation
+    Panic,
+}
+
+/// Per-`ApproximationType` policies and rational recovery settings for the current thread.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ArithmeticContext {
+    pub transcendental: ApproximationPolicy,
+    pub rational_approximation: ApproximationPolicy,
+    /// Largest denominator demotion will recover from a Decimal (default 10^9)
+    pub max_recovery_denominator: i64,
+}
+
+impl Default for ArithmeticContext {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl ArithmeticContext {
+    /// Allow every approximation (the default context).
+    pub const fn new() -> Self {
+        Self::uniform(ApproximationPolicy::Allow)
+    }
+
+    /// Apply the same policy to every `ApproximationType`.
+    pub const fn uniform(policy: ApproximationPolicy) -> Self {
+        ArithmeticContext {
+            transcendental: policy,
+            rational_approximation: policy,
+            max_recovery_denominator: CF_MAX_DENOM,
+        }
+    }
+
+    /// Panic on any approximation.
+    pub const fn strict() -> Self {
+        Self::uniform(ApproximationPolicy::Panic)
+    }
+
+    /// Set the policy for one `ApproximationType`.
+    pub fn with_policy(mut self, kind: ApproximationType, policy: ApproximationPolicy) -> Self {
+        match kind {
+            ApproximationType::Transcendental => self.transcendental = policy,
+            ApproximationType::RationalApproximation => self.rational_approximation = policy,
+        }
+        self
+    }
+
+    /// Set the largest denominator demotion will recover from a Decimal.
+    ///
+    /// Values below 1 are treated as 1 (integers only).
+    pub fn with_max_recovery_denominator(mut self, max_denom: i64) -> Self {
+        self.max_recovery_denominator = max_denom.max(1);
+        self
+    }
+
+    /// The policy for one `ApproximationType`.
+    pub fn policy_for(&self, kind: &ApproximationType) -> ApproximationPolicy {
+        match kind {
+            ApproximationType::Transcendental => self.transcendental,
+            ApproximationType::RationalApproximation => self.rational_approximation,
+        }
+    }
+
+    fn allows_everything(&self) -> bool {
+        self.transcendental == ApproximationPolicy::Allow
+            && self.rational_approximation == ApproximationPolicy::Allow
+    }
+
+    /// Install this context until the returned guard is dropped.
+    ///
+    /// Flags raised inside the scope are visible through the guard and are
+    /// merged into the enclosing scope's flags on drop.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::{ApproximationPolicy, ArithmeticContext, Number};
+    ///
+    /// let guard = ArithmeticContext::uniform(ApproximationPolicy::Flag).enter();
+    /// let a = Number::from(2);
+    /// let _ = a.clone() + a.clone() * Number::from(3);
+    /// assert!(guard.flags().is_clear());
+    ///
+    /// let _ = a.sqrt();
+    /// assert!(guard.flags().transcendental);
+    /// ```
+    pub fn enter(self) -> ContextGuard {
+        let previous = CONTEXT.with(|c| c.replace(self));
+        let previous_flags = FLAGS.with(|f| f.take());
+        ContextGuard {
+            previous,
+            previous_flags,
+            _not_send: PhantomData,
+        }
+    }
+}
+
+/// Sticky flags raised under `ApproximationPolicy::Flag`.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ApproximationFlags {
+    pub transcendental: bool,
+    pub rational_approximation: bool,
+    /// The first flagged operation, with its operands
+    pub first: Option<ExactnessError>,
+}
+
+impl ApproximationFlags {
+    /// True when nothing has been flagged.
+    pub fn is_clear(&self) -> bool {
+        !self.transcendental && !self.rational_approximation
+    }
+
+    /// True when the given `ApproximationType` has been flagged.
+    pub fn is_raised(&self, kind: &ApproximationType) -> bool {
+        match kind {
+            ApproximationType::Transcendental => self.transcendental,
+            ApproximationType::RationalApproximation => self.rational_approximation,
+        }
+    }
+
+    fn raise(&mut self, kind: &ApproximationType) {
+        match kind {
+            ApproximationType::Transcendental => self.transcendental = true,
+            ApproximationType::RationalApproximation => self.rational_approximation = true,
+        }
+    }
+
+    fn merge(&mut self, other: ApproximationFlags) {
+        self.transcendental |= other.transcendental;
+        self.rational_approximation |= other.rational_approximation;
+        if self.first.is_none() {
+            self.first = other.first;
+        }
+    }
+}
+
+/// Restores the previous arithmetic context when dropped.
+#[must_use = "the context is restored as soon as the guard is dropped"]
+pub struct ContextGuard {
+    previous: ArithmeticContext,
+    previous_flags: ApproximationFlags,
+    // The context is thread-local, so the guard must be dropped on the same thread
+    _not_send: PhantomData<*const ()>,
+}
+
+impl ContextGuard {
+    /// Flags raised since this guard was created.
+    pub fn flags(&self) -> ApproximationFlags {
+        approximation_flags()
+    }
+}
+
+impl Drop for ContextGuard {
+    fn drop(&mut self) {
+        CONTEXT.with(|c| c.set(self.previous));
+        let mut flags = std::mem::take(&mut self.previous_flags);
+        flags.merge(FLAGS.with(|f| f.take()));
+        FLAGS.with(|f| *f.borrow_mut() = flags);
+    }
+}
+
+thread_local! {
+    static CONTEXT: Cell<ArithmeticContext> = const { Cell::new(ArithmeticContext::new()) };
+    static FLAGS: RefCell<ApproximationFlags> = RefCell::new(ApproximationFlags::default());
+}
+
+/// Set the arithmetic context for the current thread.
+///
+/// Prefer `ArithmeticContext::enter` for scoped changes.
+pub fn set_arithmetic_context(context: ArithmeticContext) {
+    CONTEXT.with(|c| c.set(context));
+}
+
+/// Get the arithmetic context for the current thread.
+pub fn get_arithmetic_context() -> ArithmeticContext {
+    CONTEXT.with(|c| c.get())
+}
+
+/// Denominator limit for rational recovery on the current thread.
+pub(crate) fn max_recovery_denominator() -> i64 {
+    CONTEXT.with(|c| c.get().max_recovery_denominator)
+}
+
+/// Flags raised on the current thread.
+pub fn approximation_flags() -> ApproximationFlags {
+    FLAGS.with(|f| f.borrow().clone())
+}
+
+/// Return and clear the flags raised on the current thread.
+pub fn take_approximation_flags() -> ApproximationFlags {
+    FLAGS.with(|f| f.take())
+}
+
+/// Operands kept for error reporting, captured only when a policy is active.
+pub(crate) struct Operands(Option<(Number, Option<Number>)>);
+
+impl Operands {
+    #[inline]
+    pub(crate) fn capture(lhs: &Number, rhs: Option<&Number>) -> Operands {
+        // Provenance records operands whenever a result becomes approximate
+        if !cfg!(feature = "provenance") && CONTEXT.with(|c| c.get().allows_everything()) {
+            Operands(None)
+        } else {
+            Operands(Some((lhs.clone(), rhs.cloned())))
+        }
+    }
+}
+
+/// Apply the current context's policy to `result`, and record its provenance
+/// when the `provenance` feature is on.
+#[inline]
+#[cfg_attr(feature = "provenance", track_caller)]
+pub(crate) fn audit(op: &'static str, result: Number, operands: Operands) -> Number {
+    let (Some(apprx), Some((lhs, rhs))) = (result.apprx.clone(), operands.0) else {
+        return result;
+    };
+    let apprx = &apprx;
+
+    #[cfg(feature = "provenance")]
+    let result = Number {
+        provenance: crate::provenance::Trail::record(
+            op,
+            apprx,
+            &lhs,
+            rhs.as_ref(),
+            std::panic::Location::caller(),
+        ),
+        ..result
+    };
+
+    let error = || ExactnessError {
+        op,
+        kind: ExactnessErrorKind::Approximation(apprx.clone()),
+        lhs: Box::new(lhs),
+        rhs: rhs.map(Box::new),
+    };
+    match get_arithmetic_context().policy_for(apprx) {
+        ApproximationPolicy::Allow => {}
+        ApproximationPolicy::Flag => FLAGS.with(|f| {
+            let mut flags = f.borrow_mut();
+            flags.raise(apprx);
+            if flags.first.is_none() {
+                flags.first = Some(error());
+            }
+        }),
+        ApproximationPolicy::Panic => panic!("{}", error()),
+    }
+    result
+}
+
+/// Run `f` with every policy set to `Allow`, leaving flags untouched.
+pub(crate) fn unaudited<T>(f: impl FnOnce() -> T) -> T {
+    let previous = CONTEXT.with(|c| c.replace(ArithmeticContext::new()));
+    // Restore even if `f` panics
+    struct Restore(ArithmeticContext);
+    impl Drop for Restore {
+        fn drop(&mut self) {
+            CONTEXT.with(|c| c.set(self.0));
+        }
+    }
+    let _restore = Restore(previous);
+    f()
+}
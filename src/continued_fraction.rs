@@ -0,0 +1,170 @@
+//! Continued fractions and best rational approximations.
+//!
+//! Everything here works on the exact rational value a `Number` stores, so for an
+//! approximate value such as `sqrt(2)` the expansion is that of its stored digits.
+//! The expansion itself is `core::ContinuedFraction`, the engine automatic
+//! demotion uses for rational recovery (bounded there as in decision 011).
+
+use crate::context;
+use crate::core::ContinuedFraction;
+use crate::error_bound;
+use crate::number_theory::from_integer;
+use crate::provenance::Trail;
+use crate::{ApproximationType, Number, NumericValue};
+use bigdecimal::num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
+
+/// Terms [a0; a1, a2, ...] of `value`, at most `max_terms` of them
+fn terms(value: &BigRational, max_terms: usize) -> Vec<BigInt> {
+    ContinuedFraction::of(value)
+        .take(max_terms)
+        .map(|(a, _, _)| a)
+        .collect()
+}
+
+/// Every convergent h/k of `value`, ending with `value` itself
+fn convergents(value: &BigRational) -> Vec<BigRational> {
+    ContinuedFraction::of(value)
+        .map(|(_, h, k)| BigRational::new(h, k))
+        .collect()
+}
+
+/// The first convergent of `value` within `tolerance` of it
+#[cfg(feature = "format")]
+pub(crate) fn first_convergent_within(value: &BigRational, tolerance: &BigRational) -> BigRational {
+    convergents(value)
+        .into_iter()
+        .find(|c| (c - value).abs() <= *tolerance)
+        .expect("the last convergent is the value itself")
+}
+
+/// The closest rational to `value` with denominator at most `max_denom`, ties
+/// to the convergent (Python's `Fraction.limit_denominator`)
+pub(crate) fn best_approximation(value: &BigRational, max_denom: &BigInt) -> BigRational {
+    if value.denom() <= max_denom {
+        return value.clone();
+    }
+    // The last two convergents within the limit, starting from h/k at n = -2, -1
+    let (mut p0, mut q0, mut p1, mut q1) =
+        (BigInt::zero(), BigInt::one(), BigInt::one(), BigInt::zero());
+    // The expansion reaches `value`, whose denominator is past the limit, so it stops here
+    for (_, h, k) in ContinuedFraction::of(value) {
+        if &k > max_denom {
+            break;
+        }
+        (p0, q0, p1, q1) = (p1, q1, h, k);
+    }
+    // The best semiconvergent below the limit competes with the last convergent
+    let k = (max_denom - &q0) / &q1;
+    let semiconvergent = BigRational::new(&p0 + &k * &p1, &q0 + &k * &q1);
+    let convergent = BigRational::new(p1, q1);
+    if (&convergent - value).abs() <= (&semiconvergent - value).abs() {
+        convergent
+    } else {
+        semiconvergent
+    }
+}
+
+impl Number {
+    /// A rational standing in for `self`: `self` again when it matches the stored
+    /// value, otherwise a `RationalApproximation` bounded by its distance
+    fn approximation_of(&self, exact: &BigRational, approximation: BigRational) -> Number {
+        if approximation == *exact {
+            return Number {
+                value: self.value.clone(),
+                apprx: self.apprx.clone(),
+                err_bound: self.err_bound,
+                provenance: Trail::NONE,
+            };
+        }
+        let distance = (&approximation - exact).abs();
+        let input = error_bound::Tracked::of(self);
+        Number {
+            value: NumericValue::from_big_rational(approximation),
+            apprx: Some(ApproximationType::RationalApproximation),
+            err_bound: error_bound::rational_approximation(input, &distance),
+            provenance: Trail::NONE,
+        }
+    }
+
+    /// The first `max_terms` terms [a0; a1, a2, ...] of the continued fraction of the
+    /// stored value; empty for NaN and the infinities.
+    ///
+    /// Terms of an approximate value keep its approximation flag.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::Number;
+    ///
+    /// let x = Number::from(415) / Number::from(93);
+    /// let terms: Vec<String> = x.continued_fraction(10).iter().map(|t| t.to_string()).collect();
+    /// assert_eq!(terms, ["4", "2", "6", "7"]);
+    /// ```
+    pub fn continued_fraction(&self, max_terms: usize) -> Vec<Number> {
+        let Some(exact) = self.value.to_big_rational() else {
+            return Vec::new();
+        };
+        terms(&exact, max_terms)
+            .into_iter()
+            .map(|a| Number {
+                value: from_integer(a),
+                apprx: self.apprx.clone(),
+                err_bound: None,
+                provenance: Trail::NONE,
+            })
+            .collect()
+    }
+
+    /// Every convergent of the stored value's continued fraction, ending with the
+    /// value itself; empty for NaN and the infinities.
+    ///
+    /// All but an exact input's last convergent are `RationalApproximation`s, with
+    /// an error bound covering their distance from the value.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::Number;
+    ///
+    /// let ratio = |n: i64, d: i64| Number::from(n) / Number::from(d);
+    /// let convergents = Number::from(2).sqrt().convergents();
+    /// assert_eq!(convergents[..4], [ratio(1, 1), ratio(3, 2), ratio(7, 5), ratio(17, 12)]);
+    /// assert!(convergents[3].is_rational_approximation());
+    /// ```
+    pub fn convergents(&self) -> Vec<Number> {
+        let Some(exact) = self.value.to_big_rational() else {
+            return Vec::new();
+        };
+        convergents(&exact)
+            .into_iter()
+            .map(|c| self.approximation_of(&exact, c))
+            .collect()
+    }
+
+    /// The rational closest to the stored value with denominator at most
+    /// `max_denom` (at least 1), like Python's `Fraction.limit_denominator`.
+    ///
+    /// The result is `RationalApproximation` unless it equals the stored value, in
+    /// which case `self` is returned as is. So are NaN and the infinities.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::Number;
+    /// use std::str::FromStr;
+    ///
+    /// let pi = Number::from_str("3.1415926535897932").unwrap();
+    /// let approx = pi.limit_denominator(1000);
+    /// assert_eq!(approx, Number::from(355) / Number::from(113));
+    /// assert!(approx.is_rational_approximation());
+    /// ```
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn limit_denominator(self, max_denom: i64) -> Number {
+        let Some(exact) = self.value.to_big_rational() else {
+            return self;
+        };
+        let operands = context::Operands::capture(&self, Some(&Number::from(max_denom)));
+        let best = best_approximation(&exact, &BigInt::from(max_denom.max(1)));
+        let result = self.approximation_of(&exact, best);
+        context::audit("limit_denominator", result, operands)
+    }
+}
@@ -1,13 +1,90 @@
+use crate::provenance::Trail;
 use std::str::FromStr;
 
+use bigdecimal::BigDecimal;
 use num_traits::{FromPrimitive, ToPrimitive};
 use rust_decimal::Decimal;
 
 use crate::{Number, NumericValue};
 
+/// Error returned when a string isn't a number: by `FromStr`, `Num::from_str_radix`
+/// and, with the `format` feature, `Number::parse_formatted`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseNumberError {
+    /// Input string was empty
+    Empty,
+    /// Invalid character found at (char) position `pos`
+    InvalidDigit { pos: usize, ch: char },
+    /// Radix outside 2..=36
+    InvalidRadix(u32),
+    /// Number exceeds representable range
+    Overflow,
+    /// Multiple decimal separators or other separator issues
+    MultipleSeparators,
+    /// Input doesn't match expected regional format
+    MismatchedFormat,
+}
+
+impl std::fmt::Display for ParseNumberError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ParseNumberError::Empty => write!(f, "empty input"),
+            ParseNumberError::InvalidDigit { pos, ch } => {
+                write!(f, "invalid digit '{}' at position {}", ch, pos)
+            }
+            ParseNumberError::InvalidRadix(radix) => {
+                write!(f, "radix {} is outside 2..=36", radix)
+            }
+            ParseNumberError::Overflow => write!(f, "number exceeds representable range"),
+            ParseNumberError::MultipleSeparators => write!(f, "multiple separators"),
+            ParseNumberError::MismatchedFormat => write!(f, "input doesn't match expected format"),
+        }
+    }
+}
+
+impl std::error::Error for ParseNumberError {}
+
+/// Why `s` isn't a decimal number: the first character that breaks
+/// `[sign]digits[.digits][e[sign]digits]`, or Overflow when none does
+fn decimal_error(s: &str) -> ParseNumberError {
+    let (mut digits, mut point, mut exponent) = (0, false, false);
+    let mut prev = None;
+    for (pos, ch) in s.chars().enumerate() {
+        let valid = match ch {
+            '0'..='9' => {
+                digits += 1;
+                true
+            }
+            '+' | '-' => pos == 0 || matches!(prev, Some('e' | 'E')),
+            '.' if !point && !exponent => {
+                point = true;
+                true
+            }
+            'e' | 'E' if digits > 0 && !exponent => {
+                (digits, exponent) = (0, true);
+                true
+            }
+            _ => false,
+        };
+        if !valid {
+            return ParseNumberError::InvalidDigit { pos, ch };
+        }
+        prev = Some(ch);
+    }
+    match s.chars().last() {
+        // "-", ".", "1e": the digits that should follow are missing
+        Some(ch) if digits == 0 => ParseNumberError::InvalidDigit {
+            pos: s.chars().count() - 1,
+            ch,
+        },
+        Some(_) => ParseNumberError::Overflow,
+        None => ParseNumberError::Empty,
+    }
+}
+
 // Parse from string
 impl FromStr for NumericValue {
-    type Err = ();
+    type Err = ParseNumberError;
 
     fn from_str(#[allow(unused_mut)] mut s: &str) -> Result<NumericValue, Self::Err> {
         #[cfg(feature = "js_string_parse")]
@@ -24,7 +101,7 @@ impl FromStr for NumericValue {
             #[cfg(feature = "js_string_parse")]
             "" => Ok(NumericValue::zero()), // Empty string converts to 0 in JS
             #[cfg(not(feature = "js_string_parse"))]
-            "" => Err(()), // Empty string is an error by default
+            "" => Err(ParseNumberError::Empty), // Empty string is an error by default
             _ => {
                 // Try to parse as Decimal first
                 if let Ok(d) = Decimal::from_str(s) {
@@ -37,7 +114,7 @@ impl FromStr for NumericValue {
                         // Note: JavaScript has complex string-to-number conversion rules.
                         // This is a simplified version - JS would parse partial numbers.
                         // Full ECMAScript ToNumber would be needed for complete JS compat.
-                        Err(())
+                        Err(decimal_error(s))
                     }
                 }
             }
@@ -175,7 +252,7 @@ impl TryFrom<NumericValue> for Decimal {
 
 // Parse from string
 impl FromStr for Number {
-    type Err = ();
+    type Err = ParseNumberError;
 
     fn from_str(#[allow(unused_mut)] mut s: &str) -> Result<Number, Self::Err> {
         #[cfg(feature = "js_string_parse")]
@@ -192,15 +269,22 @@ impl FromStr for Number {
             #[cfg(feature = "js_string_parse")]
             "" => NumericValue::zero(), // Empty string converts to 0 in JS
             #[cfg(not(feature = "js_string_parse"))]
-            "" => return Err(()), // Empty string is an error by default
+            "" => return Err(ParseNumberError::Empty), // Empty string is an error by default
             _ => {
+                // Repeating decimals ("0.1(6)", "0.16̅") are exact rationals
+                if let Some(value) = crate::repeating::parse(s) {
+                    value
+                }
+                // So are fractions and mixed numbers ("1/3", "-2 1/2", "1.5/2.25", "1⅓")
+                else if let Some(fraction) = crate::fraction::parse(s) {
+                    return fraction;
+                }
                 // Try to parse as Decimal first, then attempt rational recovery
-                if let Ok(d) = Decimal::from_str(s) {
+                else if let Ok(d) = Decimal::from_str(s) {
                     // Use from_decimal which attempts rational recovery for terminating decimals
                     NumericValue::from_decimal(d)
                 } else {
                     // Try to parse as BigDecimal for very large numbers
-                    use bigdecimal::BigDecimal;
                     if let Ok(bd) = s.parse::<BigDecimal>() {
                         NumericValue::BigDecimal(bd)
                     } else {
@@ -208,14 +292,19 @@ impl FromStr for Number {
                         if let Ok(f) = f64::from_str(s) {
                             return Ok(Number::from(f));
                         } else {
-                            return Err(());
+                            return Err(decimal_error(s));
                         }
                     }
                 }
             }
         };
 
-        Ok(Number { value, apprx: None })
+        Ok(Number {
+            value,
+            apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
+        })
     }
 }
 
@@ -379,7 +468,12 @@ impl From<f64> for Number {
             }
         };
 
-        Number { value, apprx: None }
+        Number {
+            value,
+            apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
+        }
     }
 }
 
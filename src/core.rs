@@ -1,12 +1,21 @@
+use crate::provenance::Trail;
 use bigdecimal::BigDecimal;
-use num_rational::{Ratio, Rational64};
+use bigdecimal::num_bigint::BigInt;
+use num_rational::{BigRational, Ratio, Rational64};
+use num_traits::{One, Signed, ToPrimitive, Zero};
 use rust_decimal::Decimal;
 
-/// Maximum denominator for continued fractions rational recovery.
+/// Default maximum denominator for continued fractions rational recovery.
 /// Set to 10^9 to ensure arithmetic safety: two rationals with denominators
 /// up to 10^9 can multiply without overflow (10^9 × 10^9 = 10^18 < i64::MAX).
+/// Configurable per thread via `ArithmeticContext::with_max_recovery_denominator`.
 /// See: project/dev_manual/decisions/011-cf-denominator-limit.md
-const CF_MAX_DENOM: i64 = 1_000_000_000;
+pub(crate) const CF_MAX_DENOM: i64 = 1_000_000_000;
+
+/// Digits a value must carry beyond q² before a CF candidate with denominator q
+/// above `CF_MAX_DENOM` is trusted. Matches the margin the default limit keeps at
+/// Decimal's full 28-digit scale: (10^9)² = 10^18 = 10^(28 - 10).
+const CF_DIGIT_MARGIN: i64 = 10;
 
 /// A smart number type that supports multiple internal representations
 /// with automatic upgrades for precision and proper handling of IEEE special values
@@ -15,6 +24,10 @@ pub(crate) enum NumericValue {
     /// Exact rational number (e.g., 1/3, 2/7) with cached terminating flag
     /// The bool indicates if this is a terminating decimal (can be exactly represented in base 10)
     Rational(Rational64, bool),
+    /// Arbitrary-size exact rational for values whose numerator or denominator
+    /// overflow i64. Only holds non-terminating values that don't fit Rational64;
+    /// see `NumericValue::from_big_rational` for the canonical form.
+    BigRational(BigRational),
     /// Fixed-point decimal with 28 significant digits (renamed from Finite)
     Decimal(Decimal),
     /// Arbitrary precision decimal for very large numbers
@@ -77,6 +90,21 @@ impl NumericValue {
         NumericValue::Rational(r, is_term)
     }
 
+    pub fn from_big_rational(r: BigRational) -> Self {
+        use crate::representation::{
+            big_rational_to_bigdecimal, is_terminating_big, try_big_rational_to_rational64,
+        };
+        // Fits in i64 again: demote to Rational64
+        if let Some(r64) = try_big_rational_to_rational64(&r) {
+            return Self::from_rational(r64);
+        }
+        // Terminating: exactly representable in the decimal tiers
+        if is_terminating_big(r.denom()) {
+            return Self::from_bigdecimal(big_rational_to_bigdecimal(&r));
+        }
+        NumericValue::BigRational(r)
+    }
+
     pub fn from_decimal(d: Decimal) -> Self {
         // Try to downgrade to Rational first
         if let Some(r) = try_decimal_to_rational(d) {
@@ -105,6 +133,7 @@ impl NumericValue {
         matches!(
             self,
             NumericValue::Rational(_, _)
+                | NumericValue::BigRational(_)
                 | NumericValue::Decimal(_)
                 | NumericValue::BigDecimal(_)
                 | NumericValue::NegativeZero
@@ -127,19 +156,72 @@ impl NumericValue {
     }
 
     // Introspection for representation type
-    pub fn representation(&self) -> &'static str {
+    pub fn representation(&self) -> Repr {
         match self {
-            NumericValue::Rational(_, _) => "Rational",
-            NumericValue::Decimal(_) => "Decimal",
-            NumericValue::BigDecimal(_) => "BigDecimal",
-            NumericValue::NaN => "NaN",
-            NumericValue::PositiveInfinity => "PositiveInfinity",
-            NumericValue::NegativeInfinity => "NegativeInfinity",
-            NumericValue::NegativeZero => "NegativeZero",
+            NumericValue::Rational(_, _) => Repr::Rational,
+            NumericValue::BigRational(_) => Repr::BigRational,
+            NumericValue::Decimal(_) => Repr::Decimal,
+            NumericValue::BigDecimal(_) => Repr::BigDecimal,
+            NumericValue::NaN => Repr::NaN,
+            NumericValue::PositiveInfinity => Repr::PositiveInfinity,
+            NumericValue::NegativeInfinity => Repr::NegativeInfinity,
+            NumericValue::NegativeZero => Repr::NegativeZero,
         }
     }
 }
 
+/// The internal representation of a `Number`.
+///
+/// Compares equal to its name as a string, so `n.representation() == "Rational"` works.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Repr {
+    /// Exact `Rational64`
+    Rational,
+    /// Exact arbitrary-size rational
+    BigRational,
+    /// 28-digit `rust_decimal::Decimal`
+    Decimal,
+    /// Arbitrary-precision `bigdecimal::BigDecimal`
+    BigDecimal,
+    NaN,
+    PositiveInfinity,
+    NegativeInfinity,
+    NegativeZero,
+}
+
+impl Repr {
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            Repr::Rational => "Rational",
+            Repr::BigRational => "BigRational",
+            Repr::Decimal => "Decimal",
+            Repr::BigDecimal => "BigDecimal",
+            Repr::NaN => "NaN",
+            Repr::PositiveInfinity => "PositiveInfinity",
+            Repr::NegativeInfinity => "NegativeInfinity",
+            Repr::NegativeZero => "NegativeZero",
+        }
+    }
+}
+
+impl std::fmt::Display for Repr {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_str())
+    }
+}
+
+impl PartialEq<str> for Repr {
+    fn eq(&self, other: &str) -> bool {
+        self.as_str() == other
+    }
+}
+
+impl PartialEq<&str> for Repr {
+    fn eq(&self, other: &&str) -> bool {
+        self.as_str() == *other
+    }
+}
+
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub enum ApproximationType {
     Transcendental,        // From irrational operations
@@ -162,9 +244,8 @@ pub enum ApproximationType {
 /// ```
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct NumberInfo {
-    /// The internal representation type: "Rational", "Decimal", "BigDecimal",
-    /// "NaN", "PositiveInfinity", "NegativeInfinity", or "NegativeZero"
-    pub representation: &'static str,
+    /// The internal representation type
+    pub representation: Repr,
     /// Whether the value is exact (no precision was lost)
     pub is_exact: bool,
     /// The type of approximation, if any
@@ -196,6 +277,12 @@ impl std::fmt::Display for NumberInfo {
 pub struct Number {
     pub(crate) value: NumericValue,
     pub(crate) apprx: Option<ApproximationType>,
+    /// Upper bound on the absolute error, when `apprx` is set and the bound is known.
+    /// Relative to |value| instead for finite values past f64's range, where an
+    /// absolute bound would overflow (see `error_bound::beyond_f64`).
+    pub(crate) err_bound: Option<f64>,
+    /// Where the value first became approximate (`provenance` feature; zero-sized otherwise)
+    pub(crate) provenance: Trail,
 }
 
 impl Number {
@@ -203,14 +290,20 @@ impl Number {
     pub const NAN: Number = Number {
         value: NumericValue::NaN,
         apprx: None,
+        err_bound: None,
+        provenance: Trail::NONE,
     };
     pub const POSITIVE_INFINITY: Number = Number {
         value: NumericValue::PositiveInfinity,
         apprx: None,
+        err_bound: None,
+        provenance: Trail::NONE,
     };
     pub const NEGATIVE_INFINITY: Number = Number {
         value: NumericValue::NegativeInfinity,
         apprx: None,
+        err_bound: None,
+        provenance: Trail::NONE,
     };
     // ZERO and ONE as Rational for consistency with Number::from(0/1)
     // Cannot be const because Ratio::new is not const, so we use functions
@@ -220,6 +313,8 @@ impl Number {
         Number {
             value: NumericValue::zero(),
             apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
         }
     }
     #[inline]
@@ -228,11 +323,15 @@ impl Number {
         Number {
             value: NumericValue::one(),
             apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
         }
     }
     pub const NEGATIVE_ZERO: Number = Number {
         value: NumericValue::NegativeZero,
         apprx: None,
+        err_bound: None,
+        provenance: Trail::NONE,
     };
 
     // Constructors
@@ -240,6 +339,8 @@ impl Number {
         Number {
             value: NumericValue::new(num, scale),
             apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
         }
     }
 
@@ -263,6 +364,8 @@ impl Number {
         Number {
             value: NumericValue::new_uint(num),
             apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
         }
     }
 
@@ -271,6 +374,8 @@ impl Number {
         Ok(Number {
             value: NumericValue::try_from_i128_with_scale(num, scale)?,
             apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
         })
     }
 
@@ -278,6 +383,17 @@ impl Number {
         Number {
             value: NumericValue::from_rational(r),
             apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
+        }
+    }
+
+    pub fn from_big_rational(r: BigRational) -> Self {
+        Number {
+            value: NumericValue::from_big_rational(r),
+            apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
         }
     }
 
@@ -286,6 +402,8 @@ impl Number {
             value: NumericValue::from_decimal(d),
 
             apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
         }
     }
 
@@ -293,6 +411,8 @@ impl Number {
         Number {
             value: NumericValue::from_bigdecimal(bd),
             apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
         }
     }
 
@@ -330,6 +450,7 @@ impl Number {
         use num_traits::Zero;
         match &self.value {
             NumericValue::Rational(r, _) => r.is_zero(),
+            NumericValue::BigRational(r) => r.is_zero(),
             NumericValue::Decimal(d) => d.is_zero(),
             NumericValue::BigDecimal(bd) => bd.is_zero(),
             NumericValue::NegativeZero => true,
@@ -338,7 +459,7 @@ impl Number {
     }
 
     // Introspection
-    pub fn representation(&self) -> &'static str {
+    pub fn representation(&self) -> Repr {
         self.value.representation()
     }
 
@@ -354,6 +475,77 @@ impl Number {
         matches!(self.apprx, Some(ApproximationType::RationalApproximation))
     }
 
+    /// Where this value first became approximate (`provenance` feature).
+    ///
+    /// Returns `None` for exact values, and for approximate values whose origin
+    /// wasn't recorded (e.g. deserialized ones).
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::Number;
+    ///
+    /// let total = Number::from(10) + Number::from(2).sqrt() * Number::from(3);
+    /// let origin = total.explain().unwrap();
+    /// assert_eq!(origin.op(), "sqrt");
+    /// println!("{}", origin); // sqrt(2) at src/main.rs:4:33 (Transcendental)
+    /// ```
+    #[cfg(feature = "provenance")]
+    pub fn explain(&self) -> Option<&crate::Provenance> {
+        self.provenance.get()
+    }
+
+    /// Upper bound on the absolute error `|true value - self|`.
+    ///
+    /// Exact numbers return `Some(0.0)`. Approximate numbers return the bound seeded
+    /// by the transcendental that produced them and propagated through `+ - * /`.
+    /// Returns `None` when the bound is unknown, e.g. after `%`, bitwise ops, or
+    /// deserialization. The bound is rounded upward, so it may be loose but is never
+    /// smaller than the true error.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::Number;
+    ///
+    /// let x = Number::from(2).sqrt() * Number::from(3).sqrt();
+    /// let bound = x.error_bound().unwrap();
+    /// assert!(bound > 0.0 && bound < 1e-12);
+    ///
+    /// // |x - sqrt(6)| is guaranteed to be within bound
+    /// let diff = (x - Number::from(6).sqrt()).abs().to_f64();
+    /// assert!(diff <= bound + Number::from(6).sqrt().error_bound().unwrap());
+    /// ```
+    pub fn error_bound(&self) -> Option<f64> {
+        if self.apprx.is_none() {
+            Some(0.0)
+        } else if crate::error_bound::beyond_f64(&self.value) {
+            // Held relative to the value; the absolute bound is past f64 too
+            self.err_bound
+                .map(|rel| if rel == 0.0 { 0.0 } else { f64::INFINITY })
+        } else {
+            self.err_bound
+        }
+    }
+
+    /// Upper bound on the relative error `|true value - self| / |self|`.
+    ///
+    /// Also known past f64's range, where `error_bound` can only say +∞. Returns
+    /// `None` when the bound is unknown or `self` is zero or not finite.
+    pub fn relative_error_bound(&self) -> Option<f64> {
+        if crate::error_bound::beyond_f64(&self.value) {
+            return if self.apprx.is_none() {
+                Some(0.0)
+            } else {
+                self.err_bound
+            };
+        }
+        let bound = self.error_bound()?;
+        let magnitude = self.to_f64().abs();
+        if magnitude == 0.0 || !magnitude.is_finite() {
+            return None;
+        }
+        Some((bound / (magnitude * (1.0 - 4.0 * f64::EPSILON))).next_up())
+    }
+
     /// Returns complete information about this Number's internal state.
     ///
     /// This is useful for debugging and understanding how the library
@@ -430,25 +622,123 @@ impl Number {
         }
     }
 
+    /// Extract the exact rational value if stored internally as Rational or BigRational
+    pub fn to_big_rational(&self) -> Option<BigRational> {
+        match &self.value {
+            NumericValue::Rational(r, _) => {
+                Some(crate::representation::rational_to_big_rational(r))
+            }
+            NumericValue::BigRational(r) => Some(r.clone()),
+            _ => None,
+        }
+    }
+
     pub(crate) fn value(&self) -> &NumericValue {
         &self.value
     }
 
+    /// Recover an exact Rational using continued fractions with denominators up to `max_denom`.
+    ///
+    /// Like the automatic demotion after arithmetic, but with a per-call limit instead of the
+    /// thread's `ArithmeticContext`. A candidate is only accepted when converting it back
+    /// reproduces every digit of the stored value, so a larger limit never produces a
+    /// rational that doesn't match. Returns `self` unchanged when no rational is found.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::Number;
+    /// use bigdecimal::BigDecimal;
+    /// use std::str::FromStr;
+    ///
+    /// // 3 / 2^40, stored as its exact 40-digit decimal expansion
+    /// let bd = BigDecimal::from_str("0.0000000000027284841053187847137451171875").unwrap();
+    /// let n = Number::from_bigdecimal(bd);
+    /// assert_eq!(n.clone().recover_rational_with_limit(1_000_000_000).representation(), "BigDecimal");
+    /// assert_eq!(n.recover_rational_with_limit(1 << 40).representation(), "Rational");
+    /// ```
+    pub fn recover_rational_with_limit(self, max_denom: i64) -> Number {
+        let recovered = self.clone().try_demote_with_limit(max_denom.max(1));
+        // Demotion may also move BigDecimal to Decimal, which isn't a recovered rational
+        if matches!(recovered.value, NumericValue::Rational(..)) {
+            recovered
+        } else {
+            self
+        }
+    }
+
+    /// Convert to the given representation without changing the value.
+    ///
+    /// Fails instead of rounding: e.g. 1/3 can't be a `Decimal`, and NaN can only be `NaN`.
+    /// Approximation flags and error bounds carry over unchanged. Arithmetic normalizes
+    /// its results, so the chosen representation isn't kept through later operations.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::{Number, Repr};
+    ///
+    /// let half = Number::from(1) / Number::from(2);
+    /// assert_eq!(half.to_repr(Repr::Decimal).unwrap().representation(), Repr::Decimal);
+    ///
+    /// let third = Number::from(1) / Number::from(3);
+    /// assert!(third.to_repr(Repr::Decimal).is_err());
+    /// ```
+    pub fn to_repr(&self, target: Repr) -> Result<Number, crate::ExactnessError> {
+        match self.value.convert_to(target) {
+            Ok(value) => Ok(Number {
+                value,
+                apprx: self.apprx.clone(),
+                err_bound: self.err_bound,
+                provenance: self.provenance.clone(),
+            }),
+            Err(kind) => Err(crate::ExactnessError {
+                op: "to_repr",
+                kind,
+                lhs: Box::new(self.clone()),
+                rhs: None,
+            }),
+        }
+    }
+
+    /// Move to the simplest representation that holds the value exactly.
+    ///
+    /// The same demotion arithmetic applies to its results (Decimal → Rational,
+    /// BigDecimal → Decimal, BigRational → Rational), using the thread's recovery limit.
+    /// Unlike arithmetic, approximation flags are kept: demoting doesn't make a value exact.
+    pub fn demote(self) -> Number {
+        let apprx = self.apprx.clone();
+        let err_bound = self.err_bound;
+        let provenance = self.provenance.clone();
+        Number {
+            apprx,
+            err_bound,
+            provenance,
+            ..self.try_demote()
+        }
+    }
+
     /// Try to demote to simpler representation after operation
     /// This is called after arithmetic operations to recover exact representations when possible
     #[inline]
     pub(crate) fn try_demote(self) -> Self {
+        let max_denom = crate::context::max_recovery_denominator();
+        self.try_demote_with_limit(max_denom)
+    }
+
+    #[inline]
+    fn try_demote_with_limit(self, max_denom: i64) -> Self {
         match &self.value {
             NumericValue::BigDecimal(bd) => {
                 // CRITICAL: Check magnitude BEFORE attempting rational recovery
                 // Only try expensive continued fractions if value is small enough
                 if is_small_enough_for_rational(bd) {
                     // Value is small - worth trying rational recovery
-                    if let Some(rat) = try_decimal_to_rational_bigdecimal(bd) {
+                    if let Some(rat) = try_decimal_to_rational_bigdecimal(bd, max_denom) {
                         let is_term = is_terminating_decimal(*rat.numer(), *rat.denom());
                         return Number {
                             value: NumericValue::Rational(rat, is_term),
                             apprx: None,
+                            err_bound: None,
+                            provenance: Trail::NONE,
                         };
                     }
                 }
@@ -462,6 +752,8 @@ impl Number {
                     return Number {
                         value: NumericValue::from_decimal(dec),
                         apprx: self.apprx,
+                        err_bound: self.err_bound,
+                        provenance: self.provenance,
                     };
                 }
 
@@ -470,11 +762,26 @@ impl Number {
             }
             NumericValue::Decimal(d) => {
                 // Try Rational recovery from Decimal
-                if let Some(rat) = try_decimal_to_rational(*d) {
+                if let Some(rat) = try_decimal_to_rational_with_limit(*d, max_denom) {
                     let is_term = is_terminating_decimal(*rat.numer(), *rat.denom());
                     return Number {
                         value: NumericValue::Rational(rat, is_term),
                         apprx: None, // Flag cleared if it was set
+                        err_bound: None,
+                        provenance: Trail::NONE,
+                    };
+                }
+                self
+            }
+            NumericValue::BigRational(r) => {
+                // Numerator and denominator shrank back into i64 range
+                if let Some(rat) = crate::representation::try_big_rational_to_rational64(r) {
+                    let is_term = is_terminating_decimal(*rat.numer(), *rat.denom());
+                    return Number {
+                        value: NumericValue::Rational(rat, is_term),
+                        apprx: self.apprx,
+                        err_bound: self.err_bound,
+                        provenance: self.provenance,
                     };
                 }
                 self
@@ -486,7 +793,7 @@ impl Number {
     /// Set the default precision for high-precision transcendental operations.
     ///
     /// This is a convenience method that calls `crate::precision::set_default_precision`.
-    /// When the `high_precision` feature is enabled, this controls the precision (in bits)
+    /// When the `high_precision` or `high_precision_pure` feature is enabled, this controls the precision (in bits)
     /// used for transcendental operations like sin, cos, log, exp, etc.
     ///
     /// # Arguments
@@ -496,7 +803,7 @@ impl Number {
     /// ```
     /// use faithful_number::Number;
     ///
-    /// #[cfg(feature = "high_precision")]
+    /// #[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
     /// {
     ///     Number::set_default_precision(200);
     ///     let result = Number::from(2).sqrt();
@@ -508,7 +815,7 @@ impl Number {
 
     /// Get the current default precision in bits.
     ///
-    /// Returns 0 when the `high_precision` feature is disabled (uses f64).
+    /// Returns 0 when neither high-precision feature is enabled (uses f64).
     pub fn get_default_precision() -> u32 {
         crate::precision::get_default_precision()
     }
@@ -566,7 +873,15 @@ pub(crate) fn is_small_enough_for_rational(bd: &BigDecimal) -> bool {
 /// rational, when converted back to Decimal, matches the original input exactly across
 /// all 28 digits. This prevents false positives where a value close to (but not exactly)
 /// a simple fraction gets incorrectly marked as exact.
+///
+/// Always uses the default `CF_MAX_DENOM`, so callers that must be deterministic
+/// (e.g. `Hash`) don't depend on the thread's `ArithmeticContext`.
 pub(crate) fn try_decimal_to_rational(d: Decimal) -> Option<Rational64> {
+    try_decimal_to_rational_with_limit(d, CF_MAX_DENOM)
+}
+
+/// `try_decimal_to_rational` with an explicit continued fractions denominator limit.
+pub(crate) fn try_decimal_to_rational_with_limit(d: Decimal, max_denom: i64) -> Option<Rational64> {
     // Early exit for very large or very small numbers
     // Skip expensive continued fractions algorithm if value can't possibly fit in i64/i64
     // Use same threshold as is_small_enough_for_rational
@@ -607,12 +922,32 @@ pub(crate) fn try_decimal_to_rational(d: Decimal) -> Option<Rational64> {
     println!("Direct conversion failed, using rational_approximation");
 
     // If direct conversion failed, use continued fractions
-    let candidate = rational_approximation(d, CF_MAX_DENOM)?;
+    let candidate = rational_approximation(d, max_denom)?;
+    if !has_digits_for(candidate, d.scale() as i64) {
+        return None;
+    }
 
     // CRITICAL: Verify the candidate matches exactly
     verify_exact_match(d, candidate)
 }
 
+/// Check that a value verified to `digits` decimal places is precise enough to trust a
+/// CF candidate. Denominators within `CF_MAX_DENOM` keep the behaviour of decision 011;
+/// beyond it, q² must stay `CF_DIGIT_MARGIN` digits below 10^digits, otherwise a large
+/// limit would find *some* fraction matching almost any value.
+fn has_digits_for(candidate: Rational64, digits: i64) -> bool {
+    let q = *candidate.denom();
+    if q <= CF_MAX_DENOM {
+        return true;
+    }
+    // q > 10^9, so q² can't overflow i128; compare q² < 10^(digits - margin)
+    let needed = digits - CF_DIGIT_MARGIN;
+    if needed > 38 {
+        return true;
+    }
+    needed > 0 && (q as i128) * (q as i128) < 10i128.pow(needed as u32)
+}
+
 /// Verify that a rational, when converted to Decimal, exactly matches the original
 /// Returns Some(rational) only if all 28 digits match
 fn verify_exact_match(original: Decimal, candidate: Rational64) -> Option<Rational64> {
@@ -631,80 +966,99 @@ fn verify_exact_match(original: Decimal, candidate: Rational64) -> Option<Ration
     (reconstructed == original).then_some(candidate)
 }
 
-/// Find the best rational approximation using integer-only continued fractions
-/// with denominator bounded by max_denom.
-///
-/// This is 2-3x faster than the Decimal-based version and perfectly accurate
-/// because it uses pure integer arithmetic (no floating point rounding errors).
-fn rational_approximation(d: Decimal, max_denom: i64) -> Option<Rational64> {
-    #[cfg(test)]
-    println!("rational_approximation: d={}", d);
-
-    let sign = if d < Decimal::ZERO { -1 } else { 1 };
-    let d = d.abs();
-
-    // Extract mantissa/scale directly from Decimal
-    let mantissa = d.mantissa();
-    let scale = d.scale();
-    let scale_factor = 10i128.pow(scale);
-
-    // Now we have: d = mantissa / scale_factor (exact!)
-    // Perform CF on (mantissa, scale_factor) using pure integer arithmetic
-
-    let mut a = mantissa;
-    let mut b = scale_factor;
-
-    let mut p_prev2 = 1i128;
-    let mut q_prev2 = 0i128;
-
-    let a0 = a / b;
-    let mut p_prev1 = a0;
-    let mut q_prev1 = 1i128;
-
-    a %= b; // Remainder - pure integer op
-
-    let (mut best_n, mut best_d) = (a0, 1i128);
+/// ⌊p / q⌋ for q > 0
+pub(crate) fn floor_div(p: &BigInt, q: &BigInt) -> BigInt {
+    let quotient = p / q;
+    if p.is_negative() && !(p % q).is_zero() {
+        quotient - 1
+    } else {
+        quotient
+    }
+}
 
-    #[cfg(test)]
-    println!("a0={}, best={}/{}", a0, best_n, best_d);
+/// Continued-fraction expansion of p/q (q > 0) by the Euclidean algorithm,
+/// yielding each term [a0; a1, a2, ...] with its convergent h/k.
+///
+/// Terms are floors, so a negative p starts with a negative a0. This is the one
+/// engine behind rational recovery here and the public `continued_fraction`,
+/// `convergents` and `limit_denominator` (see `continued_fraction.rs`).
+pub(crate) struct ContinuedFraction {
+    p: BigInt,
+    q: BigInt,
+    /// (h_{n-2}, h_{n-1}) and (k_{n-2}, k_{n-1})
+    h: (BigInt, BigInt),
+    k: (BigInt, BigInt),
+}
 
-    for _iter in 0..100 {
-        if a == 0 {
-            break;
+impl ContinuedFraction {
+    pub(crate) fn new(p: BigInt, q: BigInt) -> Self {
+        ContinuedFraction {
+            p,
+            q,
+            h: (BigInt::zero(), BigInt::one()),
+            k: (BigInt::one(), BigInt::zero()),
         }
+    }
 
-        // Standard Euclidean algorithm for CF: work with b/a (reciprocal)
-        let a_n = b / a;
+    pub(crate) fn of(value: &BigRational) -> Self {
+        Self::new(value.numer().clone(), value.denom().clone())
+    }
+}
 
-        // If a_n is 0, something is wrong (shouldn't happen if a != 0)
-        if a_n == 0 {
-            break;
-        }
+impl Iterator for ContinuedFraction {
+    /// (term, h, k)
+    type Item = (BigInt, BigInt, BigInt);
 
-        let r = b % a; // Remainder
-        b = a;
-        a = r;
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.q.is_zero() {
+            return None;
+        }
+        let a = floor_div(&self.p, &self.q);
+        let r = &self.p - &a * &self.q;
+        self.p = std::mem::replace(&mut self.q, r);
 
-        let p_n = a_n.checked_mul(p_prev1)?.checked_add(p_prev2)?;
-        let q_n = a_n.checked_mul(q_prev1)?.checked_add(q_prev2)?;
+        let h = &a * &self.h.1 + &self.h.0;
+        let k = &a * &self.k.1 + &self.k.0;
+        self.h = (std::mem::replace(&mut self.h.1, h.clone()), h.clone());
+        self.k = (std::mem::replace(&mut self.k.1, k.clone()), k.clone());
+        Some((a, h, k))
+    }
+}
 
-        if q_n > max_denom as i128 {
+/// The last convergent of |p|/q with denominator at most max_denom, carrying the
+/// sign of p. None when it doesn't fit Rational64.
+fn last_convergent_within(p: BigInt, q: BigInt, max_denom: i64) -> Option<Rational64> {
+    let negative = p.is_negative();
+    let mut expansion = ContinuedFraction::new(p.abs(), q);
+    // The first convergent a0/1 is always taken
+    let (_, mut best_n, mut best_d) = expansion.next()?;
+    for (_, h, k) in expansion {
+        if k > BigInt::from(max_denom) {
             break;
         }
-
-        best_n = p_n;
-        best_d = q_n;
-
-        p_prev2 = p_prev1;
-        p_prev1 = p_n;
-        q_prev2 = q_prev1;
-        q_prev1 = q_n;
+        (best_n, best_d) = (h, k);
     }
 
-    let final_n: i64 = (sign as i128 * best_n).try_into().ok()?;
-    let final_d: i64 = best_d.try_into().ok()?;
+    let final_n = best_n.to_i64()?;
+    let final_d = best_d.to_i64()?;
+    Some(Ratio::new(
+        if negative { -final_n } else { final_n },
+        final_d,
+    ))
+}
 
-    Some(Ratio::new(final_n, final_d))
+/// Find the best rational approximation using integer-only continued fractions
+/// with denominator bounded by max_denom.
+///
+/// Runs on the exact mantissa and power-of-ten scale, so no floating point
+/// rounding gets in.
+fn rational_approximation(d: Decimal, max_denom: i64) -> Option<Rational64> {
+    #[cfg(test)]
+    println!("rational_approximation: d={}", d);
+
+    // d = mantissa / 10^scale exactly
+    let scale_factor = BigInt::from(10).pow(d.scale());
+    last_convergent_within(BigInt::from(d.mantissa()), scale_factor, max_denom)
 }
 
 /// Try to downgrade BigDecimal to Decimal if it fits
@@ -736,17 +1090,20 @@ pub(crate) fn try_bigdecimal_to_decimal(bd: &BigDecimal) -> Option<Decimal> {
 
 /// Try to recover exact rational from BigDecimal using continued fractions
 /// ASSUMES: magnitude check already performed by caller
-fn try_decimal_to_rational_bigdecimal(bd: &BigDecimal) -> Option<Rational64> {
+fn try_decimal_to_rational_bigdecimal(bd: &BigDecimal, max_denom: i64) -> Option<Rational64> {
+    // Verification is against the full BigDecimal, so all of its digits count
+    let digits = bd.as_bigint_and_exponent().1;
+
     // First, try direct BigDecimal→Decimal conversion
     if let Some(d) = try_bigdecimal_to_decimal(bd) {
         // Use existing Decimal→Rational logic with consistent limit
-        let candidate = rational_approximation(d, CF_MAX_DENOM)?;
+        let candidate = rational_approximation(d, max_denom)?;
 
         // CRITICAL: Verify exact match by converting back to BigDecimal
         let reconstructed =
             BigDecimal::from(*candidate.numer()) / BigDecimal::from(*candidate.denom());
 
-        if reconstructed == *bd {
+        if reconstructed == *bd && has_digits_for(candidate, digits) {
             return Some(candidate);
         }
     }
@@ -758,18 +1115,30 @@ fn try_decimal_to_rational_bigdecimal(bd: &BigDecimal) -> Option<Rational64> {
     let bd_rounded = bd.with_prec(28);
 
     if let Some(d) = try_bigdecimal_to_decimal(&bd_rounded) {
-        let candidate = rational_approximation(d, CF_MAX_DENOM)?;
+        let candidate = rational_approximation(d, max_denom)?;
 
         // CRITICAL: Verify exact match against ORIGINAL BigDecimal
         let reconstructed =
             BigDecimal::from(*candidate.numer()) / BigDecimal::from(*candidate.denom());
 
-        if reconstructed == *bd {
+        if reconstructed == *bd && has_digits_for(candidate, digits) {
             return Some(candidate);
         }
+        return None;
     }
 
-    None
+    // Scale beyond Decimal's 28 digits even after rounding (e.g. 3/2^40): run
+    // continued fractions on the exact mantissa instead
+    let candidate = rational_approximation_bigdecimal(bd, max_denom)?;
+    let reconstructed = BigDecimal::from(*candidate.numer()) / BigDecimal::from(*candidate.denom());
+    (reconstructed == *bd && has_digits_for(candidate, digits)).then_some(candidate)
+}
+
+/// `rational_approximation` on a BigDecimal's exact mantissa and scale.
+fn rational_approximation_bigdecimal(bd: &BigDecimal, max_denom: i64) -> Option<Rational64> {
+    let (mantissa, scale) = bd.as_bigint_and_exponent();
+    let scale: u32 = scale.try_into().ok()?;
+    last_convergent_within(mantissa, BigInt::from(10).pow(scale), max_denom)
 }
 
 #[cfg(test)]
@@ -849,6 +1218,29 @@ mod test_demot {
         );
     }
 
+    #[test]
+    fn test_bigdecimal_cf_beyond_decimal_scale() {
+        // 3/2^40 has 40 decimal places, more than Decimal can hold
+        let bd = BigDecimal::from(3) / BigDecimal::from(1i64 << 40);
+        assert_eq!(
+            rational_approximation_bigdecimal(&bd, 1 << 40),
+            Some(Ratio::new(3, 1 << 40))
+        );
+        assert_eq!(try_decimal_to_rational_bigdecimal(&bd, CF_MAX_DENOM), None);
+        assert_eq!(
+            try_decimal_to_rational_bigdecimal(&bd, 1 << 40),
+            Some(Ratio::new(3, 1 << 40))
+        );
+    }
+
+    #[test]
+    fn test_large_denominator_needs_digits() {
+        let candidate = Ratio::new(1, (1i64 << 40) + 1);
+        assert!(!has_digits_for(candidate, 28));
+        assert!(has_digits_for(candidate, 40));
+        assert!(has_digits_for(Ratio::new(1, 3), 0));
+    }
+
     #[test]
     fn test_exact_one_third_matches() {
         // True 1/3 in Decimal: 0.3333333333333333333333333333 (28 threes)
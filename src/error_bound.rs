@@ -0,0 +1,576 @@
+// Rigorous absolute error bounds for approximate Numbers
+// Contains: operand capture, propagation through + − × ÷, seeding for transcendentals
+//
+// A bound is an f64 upper bound on |true value − stored value|. Every step rounds
+// upward, so a bound can be loose but never under-reports. The f64 fallback paths
+// assume the platform libm is accurate to within a couple of ulps.
+
+use crate::Number;
+use crate::core::NumericValue;
+
+/// Relative error of the f64 pipeline: input conversion, libm, and conversion back
+/// to Decimal, with margin.
+const F64_RELATIVE_ERROR: f64 = 8.0 * f64::EPSILON;
+
+/// Relative error of a BigDecimal computed at BigDecimal's default precision (100 digits)
+const BIGDECIMAL_RELATIVE_ERROR: f64 = 1e-98;
+
+/// Relative rounding error of a 28-digit Decimal result
+const DECIMAL_RELATIVE_ERROR: f64 = 1e-27;
+
+/// Smallest Decimal step (scale 28): the absolute floor of any Decimal rounding
+const DECIMAL_ABSOLUTE_ERROR: f64 = 1e-28;
+
+/// Round a non-negative bound up by one ulp; NaN becomes +∞
+#[inline]
+fn up(x: f64) -> f64 {
+    if x.is_nan() {
+        f64::INFINITY
+    } else {
+        x.next_up()
+    }
+}
+
+/// Round a non-negative value down by one ulp, never below zero
+#[inline]
+fn down(x: f64) -> f64 {
+    if x.is_nan() {
+        0.0
+    } else {
+        x.next_down().max(0.0)
+    }
+}
+
+/// Upper bound on |x|. to_f64 is within a few ulps, so inflate before rounding up.
+#[inline]
+fn inflate(x: f64) -> f64 {
+    up(x.abs() * (1.0 + 4.0 * f64::EPSILON))
+}
+
+/// Lower bound on |x|, the counterpart of `inflate`
+#[inline]
+fn deflate(x: f64) -> f64 {
+    down(x.abs() * (1.0 - 4.0 * f64::EPSILON))
+}
+
+/// Whether `v` is finite but too large for f64. An absolute bound on such a value
+/// would overflow, so `Number::err_bound` holds one relative to |v| instead.
+pub(crate) fn beyond_f64(v: &NumericValue) -> bool {
+    v.is_finite() && !v.to_f64().is_finite()
+}
+
+fn magnitude_upper(v: &NumericValue) -> f64 {
+    inflate(v.to_f64())
+}
+
+/// Rounding error an approximate result may have picked up from its own representation
+fn representation_error(v: &NumericValue) -> f64 {
+    match v {
+        NumericValue::Rational(_, _) | NumericValue::BigRational(_) => 0.0,
+        NumericValue::NegativeZero => 0.0,
+        NumericValue::Decimal(_) => {
+            up(magnitude_upper(v) * DECIMAL_RELATIVE_ERROR + DECIMAL_ABSOLUTE_ERROR)
+        }
+        NumericValue::BigDecimal(_) => up(magnitude_upper(v) * BIGDECIMAL_RELATIVE_ERROR),
+        NumericValue::NaN | NumericValue::PositiveInfinity | NumericValue::NegativeInfinity => {
+            f64::INFINITY
+        }
+    }
+}
+
+/// Relative error of a transcendental result, based on the path that produced it
+fn working_relative_error(result: &NumericValue) -> f64 {
+    match result {
+        NumericValue::BigDecimal(_) => {
+            #[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
+            {
+                // MPFR is correctly rounded and the pure-Rust series round to the
+                // requested digits from guard digits; allow for input and output conversion
+                let bits = crate::precision::get_default_precision() as i32;
+                BIGDECIMAL_RELATIVE_ERROR.max(2f64.powi(4 - bits))
+            }
+            #[cfg(not(any(feature = "high_precision", feature = "high_precision_pure")))]
+            {
+                BIGDECIMAL_RELATIVE_ERROR
+            }
+        }
+        _ => F64_RELATIVE_ERROR,
+    }
+}
+
+/// Value, magnitude and absolute error of an operand, captured before it is consumed.
+#[derive(Debug, Clone, Copy)]
+pub(crate) struct Tracked {
+    value: f64,
+    magnitude: f64,
+    magnitude_low: f64,
+    error: f64,
+}
+
+impl Tracked {
+    /// Capture one operand. None if it is approximate without a known bound.
+    pub(crate) fn of(n: &Number) -> Option<Tracked> {
+        let error = n.error_bound()?;
+        let value = n.value.to_f64();
+        Some(Tracked {
+            value,
+            magnitude: inflate(value),
+            magnitude_low: deflate(value),
+            error,
+        })
+    }
+
+    /// Interval [lo, hi] around the value that covers its own error plus the
+    /// conversion to working precision at relative error `rel`
+    fn interval(&self, rel: f64) -> (f64, f64, f64) {
+        let radius = up(self.error + up(self.magnitude * rel));
+        (
+            (self.value - radius).next_down(),
+            (self.value + radius).next_up(),
+            radius,
+        )
+    }
+
+    /// Capture both operands of a binary op.
+    ///
+    /// When both are exact their magnitudes never enter the bound, so the
+    /// (comparatively costly) f64 conversion is skipped.
+    #[inline]
+    pub(crate) fn pair(a: &Number, b: &Number) -> Option<(Tracked, Tracked)> {
+        if a.apprx.is_none() && b.apprx.is_none() {
+            let exact = Tracked {
+                value: 0.0,
+                magnitude: 0.0,
+                magnitude_low: 0.0,
+                error: 0.0,
+            };
+            return Some((exact, exact));
+        }
+        Some((Tracked::of(a)?, Tracked::of(b)?))
+    }
+}
+
+/// A constant rounded to `digits` significant digits: within 10^(1 − digits)
+/// relative, which leaves room for the working-precision computation behind it
+pub(crate) fn constant(result: &NumericValue, digits: u64) -> f64 {
+    let exponent = i32::try_from(digits).unwrap_or(i32::MAX);
+    let rounding = up(magnitude_upper(result) * 10f64.powi(1 - exponent));
+    up(rounding + representation_error(result))
+}
+
+/// A rational chosen `distance` away from an input's stored value: the distance
+/// plus the input's own error
+pub(crate) fn rational_approximation(
+    input: Option<Tracked>,
+    distance: &num_rational::BigRational,
+) -> Option<f64> {
+    use num_traits::ToPrimitive;
+    let distance = inflate(distance.to_f64().unwrap_or(f64::INFINITY));
+    Some(up(input?.error + distance))
+}
+
+/// |(a ± b) − (ã ± b̃)| ≤ ea + eb
+pub(crate) fn add(a: Tracked, b: Tracked, result: &NumericValue) -> f64 {
+    up(up(a.error + b.error) + representation_error(result))
+}
+
+/// |ab − ãb̃| ≤ |ã|eb + |b̃|ea + ea·eb
+pub(crate) fn mul(a: Tracked, b: Tracked, result: &NumericValue) -> f64 {
+    let propagated =
+        up(up(a.magnitude * b.error) + up(b.magnitude * a.error) + up(a.error * b.error));
+    up(propagated + representation_error(result))
+}
+
+/// |(ab + c) − (ãb̃ + c̃)| ≤ |ã|eb + |b̃|ea + ea·eb + ec, with a single rounding
+pub(crate) fn mul_add(a: Tracked, b: Tracked, c: Tracked, result: &NumericValue) -> f64 {
+    let propagated =
+        up(up(a.magnitude * b.error) + up(b.magnitude * a.error) + up(a.error * b.error));
+    up(up(propagated + c.error) + representation_error(result))
+}
+
+/// |a/b − ã/b̃| ≤ (|ã|eb + |b̃|ea) / (|b̃|(|b̃| − eb)), provided |b̃| > eb
+pub(crate) fn div(a: Tracked, b: Tracked, result: &NumericValue) -> f64 {
+    if a.error == 0.0 && b.error == 0.0 {
+        return representation_error(result);
+    }
+    let b_low = b.magnitude_low;
+    let margin = down(b_low - b.error);
+    if margin <= 0.0 {
+        // The divisor's interval contains zero
+        return f64::INFINITY;
+    }
+    let numer = up(up(a.magnitude * b.error) + up(b.magnitude * a.error));
+    let denom = down(b_low * margin);
+    up(up(numer / denom) + representation_error(result))
+}
+
+/// Bound for f(x) given sup |f'| over [x − r, x + r], where r covers the input's
+/// own error plus its conversion to working precision.
+pub(crate) fn transcendental(
+    input: Option<Tracked>,
+    result: &NumericValue,
+    derivative_sup: impl Fn(f64, f64) -> f64,
+) -> Option<f64> {
+    let input = input?;
+    let rel = working_relative_error(result);
+    let (lo, hi, radius) = input.interval(rel);
+
+    // The slope itself is evaluated in f64; leave room for its own rounding
+    let slope = up(derivative_sup(lo, hi) * (1.0 + 8.0 * f64::EPSILON));
+    let propagated = up(slope * radius);
+    let rounding = up(magnitude_upper(result) * rel);
+    Some(up(up(propagated + rounding) + representation_error(result)))
+}
+
+/// Bound for f(a, b) of two inputs given sup |∂f/∂a| and sup |∂f/∂b| over the box.
+pub(crate) fn transcendental2(
+    a: Option<Tracked>,
+    b: Option<Tracked>,
+    result: &NumericValue,
+    partials_sup: impl Fn((f64, f64), (f64, f64)) -> (f64, f64),
+) -> Option<f64> {
+    let (a, b) = (a?, b?);
+    let rel = working_relative_error(result);
+    let (a_lo, a_hi, ra) = a.interval(rel);
+    let (b_lo, b_hi, rb) = b.interval(rel);
+
+    let (da, db) = partials_sup((a_lo, a_hi), (b_lo, b_hi));
+    let da = up(da * (1.0 + 8.0 * f64::EPSILON));
+    let db = up(db * (1.0 + 8.0 * f64::EPSILON));
+    let propagated = up(up(da * ra) + up(db * rb));
+    let rounding = up(magnitude_upper(result) * rel);
+    Some(up(up(propagated + rounding) + representation_error(result)))
+}
+
+/// Absolute error in ln Γ(x) (so relative error in Γ(x)) of the f64 Lanczos
+/// fallback. The series itself is good to ~1e-15, but rounding of t^(x + 1/2)
+/// grows with |x| ln|x|, and the reflection adds the error of ln|sin πx|.
+fn lanczos_error(x: f64) -> f64 {
+    let reflection = if x < 0.5 {
+        crate::math::sin_pi(x).abs().ln().abs()
+    } else {
+        0.0
+    };
+    let scale = (x.abs() + 1.0) * ((x.abs() + 8.0).ln() + 1.0) + reflection + 10.0;
+    up(64.0 * f64::EPSILON * scale)
+}
+
+/// Whether a gamma result came from the f64 Lanczos fallback rather than MPFR
+fn from_lanczos(result: &NumericValue) -> bool {
+    !(cfg!(feature = "high_precision") && matches!(result, NumericValue::BigDecimal(_)))
+}
+
+pub(crate) fn gamma(input: Option<Tracked>, result: &NumericValue) -> Option<f64> {
+    let x = input?.value;
+    if beyond_f64(result) {
+        return Some(gamma_relative(input?, result));
+    }
+    let bound = transcendental(input, result, gamma_slope)?;
+    if !from_lanczos(result) {
+        return Some(bound);
+    }
+    Some(up(bound + up(magnitude_upper(result) * lanczos_error(x))))
+}
+
+/// Relative bound on a Γ(x) past f64's range (see `beyond_f64`): the error in
+/// ln Γ(x) is the input's error through ψ plus the evaluation's own.
+fn gamma_relative(input: Tracked, result: &NumericValue) -> f64 {
+    let rel = working_relative_error(result);
+    let (lo, hi, radius) = input.interval(rel);
+    let propagated = up(ln_gamma_slope(lo, hi) * radius);
+    let evaluation = if from_lanczos(result) {
+        up(lanczos_error(input.value) + kept_digits_error(result))
+    } else {
+        rel
+    };
+    relative_from_ln(up(propagated + evaluation))
+}
+
+/// Relative bound on C(n, k) from ln Γ (kept relative, see `beyond_f64`): the
+/// Lanczos error of each of the three ln Γ terms and the digits the result keeps
+pub(crate) fn binomial(n: f64, k: f64, result: &NumericValue) -> Option<f64> {
+    if !beyond_f64(result) {
+        // Even the exponent was out of reach
+        return None;
+    }
+    let evaluation =
+        up(up(lanczos_error(n + 1.0) + lanczos_error(k + 1.0)) + lanczos_error(n - k + 1.0));
+    Some(relative_from_ln(up(evaluation + kept_digits_error(result))))
+}
+
+/// Relative rounding error of a result built from f64 logarithms, which keeps only
+/// the digits the logarithm carries
+fn kept_digits_error(result: &NumericValue) -> f64 {
+    let digits = match result {
+        NumericValue::BigDecimal(bd) => i32::try_from(bd.digits()).unwrap_or(i32::MAX),
+        _ => 1,
+    };
+    10f64.powi(1 - digits)
+}
+
+/// Relative error of e^ln given an absolute error δ in ln: e^δ − 1 <= 2δ for δ <= 1
+fn relative_from_ln(delta: f64) -> f64 {
+    if delta > 1.0 {
+        f64::INFINITY
+    } else {
+        up(2.0 * delta)
+    }
+}
+
+pub(crate) fn ln_gamma(input: Option<Tracked>, result: &NumericValue) -> Option<f64> {
+    let x = input?.value;
+    let bound = transcendental(input, result, ln_gamma_slope)?;
+    if !from_lanczos(result) {
+        return Some(bound);
+    }
+    Some(up(bound + lanczos_error(x)))
+}
+
+// Derivative bounds over [lo, hi] for the transcendental functions in math.rs.
+// Any interval that touches a singularity gets an infinite slope.
+
+pub(crate) fn sqrt_slope(lo: f64, _hi: f64) -> f64 {
+    if lo > 0.0 {
+        0.5 / lo.sqrt()
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// d/dx x^(1/n) = x^(1/n - 1) / n, largest nearest zero
+pub(crate) fn root_slope(n: u32) -> impl Fn(f64, f64) -> f64 {
+    move |lo, hi| {
+        if n <= 1 {
+            return 1.0;
+        }
+        if lo > 0.0 || hi < 0.0 {
+            let near = lo.abs().min(hi.abs());
+            let n = f64::from(n);
+            near.powf(1.0 / n - 1.0) / n
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+pub(crate) fn ln_slope(lo: f64, _hi: f64) -> f64 {
+    if lo > 0.0 { 1.0 / lo } else { f64::INFINITY }
+}
+
+pub(crate) fn log10_slope(lo: f64, hi: f64) -> f64 {
+    ln_slope(lo, hi) / std::f64::consts::LN_10
+}
+
+pub(crate) fn log2_slope(lo: f64, hi: f64) -> f64 {
+    ln_slope(lo, hi) / std::f64::consts::LN_2
+}
+
+/// log1p' = 1/(1 + x)
+pub(crate) fn log1p_slope(lo: f64, _hi: f64) -> f64 {
+    if lo > -1.0 {
+        1.0 / (1.0 + lo)
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// Also the slope of expm1
+pub(crate) fn exp_slope(_lo: f64, hi: f64) -> f64 {
+    hi.exp()
+}
+
+/// sin, cos, atan, tanh and asinh are 1-Lipschitz
+pub(crate) fn unit_slope(_lo: f64, _hi: f64) -> f64 {
+    1.0
+}
+
+/// sec² is convex between poles, so its max is at an endpoint
+pub(crate) fn tan_slope(lo: f64, hi: f64) -> f64 {
+    use std::f64::consts::{FRAC_PI_2, PI};
+    if ((lo - FRAC_PI_2) / PI).floor() != ((hi - FRAC_PI_2) / PI).floor() {
+        return f64::INFINITY;
+    }
+    1.0 + lo.tan().powi(2).max(hi.tan().powi(2))
+}
+
+/// asin and acos share |f'| = 1/√(1 − x²)
+pub(crate) fn asin_slope(lo: f64, hi: f64) -> f64 {
+    let m = lo.abs().max(hi.abs());
+    if m < 1.0 {
+        1.0 / (1.0 - m * m).sqrt()
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// sinh' = cosh grows with |x|
+pub(crate) fn sinh_slope(lo: f64, hi: f64) -> f64 {
+    lo.abs().max(hi.abs()).cosh()
+}
+
+/// |cosh'| = |sinh| grows with |x|
+pub(crate) fn cosh_slope(lo: f64, hi: f64) -> f64 {
+    lo.abs().max(hi.abs()).sinh()
+}
+
+/// acosh' = 1/√(x² − 1) is largest at the left endpoint
+pub(crate) fn acosh_slope(lo: f64, _hi: f64) -> f64 {
+    if lo > 1.0 {
+        1.0 / (lo * lo - 1.0).sqrt()
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// atanh' = 1/(1 − x²) is largest at the endpoint farthest from 0
+pub(crate) fn atanh_slope(lo: f64, hi: f64) -> f64 {
+    let m = lo.abs().max(hi.abs());
+    if m < 1.0 {
+        1.0 / (1.0 - m * m)
+    } else {
+        f64::INFINITY
+    }
+}
+
+/// atan2(y, x): both partials are bounded by 1/√(x² + y²)
+pub(crate) fn atan2_partials(y: (f64, f64), x: (f64, f64)) -> (f64, f64) {
+    let nearest = |(lo, hi): (f64, f64)| {
+        if lo <= 0.0 && hi >= 0.0 {
+            0.0
+        } else {
+            lo.abs().min(hi.abs())
+        }
+    };
+    let r = nearest(y).hypot(nearest(x));
+    let slope = if r > 0.0 { 1.0 / r } else { f64::INFINITY };
+    (slope, slope)
+}
+
+/// True when [lo, hi] contains 0 or a negative integer, where Γ has a pole
+fn crosses_gamma_pole(lo: f64, hi: f64) -> bool {
+    lo <= 0.0 && hi.min(0.0).floor() >= lo
+}
+
+/// Γ' = Γψ. Between poles ln|Γ| is convex (ψ' > 0), so Γ'' has the sign of Γ,
+/// Γ' is monotonic, and |Γ'| peaks at an endpoint
+pub(crate) fn gamma_slope(lo: f64, hi: f64) -> f64 {
+    use crate::math::{digamma_f64, gamma_f64};
+    if crosses_gamma_pole(lo, hi) {
+        return f64::INFINITY;
+    }
+    let at = |x: f64| (gamma_f64(x) * digamma_f64(x)).abs();
+    at(lo).max(at(hi))
+}
+
+/// (ln|Γ|)' = ψ, increasing between poles
+pub(crate) fn ln_gamma_slope(lo: f64, hi: f64) -> f64 {
+    use crate::math::digamma_f64;
+    if crosses_gamma_pole(lo, hi) {
+        return f64::INFINITY;
+    }
+    digamma_f64(lo).abs().max(digamma_f64(hi).abs())
+}
+
+/// hypot(a, b): |∂/∂a| = |a| / hypot(a, b) <= 1, likewise for b
+pub(crate) fn hypot_partials(_a: (f64, f64), _b: (f64, f64)) -> (f64, f64) {
+    (1.0, 1.0)
+}
+
+/// base^n for a fixed integer n: |f'| = |n|·|b|^(n−1)
+pub(crate) fn powi_slope(n: i32) -> impl Fn(f64, f64) -> f64 {
+    move |lo, hi| {
+        if n == 0 {
+            return 0.0;
+        }
+        let n = f64::from(n);
+        let far = lo.abs().max(hi.abs());
+        if n >= 1.0 {
+            n * far.powf(n - 1.0)
+        } else if lo > 0.0 || hi < 0.0 {
+            let near = lo.abs().min(hi.abs());
+            n.abs() * near.powf(n - 1.0)
+        } else {
+            f64::INFINITY
+        }
+    }
+}
+
+/// base^exp for a positive base: ∂/∂b = e·b^(e−1), ∂/∂e = b^e·ln b.
+/// Each of b^(e−1), b^e is monotone in both variables, so the max is at a corner.
+pub(crate) fn pow_partials(base: (f64, f64), exp: (f64, f64)) -> (f64, f64) {
+    if base.0 <= 0.0 {
+        return (f64::INFINITY, f64::INFINITY);
+    }
+    let corners = [
+        (base.0, exp.0),
+        (base.0, exp.1),
+        (base.1, exp.0),
+        (base.1, exp.1),
+    ];
+    let max_over = |f: &dyn Fn(f64, f64) -> f64| {
+        corners
+            .iter()
+            .map(|&(b, e)| f(b, e))
+            .fold(0.0f64, |acc, v| acc.max(v))
+    };
+    let e_max = exp.0.abs().max(exp.1.abs());
+    let ln_max = base.0.ln().abs().max(base.1.ln().abs());
+    (
+        e_max * max_over(&|b, e| b.powf(e - 1.0)),
+        max_over(&|b, e| b.powf(e)) * ln_max,
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn tan_slope_is_infinite_across_a_pole() {
+        use std::f64::consts::FRAC_PI_2;
+        assert!(tan_slope(FRAC_PI_2 - 0.1, FRAC_PI_2 + 0.1).is_infinite());
+        assert!((tan_slope(-0.1, 0.1) - (1.0 + 0.1f64.tan().powi(2))).abs() < 1e-15);
+    }
+
+    #[test]
+    fn singular_slopes() {
+        assert!(asin_slope(0.5, 1.0).is_infinite());
+        assert!(ln_slope(-0.1, 0.1).is_infinite());
+        assert!(sqrt_slope(0.0, 1.0).is_infinite());
+        assert!(powi_slope(-2)(-1.0, 1.0).is_infinite());
+        assert_eq!(powi_slope(0)(-1.0, 1.0), 0.0);
+        assert!(gamma_slope(-1.1, -0.9).is_infinite());
+        assert!(gamma_slope(-0.1, 0.1).is_infinite());
+        assert!(ln_gamma_slope(-3.0, -2.5).is_infinite());
+        assert!(gamma_slope(-0.9, -0.1).is_finite());
+    }
+
+    #[test]
+    fn gamma_slope_uses_the_steeper_endpoint() {
+        // Γ'(1) = −γ, Γ'(2) = 1 − γ
+        const EULER_GAMMA: f64 = 0.577_215_664_901_532_9;
+        assert!((gamma_slope(1.0, 2.0) - EULER_GAMMA).abs() < 1e-12);
+        assert!((ln_gamma_slope(1.0, 2.0) - EULER_GAMMA).abs() < 1e-12);
+    }
+
+    #[test]
+    fn powi_slope_uses_the_far_endpoint() {
+        // d/dx x^3 = 3x^2, largest at |x| = 2
+        assert_eq!(powi_slope(3)(-2.0, 1.0), 12.0);
+    }
+
+    #[test]
+    fn root_slope_uses_the_near_endpoint() {
+        // d/dx x^(1/3) = x^(-2/3) / 3, largest at x = 1
+        assert_eq!(root_slope(3)(1.0, 8.0), 1.0 / 3.0);
+        assert_eq!(root_slope(3)(-1.0, 1.0), f64::INFINITY);
+    }
+
+    #[test]
+    fn rounding_helpers_never_cross() {
+        assert!(up(1.0) > 1.0);
+        assert!(down(1.0) < 1.0);
+        assert_eq!(down(0.0), 0.0);
+        assert_eq!(up(f64::NAN), f64::INFINITY);
+    }
+}
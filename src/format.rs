@@ -4,7 +4,13 @@
 //! in various regional and scientific formats, with round-trip parsing support.
 
 use crate::Number;
+use crate::RoundingMode;
+use crate::continued_fraction::{best_approximation, first_convergent_within};
 use crate::core::NumericValue;
+use crate::fraction::{FRACTION_SLASH, SUBSCRIPT_DIGITS, SUPERSCRIPT_DIGITS, VULGAR_FRACTIONS};
+use bigdecimal::num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, Zero};
 
 /// Exponential notation style
 #[repr(u8)]
@@ -26,6 +32,13 @@ pub enum Notation {
     Scientific,
     /// Engineering notation (exponent is multiple of 3)
     Engineering,
+    /// Improper fraction (17/12)
+    Fraction,
+    /// Mixed number (1 5/12)
+    MixedFraction,
+    /// Mixed number with a vulgar fraction glyph (1⅓), or superscript/subscript
+    /// digits when there is none (1⁵⁄₁₂)
+    UnicodeFraction,
 }
 
 /// Regional formatting preferences
@@ -54,6 +67,12 @@ pub struct DisplayOptions {
     pub exp_notation: ExpNotation,
     /// Regional formatting
     pub regional_format: RegionalFormat,
+    /// Largest denominator for fraction notations: the closest fraction within
+    /// the limit is shown (None = no limit)
+    pub max_denominator: Option<i64>,
+    /// Round fraction notations to the nearest multiple of 1/n, ties to even
+    /// (e.g. Some(16) for sixteenths of an inch)
+    pub snap_denominator: Option<i64>,
 }
 
 impl Default for RegionalFormat {
@@ -70,6 +89,8 @@ impl Default for DisplayOptions {
             notation: Notation::Decimal,
             exp_notation: ExpNotation::E,
             regional_format: RegionalFormat::default(),
+            max_denominator: None,
+            snap_denominator: None,
         }
     }
 }
@@ -162,6 +183,30 @@ impl DisplayOptions {
         }
     }
 
+    /// Improper fractions (17/12)
+    pub fn fraction() -> Self {
+        DisplayOptions {
+            notation: Notation::Fraction,
+            ..Default::default()
+        }
+    }
+
+    /// Mixed numbers (1 5/12)
+    pub fn mixed_fraction() -> Self {
+        DisplayOptions {
+            notation: Notation::MixedFraction,
+            ..Default::default()
+        }
+    }
+
+    /// Mixed numbers with Unicode fraction glyphs (1⅓, 1⁵⁄₁₂)
+    pub fn unicode_fraction() -> Self {
+        DisplayOptions {
+            notation: Notation::UnicodeFraction,
+            ..Default::default()
+        }
+    }
+
     /// US regional format with decimal notation
     pub fn us() -> Self {
         DisplayOptions {
@@ -224,7 +269,47 @@ impl Number {
             Notation::Decimal => format_decimal(raw, is_negative, opts),
             Notation::Scientific => format_scientific(raw, is_negative, opts),
             Notation::Engineering => format_engineering(raw, is_negative, opts),
+            Notation::Fraction | Notation::MixedFraction | Notation::UnicodeFraction => {
+                format_fraction(&self.fraction_value(opts), opts.notation)
+            }
+        }
+    }
+
+    /// The finite value as the fraction the fraction notations show.
+    ///
+    /// Decimal tiers go through rational recovery first: the simplest convergent that
+    /// rounds to the stored digits, so 0.142857…14 (44 digits) is 1/7. Then snapping
+    /// and the denominator limit apply.
+    fn fraction_value(&self, opts: &DisplayOptions) -> BigRational {
+        let exact = self
+            .value
+            .to_big_rational()
+            .expect("finite values have an exact rational");
+        let scale = match &self.value {
+            NumericValue::Decimal(d) => Some(i64::from(d.scale())),
+            NumericValue::BigDecimal(bd) => Some(bd.as_bigint_and_exponent().1),
+            _ => None,
+        };
+        let mut value = match scale {
+            Some(scale) => {
+                // Half a unit in the last stored digit
+                let ten = BigRational::from_integer(BigInt::from(10u8));
+                let ulp = num_traits::pow(ten, scale.unsigned_abs() as usize);
+                let ulp = if scale >= 0 { ulp.recip() } else { ulp };
+                first_convergent_within(&exact, &(ulp / BigInt::from(2u8)))
+            }
+            None => exact,
+        };
+        if let Some(snap) = opts.snap_denominator {
+            let snap = BigInt::from(snap.max(1));
+            let multiple = RoundingMode::HalfEven
+                .round_to_integer(&(value * BigRational::from_integer(snap.clone())));
+            value = BigRational::new(multiple, snap);
+        }
+        if let Some(max_denom) = opts.max_denominator {
+            value = best_approximation(&value, &BigInt::from(max_denom.max(1)));
         }
+        value
     }
 }
 
@@ -242,6 +327,7 @@ fn format_zero(opts: &DisplayOptions) -> String {
                 "0".to_string()
             }
         }
+        Notation::Fraction | Notation::MixedFraction | Notation::UnicodeFraction => "0".to_string(),
         Notation::Scientific | Notation::Engineering => {
             let sig_figs = opts.significant_figures.unwrap_or(6) as usize;
             let zeros = if sig_figs > 1 {
@@ -504,39 +590,62 @@ fn truncate_to_sig_figs(mantissa: &str, sig_figs: usize, decimal_sep: char) -> S
 }
 
 // ============================================================================
-// Parsing
+// Fractions
 // ============================================================================
 
-/// Error type for formatted number parsing
-#[derive(Debug, Clone, PartialEq, Eq)]
-pub enum ParseError {
-    /// Input string was empty
-    EmptyInput,
-    /// Invalid character found at position
-    InvalidCharacter { pos: usize, ch: char },
-    /// Multiple decimal separators or other separator issues
-    MultipleSeparators,
-    /// Input doesn't match expected regional format
-    MismatchedFormat,
-    /// Number exceeds representable range
-    Overflow,
+fn format_fraction(value: &BigRational, notation: Notation) -> String {
+    let sign = if value.is_negative() { "-" } else { "" };
+    let (numer, denom) = (value.numer().abs(), value.denom());
+    if denom.is_one() {
+        return format!("{}{}", sign, numer);
+    }
+    if notation == Notation::Fraction {
+        return format!("{}{}/{}", sign, numer, denom);
+    }
+
+    let whole = &numer / denom;
+    let rest = &numer % denom;
+    let fraction = if notation == Notation::UnicodeFraction {
+        unicode_fraction(&rest, denom)
+    } else {
+        format!("{}/{}", rest, denom)
+    };
+    if whole.is_zero() {
+        format!("{}{}", sign, fraction)
+    } else if notation == Notation::UnicodeFraction {
+        format!("{}{}{}", sign, whole, fraction)
+    } else {
+        format!("{}{} {}", sign, whole, fraction)
+    }
 }
 
-impl std::fmt::Display for ParseError {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        match self {
-            ParseError::EmptyInput => write!(f, "empty input"),
-            ParseError::InvalidCharacter { pos, ch } => {
-                write!(f, "invalid character '{}' at position {}", ch, pos)
-            }
-            ParseError::MultipleSeparators => write!(f, "multiple separators"),
-            ParseError::MismatchedFormat => write!(f, "input doesn't match expected format"),
-            ParseError::Overflow => write!(f, "number exceeds representable range"),
-        }
+fn unicode_fraction(numer: &BigInt, denom: &BigInt) -> String {
+    let glyph = VULGAR_FRACTIONS
+        .iter()
+        .find(|(n, d, _)| *numer == BigInt::from(*n) && *denom == BigInt::from(*d));
+    if let Some((_, _, glyph)) = glyph {
+        return glyph.to_string();
     }
+    let script = |n: &BigInt, digits: &[char; 10]| -> String {
+        n.to_string()
+            .bytes()
+            .map(|b| digits[(b - b'0') as usize])
+            .collect()
+    };
+    format!(
+        "{}{}{}",
+        script(numer, &SUPERSCRIPT_DIGITS),
+        FRACTION_SLASH,
+        script(denom, &SUBSCRIPT_DIGITS)
+    )
 }
 
-impl std::error::Error for ParseError {}
+// ============================================================================
+// Parsing
+// ============================================================================
+
+/// Error type for formatted number parsing: the same type `FromStr` returns
+pub type ParseError = crate::ParseNumberError;
 
 /// Options for parsing formatted numbers
 #[derive(Debug, Clone)]
@@ -599,7 +708,7 @@ impl Number {
         let s = s.trim();
 
         if s.is_empty() {
-            return Err(ParseError::EmptyInput);
+            return Err(ParseError::Empty);
         }
 
         // Handle special values
@@ -610,6 +719,11 @@ impl Number {
             _ => {}
         }
 
+        // Fractions: "17/12", "-1 5/12", "1⅓", "1⁵⁄₁₂", read as FromStr reads them
+        if let Some(fraction) = crate::fraction::parse(s) {
+            return fraction;
+        }
+
         // Handle sign
         let (is_negative, s) = if let Some(rest) = s.strip_prefix('-') {
             (true, rest)
@@ -691,14 +805,14 @@ fn normalize_regional_format(s: &str, fmt: &RegionalFormat) -> Result<String, Pa
             continue;
         } else if ch == '-' || ch == '+' {
             // Sign should have been handled already
-            return Err(ParseError::InvalidCharacter { pos, ch });
+            return Err(ParseError::InvalidDigit { pos, ch });
         } else {
-            return Err(ParseError::InvalidCharacter { pos, ch });
+            return Err(ParseError::InvalidDigit { pos, ch });
         }
     }
 
     if result.is_empty() {
-        return Err(ParseError::EmptyInput);
+        return Err(ParseError::Empty);
     }
 
     Ok(result)
@@ -793,6 +907,177 @@ mod tests {
         }
     }
 
+    mod fraction_tests {
+        use super::*;
+        use num_rational::Ratio;
+
+        fn rational(n: i64, d: i64) -> Number {
+            Number::from_rational(Ratio::new(n, d))
+        }
+
+        #[test]
+        fn exact_rationals() {
+            let x = rational(-17, 12);
+            assert_eq!(x.format(&DisplayOptions::fraction()), "-17/12");
+            assert_eq!(x.format(&DisplayOptions::mixed_fraction()), "-1 5/12");
+            assert_eq!(x.format(&DisplayOptions::unicode_fraction()), "-1⁵⁄₁₂");
+
+            let third = rational(1, 3);
+            assert_eq!(third.format(&DisplayOptions::mixed_fraction()), "1/3");
+            assert_eq!(third.format(&DisplayOptions::unicode_fraction()), "⅓");
+            assert_eq!(
+                rational(5, 2).format(&DisplayOptions::unicode_fraction()),
+                "2½"
+            );
+            assert_eq!(
+                rational(17, 32).format(&DisplayOptions::unicode_fraction()),
+                "¹⁷⁄₃₂"
+            );
+
+            assert_eq!(
+                Number::from(7).format(&DisplayOptions::mixed_fraction()),
+                "7"
+            );
+            assert_eq!(Number::neg_zero().format(&DisplayOptions::fraction()), "0");
+            assert_eq!(Number::NAN.format(&DisplayOptions::fraction()), "NaN");
+        }
+
+        #[test]
+        fn decimals_go_through_rational_recovery() {
+            let big = Number::from_bigdecimal(
+                bigdecimal::BigDecimal::from_str("0.14285714285714285714285714285714285714285714")
+                    .unwrap(),
+            );
+            assert_eq!(big.representation(), crate::Repr::BigDecimal);
+            assert_eq!(big.format(&DisplayOptions::fraction()), "1/7");
+
+            let pi = Number::from_str("3.14159265358979").unwrap();
+            let opts = DisplayOptions {
+                max_denominator: Some(1000),
+                ..DisplayOptions::mixed_fraction()
+            };
+            assert_eq!(pi.format(&opts), "3 16/113");
+        }
+
+        #[test]
+        fn snapping() {
+            // 0.30 is 4.8 sixteenths
+            let opts = DisplayOptions {
+                snap_denominator: Some(16),
+                ..DisplayOptions::unicode_fraction()
+            };
+            assert_eq!(Number::from_str("2.30").unwrap().format(&opts), "2⁵⁄₁₆");
+            assert_eq!(Number::from_str("0.49").unwrap().format(&opts), "½");
+            assert_eq!(Number::from_str("-0.01").unwrap().format(&opts), "0");
+            // Ties to even: 1/32 is halfway between 0 and 1/16
+            assert_eq!(rational(1, 32).format(&opts), "0");
+
+            let both = DisplayOptions {
+                snap_denominator: Some(64),
+                max_denominator: Some(8),
+                ..DisplayOptions::fraction()
+            };
+            assert_eq!(Number::from_str("0.38").unwrap().format(&both), "3/8");
+        }
+
+        #[test]
+        fn parse_fractions() {
+            let opts = ParseOptions::default();
+            let cases = [
+                ("17/12", rational(17, 12)),
+                ("-1 5/12", rational(-17, 12)),
+                ("1⅓", rational(4, 3)),
+                ("-⅔", rational(-2, 3)),
+                ("2 ½", rational(5, 2)),
+                ("¹⁷⁄₃₂", rational(17, 32)),
+                ("1¹⁷⁄₃₂", rational(49, 32)),
+            ];
+            for (s, expected) in cases {
+                let n = Number::parse_formatted(s, &opts).unwrap();
+                assert_eq!(n, expected, "{}", s);
+                assert!(n.is_exact());
+            }
+            assert!(
+                Number::parse_formatted("1/0", &opts)
+                    .unwrap()
+                    .is_positive_infinity()
+            );
+
+            assert_eq!(
+                Number::parse_formatted("1/x", &opts),
+                Err(ParseError::InvalidDigit { pos: 2, ch: 'x' })
+            );
+            assert_eq!(
+                Number::parse_formatted("1 /3", &opts),
+                Err(ParseError::InvalidDigit { pos: 2, ch: '/' })
+            );
+            assert_eq!(
+                Number::parse_formatted("1²⁄₃", &opts),
+                Number::parse_formatted("1 2/3", &opts)
+            );
+        }
+
+        #[test]
+        fn parse_formatted_reads_fractions_like_from_str() {
+            let opts = ParseOptions::default();
+            for s in [
+                "1.5/2",
+                "1.5/2.25",
+                "-0/5",
+                "+22/7",
+                "-2 1/2",
+                "1⅓",
+                "-1 ¹⁷⁄₃₂",
+                "0/0",
+                "-1/0",
+                "1/x",
+                "1/",
+                "1.5 1/2",
+                "1/2/3",
+                "1⁄₂",
+            ] {
+                let formatted = Number::parse_formatted(s, &opts);
+                let from_str = Number::from_str(s);
+                match (&formatted, &from_str) {
+                    (Ok(a), Ok(b)) if a.is_nan() => assert!(b.is_nan(), "{}", s),
+                    (Ok(a), Ok(b)) => {
+                        assert_eq!(a, b, "{}", s);
+                        assert_eq!(a.is_neg_zero(), b.is_neg_zero(), "{}", s);
+                    }
+                    _ => assert_eq!(formatted, from_str, "{}", s),
+                }
+            }
+            assert_eq!(Number::parse_formatted("1.5/2", &opts), Ok(rational(3, 4)));
+            assert!(
+                Number::parse_formatted("-0/5", &opts)
+                    .unwrap()
+                    .is_neg_zero()
+            );
+        }
+
+        #[test]
+        fn roundtrip_fractions() {
+            let values = [
+                rational(-17, 12),
+                rational(22, 7),
+                rational(3, 4),
+                rational(-1, 1024),
+                Number::from(-5),
+            ];
+            for x in values {
+                for opts in [
+                    DisplayOptions::fraction(),
+                    DisplayOptions::mixed_fraction(),
+                    DisplayOptions::unicode_fraction(),
+                ] {
+                    let s = x.format(&opts);
+                    let parsed = Number::parse_formatted(&s, &ParseOptions::default()).unwrap();
+                    assert_eq!(parsed, x, "{}", s);
+                }
+            }
+        }
+    }
+
     mod parse_tests {
         use super::*;
 
@@ -839,14 +1124,14 @@ mod tests {
         fn parse_empty_error() {
             assert_eq!(
                 Number::parse_formatted("", &ParseOptions::default()),
-                Err(ParseError::EmptyInput)
+                Err(ParseError::Empty)
             );
         }
 
         #[test]
         fn parse_invalid_char() {
             let result = Number::parse_formatted("12abc34", &ParseOptions::default());
-            assert!(matches!(result, Err(ParseError::InvalidCharacter { .. })));
+            assert!(matches!(result, Err(ParseError::InvalidDigit { .. })));
         }
 
         #[test]
@@ -0,0 +1,152 @@
+//! Fraction strings, read the same way by `FromStr` and `parse_formatted`:
+//! "1/3", "-2 1/2", "1.5/2.25", and the Unicode forms the fraction notations
+//! write ("1⅓", "1¹⁷⁄₃₂").
+
+use crate::{Number, ParseNumberError};
+use bigdecimal::BigDecimal;
+use num_rational::BigRational;
+use num_traits::Zero;
+use std::ops::Range;
+use std::str::FromStr;
+
+/// Unicode vulgar fraction glyphs
+pub(crate) const VULGAR_FRACTIONS: [(u8, u8, char); 18] = [
+    (1, 2, '½'),
+    (1, 3, '⅓'),
+    (2, 3, '⅔'),
+    (1, 4, '¼'),
+    (3, 4, '¾'),
+    (1, 5, '⅕'),
+    (2, 5, '⅖'),
+    (3, 5, '⅗'),
+    (4, 5, '⅘'),
+    (1, 6, '⅙'),
+    (5, 6, '⅚'),
+    (1, 7, '⅐'),
+    (1, 8, '⅛'),
+    (3, 8, '⅜'),
+    (5, 8, '⅝'),
+    (7, 8, '⅞'),
+    (1, 9, '⅑'),
+    (1, 10, '⅒'),
+];
+
+const ASCII_DIGITS: [char; 10] = ['0', '1', '2', '3', '4', '5', '6', '7', '8', '9'];
+pub(crate) const SUPERSCRIPT_DIGITS: [char; 10] =
+    ['⁰', '¹', '²', '³', '⁴', '⁵', '⁶', '⁷', '⁸', '⁹'];
+pub(crate) const SUBSCRIPT_DIGITS: [char; 10] = ['₀', '₁', '₂', '₃', '₄', '₅', '₆', '₇', '₈', '₉'];
+
+/// U+2044, used between superscript and subscript digits
+pub(crate) const FRACTION_SLASH: char = '⁄';
+
+/// `s` as a fraction or mixed number; None when it has no fraction in it.
+///
+/// `[sign][whole ]numer/denom` with decimal numerator and denominator, or
+/// `[sign][whole[ ]]` then a vulgar fraction glyph or superscript digits, U+2044
+/// and subscript digits. The sign belongs to the numerator too, so "-0/5" is -0
+/// like `Number::neg_zero() / 5`.
+pub(crate) fn parse(s: &str) -> Option<Result<Number, ParseNumberError>> {
+    let sign = usize::from(s.starts_with(['-', '+']));
+    let (whole, numer, denom) = if let Some(slash) = s.find('/') {
+        // The whole part is separated by spaces
+        let head = &s[sign..slash];
+        let numer_start = match head.find(' ') {
+            Some(space) => slash - head[space..].trim_start().len(),
+            None => sign,
+        };
+        let numer = exact_decimal(s, numer_start..slash, &ASCII_DIGITS, true);
+        let denom = exact_decimal(s, slash + 1..s.len(), &ASCII_DIGITS, true);
+        (sign..numer_start, numer, denom)
+    } else if let Some(slash) = s.find(FRACTION_SLASH) {
+        // The superscript numerator follows the whole part directly
+        let split = s[..slash]
+            .char_indices()
+            .rev()
+            .take_while(|(_, c)| SUPERSCRIPT_DIGITS.contains(c))
+            .last()
+            .map_or(slash, |(i, _)| i);
+        let denom_start = slash + FRACTION_SLASH.len_utf8();
+        let numer = exact_decimal(s, split..slash, &SUPERSCRIPT_DIGITS, false);
+        let denom = exact_decimal(s, denom_start..s.len(), &SUBSCRIPT_DIGITS, false);
+        (sign..split, numer, denom)
+    } else {
+        let last = s.chars().next_back()?;
+        let &(n, d, glyph) = VULGAR_FRACTIONS.iter().find(|(_, _, g)| *g == last)?;
+        let integer = |n: u8| Ok(BigRational::from_integer(n.into()));
+        (sign..s.len() - glyph.len_utf8(), integer(n), integer(d))
+    };
+    Some(fraction_value(s, whole, numer, denom))
+}
+
+/// sign · (whole + numer / denom), with the pieces' own errors reported in order
+fn fraction_value(
+    s: &str,
+    whole: Range<usize>,
+    numer: Result<BigRational, ParseNumberError>,
+    denom: Result<BigRational, ParseNumberError>,
+) -> Result<Number, ParseNumberError> {
+    let whole = if whole.is_empty() {
+        BigRational::zero()
+    } else {
+        let end = whole.start + s[whole.clone()].trim_end().len();
+        exact_decimal(s, whole.start..end, &ASCII_DIGITS, false)?
+    };
+    let (numer, denom) = (numer?, denom?);
+
+    let negative = s.starts_with('-');
+    let signed = |r: BigRational| match (negative, r.is_zero()) {
+        (true, true) => Number::neg_zero(),
+        (true, false) => Number::from_big_rational(-r),
+        (false, _) => Number::from_big_rational(r),
+    };
+    Ok(if denom.is_zero() {
+        // ±∞ or NaN, as `/` gives
+        signed(numer) / Number::from(0)
+    } else {
+        signed(whole + numer / denom)
+    })
+}
+
+/// `s[range]` as unsigned `digits` ("12", or "1.5" and ".25" with `point`) as an
+/// exact rational. An empty piece is reported at the character after it, or for the
+/// denominator at the slash.
+fn exact_decimal(
+    s: &str,
+    range: Range<usize>,
+    digits: &[char; 10],
+    point: bool,
+) -> Result<BigRational, ParseNumberError> {
+    let invalid = |byte: usize| {
+        let ch = s[byte..].chars().next().expect("a character at the offset");
+        ParseNumberError::InvalidDigit {
+            pos: s[..byte].chars().count(),
+            ch,
+        }
+    };
+    let piece = &s[range.clone()];
+    let mut ascii = String::with_capacity(piece.len());
+    for (i, ch) in piece.char_indices() {
+        match digits.iter().position(|&d| d == ch) {
+            Some(digit) => ascii.push(ASCII_DIGITS[digit]),
+            None if ch == '.' && point && !ascii.contains('.') => ascii.push('.'),
+            None => return Err(invalid(range.start + i)),
+        }
+    }
+    if !ascii.bytes().any(|b| b.is_ascii_digit()) {
+        let at = if !piece.is_empty() {
+            range.start
+        } else if range.end < s.len() {
+            range.end
+        } else {
+            s[..range.start]
+                .char_indices()
+                .next_back()
+                .map_or(0, |(i, _)| i)
+        };
+        return Err(invalid(at));
+    }
+    let bd = BigDecimal::from_str(&ascii).map_err(|_| ParseNumberError::Overflow)?;
+    crate::NumericValue::BigDecimal(bd)
+        .to_big_rational()
+        .ok_or(ParseNumberError::Overflow)
+}
@@ -1,3 +1,4 @@
+use crate::provenance::Trail;
 use crate::{Number, NumericValue};
 use num_traits::{ToPrimitive, Zero};
 use std::cmp::{Ordering, PartialOrd};
@@ -39,6 +40,8 @@ impl Number {
         Number {
             value: self.value.unsigned_right_shift(bits.value),
             apprx,
+            err_bound: None,
+            provenance: Trail::NONE,
         }
     }
 
@@ -89,6 +92,18 @@ impl NumericValue {
                     .to_i32()
                     .unwrap_or_else(|| r.to_integer() as i32)
             }
+            NumericValue::BigRational(r) => {
+                // JavaScript ToInt32: truncate, then keep the low 32 bits (two's complement)
+                use bigdecimal::num_bigint::Sign;
+                let (sign, digits) = r.to_integer().to_u32_digits();
+                let low = digits.first().copied().unwrap_or(0);
+                let wrapped = if sign == Sign::Minus {
+                    low.wrapping_neg()
+                } else {
+                    low
+                };
+                wrapped as i32
+            }
             NumericValue::Decimal(d) => {
                 // Try direct conversion first (fast path)
                 if let Some(i) = d.to_i32() {
@@ -124,6 +139,18 @@ impl NumericValue {
                 // to_integer() returns Ratio with denom=1, numer is the integer value
                 r.to_integer().to_i64().unwrap_or(0)
             }
+            NumericValue::BigRational(r) => {
+                // Truncate, then keep the low 64 bits (two's complement)
+                use bigdecimal::num_bigint::Sign;
+                let (sign, digits) = r.to_integer().to_u64_digits();
+                let low = digits.first().copied().unwrap_or(0);
+                let wrapped = if sign == Sign::Minus {
+                    low.wrapping_neg()
+                } else {
+                    low
+                };
+                wrapped as i64
+            }
             NumericValue::Decimal(d) => {
                 // Try direct conversion first (fast path)
                 if let Some(i) = d.to_i64() {
@@ -155,6 +182,7 @@ impl NumericValue {
     pub(crate) fn to_u32_js_coerce(&self) -> u32 {
         match self {
             NumericValue::Rational(_, _)
+            | NumericValue::BigRational(_)
             | NumericValue::Decimal(_)
             | NumericValue::BigDecimal(_)
             | NumericValue::NegativeZero => {
@@ -178,6 +206,7 @@ impl NumericValue {
     pub(crate) fn is_truthy(&self) -> bool {
         match self {
             NumericValue::Rational(r, _) => !r.is_zero(), // 0 is falsy, everything else is truthy
+            NumericValue::BigRational(r) => !r.is_zero(), // 0 is falsy, everything else is truthy
             NumericValue::Decimal(d) => !d.is_zero(),     // 0 is falsy, everything else is truthy
             NumericValue::BigDecimal(bd) => !bd.is_zero(), // 0 is falsy, everything else is truthy
             NumericValue::NegativeZero => false,          // -0 is falsy
@@ -214,6 +243,10 @@ impl NumericValue {
                     d.to_string()
                 }
             }
+            NumericValue::BigRational(r) => {
+                use crate::representation::big_rational_to_bigdecimal;
+                big_rational_to_bigdecimal(r).to_string()
+            }
             NumericValue::BigDecimal(bd) => bd.to_string(),
             NumericValue::NegativeZero => "0".to_string(), // -0 displays as "0" in JS
             NumericValue::NaN => "NaN".to_string(),
@@ -246,6 +279,7 @@ impl NumericValue {
 }
 
 #[cfg(test)]
+#[allow(clippy::op_ref, unused_variables)] // comparisons by reference, as the JS operators take them
 mod js_semantics_tests {
     use super::*;
     use rust_decimal::Decimal;
@@ -629,7 +663,10 @@ mod js_semantics_tests {
         // Round (half away from zero - mathematical default, not JS semantics)
         // JS uses half toward +∞, but per VISION.md we use mathematical semantics by default
         assert_js_eq!(num!(3.5).round(), num!(4));
+        #[cfg(not(feature = "js_rounding"))]
         assert_js_eq!(num!(-3.5).round(), num!(-4)); // Mathematical: away from zero
+        #[cfg(feature = "js_rounding")]
+        assert_js_eq!(num!(-3.5).round(), num!(-3)); // JS: toward +∞
 
         // Trunc
         assert_js_eq!(positive.clone().trunc(), num!(3));
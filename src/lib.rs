@@ -4,14 +4,25 @@
 #[macro_use]
 pub mod macros;
 
+#[cfg(all(feature = "high_precision_pure", not(feature = "high_precision")))]
+mod bigdecimal_math;
+mod constants;
+pub mod context;
+pub mod continued_fraction;
 pub mod conversions;
 pub mod core;
+mod error_bound;
+mod fraction;
 pub mod js_semantics;
 pub mod math;
+pub mod number_theory;
 pub mod ops;
 pub mod ordered;
 pub mod precision;
+mod provenance;
+mod repeating;
 pub mod representation;
+pub mod rounding;
 pub mod traits;
 
 #[cfg(feature = "format")]
@@ -20,10 +31,21 @@ pub mod format;
 #[cfg(any(feature = "serde_str", feature = "serde_bin"))]
 mod serde_impl;
 
+pub use crate::constants::Constant;
+pub use crate::context::{
+    ApproximationFlags, ApproximationPolicy, ArithmeticContext, ContextGuard, approximation_flags,
+    get_arithmetic_context, set_arithmetic_context, take_approximation_flags,
+};
+pub use crate::conversions::ParseNumberError;
 use crate::core::NumericValue;
-pub use crate::core::{ApproximationType, Number, NumberInfo};
+pub use crate::core::{ApproximationType, Number, NumberInfo, Repr};
+pub use crate::ops::checked::{ExactnessError, ExactnessErrorKind};
 pub use crate::ordered::OrderedNumber;
 pub use crate::precision::{get_default_precision, set_default_precision};
+#[cfg(feature = "provenance")]
+pub use crate::provenance::Provenance;
+pub use crate::repeating::RepeatingNotation;
+pub use crate::rounding::RoundingMode;
 
 #[cfg(feature = "format")]
 pub use crate::format::{
@@ -32,7 +54,7 @@ pub use crate::format::{
 
 pub mod repr {
     pub use bigdecimal::BigDecimal;
-    pub use num_rational::Rational64;
+    pub use num_rational::{BigRational, Rational64};
     pub use rust_decimal::Decimal;
     pub use rust_decimal::RoundingStrategy;
 }
@@ -143,10 +165,10 @@ mod metadata_tests {
 
             // sqrt(2) ≈ 1.414... → IS transcendental
             let sqrt2 = Number::from(2).sqrt();
-            // With high_precision feature, transcendental ops return BigDecimal
-            #[cfg(feature = "high_precision")]
+            // With a high-precision feature, transcendental ops return BigDecimal
+            #[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
             assert_eq!(sqrt2.representation(), "BigDecimal");
-            #[cfg(not(feature = "high_precision"))]
+            #[cfg(not(any(feature = "high_precision", feature = "high_precision_pure")))]
             assert_eq!(sqrt2.representation(), "Decimal");
             sqrt2.assert_transcendental();
         }
@@ -206,15 +228,22 @@ mod metadata_tests {
         }
 
         #[test]
-        fn transcendental_trumps_rational_approximation() {
-            // Create a rational approximation via overflow
+        fn non_terminating_overflow_stays_exact() {
+            // Denominator overflows i64, so the result is promoted to BigRational
             let third = Number::from_rational(Ratio::new(1, 3)); // Non-terminating
             let huge1 = Number::from_rational(Ratio::new(1, 4_000_000_000));
             let huge2 = Number::from_rational(Ratio::new(1, 3_000_000_000));
-            let rat_approx = third * huge1 * huge2; // Overflows to Decimal
+            let result = third * huge1 * huge2;
 
-            // MUST have rational_approximation and be BigDecimal to preserve precision
-            assert_eq!(rat_approx.representation(), "BigDecimal");
+            assert_eq!(result.representation(), "BigRational");
+            result.assert_exact();
+        }
+
+        #[test]
+        fn transcendental_trumps_rational_approximation() {
+            // Overflow no longer produces the flag, so attach it directly
+            let mut rat_approx = Number::from_str("0.333333333333333333333333").unwrap();
+            rat_approx.apprx = Some(ApproximationType::RationalApproximation);
             rat_approx.assert_rational_approximation();
 
             // Transcendental operation should trump
@@ -234,12 +263,8 @@ mod metadata_tests {
             sqrt2.clone().ceil().assert_exact();
 
             // Rational approximation: also cleared by rounding
-            let third = Number::from_rational(Ratio::new(1, 3)); // Non-terminating
-            let huge1 = Number::from_rational(Ratio::new(1, 4_000_000_000));
-            let huge2 = Number::from_rational(Ratio::new(1, 3_000_000_000));
-            let rat_approx = third * huge1 * huge2; // Overflows to BigDecimal (non-terminating)
-
-            assert_eq!(rat_approx.representation(), "BigDecimal");
+            let mut rat_approx = Number::from_str("0.333333333333333333333333").unwrap();
+            rat_approx.apprx = Some(ApproximationType::RationalApproximation);
             rat_approx.assert_rational_approximation();
 
             rat_approx.round().assert_exact(); // Rounds to 0 (exact)
@@ -247,8 +272,8 @@ mod metadata_tests {
 
         #[test]
         fn rational_approximation_must_preserve_precision_for_recovery() {
-            // This test demonstrates that rational approximations MUST use BigDecimal
-            // to preserve enough precision to recover the original rational after inverse operations
+            // Overflowed intermediates must keep enough precision to recover the
+            // original rational after inverse operations (BigRational keeps all of it)
 
             let third = Number::from_rational(Ratio::new(1, 3)); // Non-terminating
             let huge1 = Number::from_rational(Ratio::new(1, 4_000_000_000));
@@ -273,7 +298,7 @@ mod metadata_tests {
             println!("Recovered value: {:?}", recovered.to_rational64());
 
             // If intermediate was stored as Decimal (28 digits), we LOSE precision
-            // If intermediate was stored as BigDecimal, we CAN recover exactly
+            // Stored as BigRational, we recover exactly
 
             // EXPECTED: recovered should be Rational(1, 3) - the original value
             assert_eq!(
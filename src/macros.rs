@@ -7,6 +7,7 @@ macro_rules! forward_ref_binop {
     (impl $trait:ident, $method:ident for $type:ty) => {
         impl $trait<&$type> for $type {
             type Output = $type;
+            #[cfg_attr(feature = "provenance", track_caller)]
             fn $method(self, rhs: &$type) -> $type {
                 $trait::$method(self, rhs.clone())
             }
@@ -14,6 +15,7 @@ macro_rules! forward_ref_binop {
 
         impl $trait<$type> for &$type {
             type Output = $type;
+            #[cfg_attr(feature = "provenance", track_caller)]
             fn $method(self, rhs: $type) -> $type {
                 $trait::$method(self.clone(), rhs)
             }
@@ -21,6 +23,7 @@ macro_rules! forward_ref_binop {
 
         impl $trait<&$type> for &$type {
             type Output = $type;
+            #[cfg_attr(feature = "provenance", track_caller)]
             fn $method(self, rhs: &$type) -> $type {
                 $trait::$method(self.clone(), rhs.clone())
             }
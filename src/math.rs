@@ -1,8 +1,13 @@
+use crate::context;
+use crate::error_bound;
+use crate::provenance::Trail;
+use crate::rounding::RoundingMode;
 use crate::{Number, NumericValue};
-use num_rational::Ratio;
+use bigdecimal::num_bigint::BigInt;
+use num_rational::{BigRational, Ratio};
 use rust_decimal::Decimal;
 
-use num_traits::{Signed, ToPrimitive, Zero};
+use num_traits::{FromPrimitive, One, Signed, ToPrimitive, Zero};
 use std::str::FromStr;
 
 #[cfg(feature = "high_precision")]
@@ -21,6 +26,11 @@ fn to_rug_float(value: &NumericValue, precision: u32) -> Option<Float> {
             let denom = *r.denom();
             Some(Float::with_val(precision, numer) / Float::with_val(precision, denom))
         }
+        NumericValue::BigRational(r) => {
+            let numer = Float::parse(r.numer().to_string()).ok()?;
+            let denom = Float::parse(r.denom().to_string()).ok()?;
+            Some(Float::with_val(precision, numer) / Float::with_val(precision, denom))
+        }
         NumericValue::Decimal(d) => {
             // Convert Decimal to string, then to Float
             let s = d.to_string();
@@ -45,11 +55,265 @@ fn rug_float_to_bigdecimal(f: &Float) -> BigDecimal {
     BigDecimal::from_str(&s).unwrap_or_else(|_| BigDecimal::from(0))
 }
 
+/// Return `bigdecimal_math::$f(args.., precision)` from the enclosing function at
+/// the working precision, in builds with `high_precision_pure` but not `rug`.
+/// Expands to nothing in other builds; when the series gives up, the f64 fallback
+/// after it runs as usual.
+macro_rules! pure_series {
+    ($f:ident($($arg:expr),*)) => {
+        #[cfg(all(feature = "high_precision_pure", not(feature = "high_precision")))]
+        {
+            let precision = crate::precision::get_default_precision();
+            if let Some(result) = crate::bigdecimal_math::$f($($arg,)* precision) {
+                return result;
+            }
+        }
+    };
+}
+
+/// Lanczos approximation (g = 7, n = 9) behind the f64 gamma fallback
+const LANCZOS_G: f64 = 7.0;
+const LANCZOS_COEFFICIENTS: [f64; 9] = [
+    0.999_999_999_999_809_9,
+    676.520_368_121_885_1,
+    -1_259.139_216_722_402_8,
+    771.323_428_777_653_1,
+    -176.615_029_162_140_6,
+    12.507_343_278_686_905,
+    -0.138_571_095_265_720_12,
+    9.984_369_578_019_572e-6,
+    1.505_632_735_149_311_6e-7,
+];
+
+fn lanczos_series(x: f64) -> f64 {
+    LANCZOS_COEFFICIENTS[1..]
+        .iter()
+        .enumerate()
+        .fold(LANCZOS_COEFFICIENTS[0], |acc, (i, c)| {
+            acc + c / (x + i as f64 + 1.0)
+        })
+}
+
+/// sin(πx), reduced exactly into [0, 1/2] first so it stays accurate near integers
+pub(crate) fn sin_pi(x: f64) -> f64 {
+    let mut r = x.abs() % 2.0;
+    let mut sign = x.signum();
+    if r >= 1.0 {
+        r -= 1.0;
+        sign = -sign;
+    }
+    if r > 0.5 {
+        r = 1.0 - r;
+    }
+    sign * (std::f64::consts::PI * r).sin()
+}
+
+/// Γ(x) in f64 (std has no gamma)
+pub(crate) fn gamma_f64(x: f64) -> f64 {
+    use std::f64::consts::PI;
+    if x < 0.5 {
+        // Reflection: Γ(x) Γ(1 − x) = π / sin(πx)
+        return PI / (sin_pi(x) * gamma_f64(1.0 - x));
+    }
+    if x > 172.0 {
+        return f64::INFINITY;
+    }
+    let x = x - 1.0;
+    let t = x + LANCZOS_G + 0.5;
+    // Split t^(x + 1/2) so it doesn't overflow before e^-t brings it back down
+    let half = t.powf((x + 0.5) / 2.0);
+    (2.0 * PI).sqrt() * lanczos_series(x) * half * (-t).exp() * half
+}
+
+/// ln |Γ(x)| in f64
+pub(crate) fn ln_gamma_f64(x: f64) -> f64 {
+    use std::f64::consts::PI;
+    if x < 0.5 {
+        return (PI / sin_pi(x).abs()).ln() - ln_gamma_f64(1.0 - x);
+    }
+    let x = x - 1.0;
+    let t = x + LANCZOS_G + 0.5;
+    0.5 * (2.0 * PI).ln() + (x + 0.5) * t.ln() - t + lanczos_series(x).ln()
+}
+
+/// e^ln for a result past f64's range, such as Γ(x) beyond 171.6, as a BigDecimal.
+///
+/// Only the digits the f64 logarithm carries are kept: its absolute error grows
+/// with the exponent. +∞ when even the exponent is out of reach.
+pub(crate) fn exp_beyond_f64(ln: f64) -> NumericValue {
+    let log10 = ln / std::f64::consts::LN_10;
+    if !log10.is_finite() || log10 > 1e15 {
+        return NumericValue::PositiveInfinity;
+    }
+    let exponent = log10.floor();
+    let digits = (15 - exponent.log10().ceil() as i32).max(1);
+    let mantissa = (10f64.powf(log10 - exponent + f64::from(digits - 1))).round() as i64;
+    NumericValue::BigDecimal(bigdecimal::BigDecimal::new(
+        BigInt::from(mantissa),
+        i64::from(digits - 1) - exponent as i64,
+    ))
+}
+
+/// ψ(x) = Γ'(x) / Γ(x) in f64, for derivative bounds
+pub(crate) fn digamma_f64(x: f64) -> f64 {
+    if x < 0.5 {
+        // Reflection: ψ(1 − x) − ψ(x) = π cot(πx)
+        let pi = std::f64::consts::PI;
+        return digamma_f64(1.0 - x) - pi * (pi * x).cos() / sin_pi(x);
+    }
+    // Recurrence up to where the asymptotic series is accurate
+    let (mut x, mut shift) = (x, 0.0);
+    while x < 10.0 {
+        shift -= 1.0 / x;
+        x += 1.0;
+    }
+    let inv2 = 1.0 / (x * x);
+    let series = inv2 * (1.0 / 12.0 - inv2 * (1.0 / 120.0 - inv2 * (1.0 / 252.0 - inv2 / 240.0)));
+    shift + x.ln() - 0.5 / x - series
+}
+
+/// Γ(x) for a half-integer x = m + 1/2 is c·√π with c rational:
+/// (2m)! / (4^m m!) for m >= 0, and (−4)^k k! / (2k)! for m = −k < 0
+fn half_integer_gamma_coefficient(r: &BigRational) -> Option<BigRational> {
+    use crate::number_theory::{MAX_EXACT_FACTORIAL, factorial_big};
+    if *r.denom() != BigInt::from(2) {
+        return None;
+    }
+    let m = r.floor().to_integer();
+    let k = m.abs().to_u64().filter(|&k| k <= MAX_EXACT_FACTORIAL)?;
+    let four_k = BigInt::from(4).pow(k as u32);
+    let (numer, denom) = if m.is_negative() {
+        let numer = four_k * factorial_big(k);
+        let sign = if k % 2 == 1 { -numer } else { numer };
+        (sign, factorial_big(2 * k))
+    } else {
+        (factorial_big(2 * k), four_k * factorial_big(k))
+    };
+    Some(BigRational::new(numer, denom))
+}
+
+/// c·√π for an exact rational c, at the working precision
+fn sqrt_pi_times(exact: BigRational) -> NumericValue {
+    let c = NumericValue::from_big_rational(exact.clone());
+    #[cfg(feature = "high_precision")]
+    {
+        // Use high-precision rug::Float
+        let precision = crate::precision::get_default_precision();
+        if let Some(c_f) = to_rug_float(&c, precision) {
+            let pi = Float::with_val(precision, rug::float::Constant::Pi);
+            let result = pi.sqrt() * c_f;
+            return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
+        }
+    }
+    pure_series!(sqrt_pi_times(&c));
+
+    // Fallback to f64, through BigDecimal once the product leaves f64's range
+    let sqrt_pi = std::f64::consts::PI.sqrt();
+    let product = c.to_f64() * sqrt_pi;
+    if product.is_finite() && product != 0.0 {
+        return NumericValue::from(product);
+    }
+    let c = crate::representation::big_rational_to_bigdecimal(&exact);
+    match bigdecimal::BigDecimal::from_f64(sqrt_pi) {
+        Some(root) => NumericValue::BigDecimal((c * root).with_prec(17).normalized()),
+        None => NumericValue::from(product),
+    }
+}
+
+// Exact-point detection for transcendental functions
+impl NumericValue {
+    /// True when the value is exactly the integer `n` (in any representation)
+    fn is_exactly(&self, n: i64) -> bool {
+        match self {
+            NumericValue::Rational(r, _) => *r == Ratio::from_integer(n),
+            _ => self
+                .to_big_rational()
+                .is_some_and(|r| r == BigRational::from_integer(BigInt::from(n))),
+        }
+    }
+
+    /// k such that the value is exactly base^k, for an integer base > 1.
+    ///
+    /// Neither 2 nor 10 is a perfect power, so a rational x has a rational
+    /// log base 2 or 10 only when it is an integer power of the base.
+    fn integer_log(&self, base: u32) -> Option<i64> {
+        let r = self.to_big_rational()?;
+        if !r.is_positive() {
+            return None;
+        }
+        let (mut n, negate) = if r.denom().is_one() {
+            (r.numer().clone(), false)
+        } else if r.numer().is_one() {
+            (r.denom().clone(), true)
+        } else {
+            return None;
+        };
+        let mut k = 0i64;
+        while !n.is_one() {
+            if !(&n % base).is_zero() {
+                return None;
+            }
+            n /= base;
+            k += 1;
+        }
+        Some(if negate { -k } else { k })
+    }
+
+    /// Exact base^(p/q) when the base is a perfect q-th power, e.g. 8^(2/3) = 4.
+    ///
+    /// Covers integer exponents (q = 1) of rational bases too. Returns None when
+    /// the result is irrational, when a negative base meets a non-integer exponent
+    /// (NaN, as in JS), or when |p| is too large to expand exactly.
+    pub(crate) fn exact_pow(&self, exponent: &NumericValue) -> Option<NumericValue> {
+        let exp = exponent.to_big_rational()?;
+        let base = match self {
+            // Decimal bases already take exact integer powers
+            NumericValue::Decimal(_) | NumericValue::BigDecimal(_) if exp.is_integer() => {
+                return None;
+            }
+            // -0 keeps its sign rules in the general path
+            NumericValue::NegativeZero => return None,
+            _ => self.to_big_rational()?,
+        };
+
+        let p = exp
+            .numer()
+            .to_i32()
+            .filter(|p| (-1000..=1000).contains(p))?;
+        let q = exp.denom().to_u32()?;
+        if q > 1 && base.is_negative() {
+            return None;
+        }
+        if base.is_zero() && p < 0 {
+            return None;
+        }
+
+        let root = exact_root(&base, q)?;
+        Some(NumericValue::from_big_rational(root.pow(p)))
+    }
+}
+
+/// Exact q-th root of a rational: Some only when numerator and denominator are
+/// both perfect q-th powers. Negative values have a real root for odd q.
+pub(crate) fn exact_root(r: &BigRational, q: u32) -> Option<BigRational> {
+    if q == 0 || (q.is_multiple_of(2) && r.is_negative()) {
+        return None;
+    }
+    if q == 1 {
+        return Some(r.clone());
+    }
+    let numer_root = r.numer().nth_root(q);
+    let denom_root = r.denom().nth_root(q);
+    (numer_root.pow(q) == *r.numer() && denom_root.pow(q) == *r.denom())
+        .then(|| BigRational::new(numer_root, denom_root))
+}
+
 impl NumericValue {
     // Mathematical functions following JS semantics
     pub fn abs(self) -> NumericValue {
         match self {
             NumericValue::Rational(r, _) => NumericValue::from_rational(r.abs()),
+            NumericValue::BigRational(r) => NumericValue::BigRational(r.abs()),
             NumericValue::Decimal(d) => NumericValue::Decimal(d.abs()),
             NumericValue::BigDecimal(bd) => NumericValue::BigDecimal(bd.abs()),
             NumericValue::NegativeZero => NumericValue::zero(), // abs(-0) = +0
@@ -69,6 +333,7 @@ impl NumericValue {
                 let floored = numer.div_euclid(denom);
                 NumericValue::from_rational(Ratio::from_integer(floored))
             }
+            NumericValue::BigRational(r) => NumericValue::from_big_rational(r.floor()),
             NumericValue::Decimal(d) => NumericValue::Decimal(d.floor()),
             NumericValue::BigDecimal(bd) => {
                 use bigdecimal::BigDecimal;
@@ -98,6 +363,7 @@ impl NumericValue {
                 let ceiled = (-numer).div_euclid(denom).saturating_neg();
                 NumericValue::from_rational(Ratio::from_integer(ceiled))
             }
+            NumericValue::BigRational(r) => NumericValue::from_big_rational(r.ceil()),
             NumericValue::Decimal(d) => NumericValue::Decimal(d.ceil()),
             NumericValue::BigDecimal(bd) => {
                 use bigdecimal::BigDecimal;
@@ -117,80 +383,18 @@ impl NumericValue {
     }
 
     pub fn round(self) -> NumericValue {
-        match self {
-            NumericValue::Rational(r, _) => {
-                let numer = *r.numer();
-                let denom = *r.denom();
-
-                #[cfg(feature = "js_rounding")]
-                {
-                    // JS semantics: round half toward positive infinity
-                    // round(1.5) = 2, round(-1.5) = -1
-                    // Formula: floor(x + 0.5) = floor((2*numer + denom) / (2*denom))
-                    let two_numer = numer.saturating_mul(2);
-                    let two_denom = denom.saturating_mul(2);
-                    let rounded = (two_numer + denom).div_euclid(two_denom);
-                    return NumericValue::from_rational(Ratio::from_integer(rounded));
-                }
-
-                #[cfg(not(feature = "js_rounding"))]
-                {
-                    // Mathematical semantics: round half away from zero
-                    // round(1.5) = 2, round(-1.5) = -2
-                    let rounded = if numer >= 0 {
-                        let two_numer = numer.saturating_mul(2);
-                        let two_denom = denom.saturating_mul(2);
-                        (two_numer + denom) / two_denom
-                    } else {
-                        let abs_numer = numer.saturating_neg();
-                        let two_numer = abs_numer.saturating_mul(2);
-                        let two_denom = denom.saturating_mul(2);
-                        -((two_numer + denom) / two_denom)
-                    };
-                    NumericValue::from_rational(Ratio::from_integer(rounded))
-                }
-            }
-            NumericValue::Decimal(d) => {
-                use rust_decimal::RoundingStrategy;
-                #[cfg(feature = "js_rounding")]
-                let strategy = RoundingStrategy::MidpointTowardPositiveInfinity;
-                #[cfg(not(feature = "js_rounding"))]
-                let strategy = RoundingStrategy::MidpointAwayFromZero;
-                NumericValue::Decimal(d.round_dp_with_strategy(0, strategy))
-            }
-            NumericValue::BigDecimal(bd) => {
-                use bigdecimal::RoundingMode;
-                #[cfg(feature = "js_rounding")]
-                let mode = RoundingMode::Ceiling;
-                #[cfg(not(feature = "js_rounding"))]
-                let mode = RoundingMode::HalfUp;
-                NumericValue::BigDecimal(bd.round(0).with_scale_round(0, mode))
-            }
-            NumericValue::NegativeZero => NumericValue::NegativeZero, // round(-0) = -0
-            NumericValue::NaN => NumericValue::NaN,
-            NumericValue::PositiveInfinity => NumericValue::PositiveInfinity,
-            NumericValue::NegativeInfinity => NumericValue::NegativeInfinity,
-        }
+        // JS semantics: round half toward positive infinity (round(-1.5) = -1);
+        // otherwise half away from zero (round(-1.5) = -2)
+        #[cfg(feature = "js_rounding")]
+        let mode = RoundingMode::HalfUp;
+        #[cfg(not(feature = "js_rounding"))]
+        let mode = RoundingMode::HalfAwayFromZero;
+        self.round_with(mode)
     }
 
+    /// Round to `dp` decimal places, ties to even
     pub fn round_dp(self, dp: u32) -> NumericValue {
-        match self {
-            NumericValue::Rational(r, _) => {
-                // Convert to Decimal for rounding, then back
-                use rust_decimal::Decimal;
-                let d = Decimal::from(*r.numer()) / Decimal::from(*r.denom());
-                NumericValue::Decimal(d.round_dp(dp))
-            }
-            NumericValue::Decimal(d) => NumericValue::Decimal(d.round_dp(dp)),
-            NumericValue::BigDecimal(bd) => {
-                // BigDecimal round to specified decimal places
-                NumericValue::BigDecimal(bd.round(dp as i64))
-            }
-            NumericValue::NegativeZero => NumericValue::NegativeZero, // round(-0) = -0
-            NumericValue::NaN => NumericValue::NaN,
-            NumericValue::PositiveInfinity => NumericValue::PositiveInfinity,
-            NumericValue::NegativeInfinity => NumericValue::NegativeInfinity,
-        }
+        self.round_dp_with(dp, RoundingMode::HalfEven)
     }
 
     pub fn trunc(self) -> NumericValue {
@@ -202,6 +406,7 @@ impl NumericValue {
                     NumericValue::from_rational(r.trunc())
                 }
             }
+            NumericValue::BigRational(r) => NumericValue::from_big_rational(r.trunc()),
             NumericValue::Decimal(d) => NumericValue::Decimal(d.trunc()),
             NumericValue::BigDecimal(bd) => NumericValue::BigDecimal(bd.with_scale(0)),
             NumericValue::NegativeZero => NumericValue::NegativeZero, // trunc(-0) = -0
@@ -263,6 +468,31 @@ impl NumericValue {
                 let decimal = Decimal::from(numer) / Decimal::from(denom);
                 NumericValue::Decimal(decimal).sqrt()
             }
+            NumericValue::BigRational(r) => {
+                if r.is_negative() {
+                    return NumericValue::NaN;
+                }
+                // Perfect squares stay exact: sqrt(n/d) = sqrt(n)/sqrt(d)
+                let (numer_sqrt, denom_sqrt) = (r.numer().sqrt(), r.denom().sqrt());
+                if &numer_sqrt * &numer_sqrt == *r.numer()
+                    && &denom_sqrt * &denom_sqrt == *r.denom()
+                {
+                    return NumericValue::from_big_rational(Ratio::new(numer_sqrt, denom_sqrt));
+                }
+
+                #[cfg(feature = "high_precision")]
+                {
+                    let precision = crate::precision::get_default_precision();
+                    if let Some(f) = to_rug_float(&NumericValue::BigRational(r.clone()), precision)
+                    {
+                        return NumericValue::BigDecimal(rug_float_to_bigdecimal(&f.sqrt()));
+                    }
+                }
+                pure_series!(sqrt(&NumericValue::BigRational(r.clone())));
+
+                use crate::representation::big_rational_to_bigdecimal;
+                NumericValue::BigDecimal(big_rational_to_bigdecimal(&r)).sqrt()
+            }
             NumericValue::Decimal(d) => {
                 if d < Decimal::ZERO {
                     return NumericValue::NaN; // sqrt of negative number is NaN in JS
@@ -279,6 +509,7 @@ impl NumericValue {
                         return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
                     }
                 }
+                pure_series!(sqrt(&NumericValue::Decimal(d)));
 
                 // Fallback: Babylonian method (Newton-Raphson) for square root
                 // Formula: x_{n+1} = (x_n + S/x_n) / 2
@@ -319,6 +550,8 @@ impl NumericValue {
                 } else if bd < bigdecimal::BigDecimal::from(0) {
                     NumericValue::NaN
                 } else {
+                    pure_series!(sqrt(&NumericValue::BigDecimal(bd.clone())));
+
                     // Use BigDecimal's built-in sqrt with default precision
                     NumericValue::BigDecimal(bd.sqrt().unwrap_or(bd))
                 }
@@ -330,7 +563,67 @@ impl NumericValue {
         }
     }
 
+    /// n-th root. Even roots follow `sqrt` (NaN for negatives, sqrt(-0) = +0);
+    /// odd roots are real for negative inputs and keep -0 and -∞.
+    pub fn nth_root(self, n: u32) -> NumericValue {
+        let odd = n % 2 == 1;
+        match &self {
+            NumericValue::NaN => return NumericValue::NaN,
+            _ if n == 0 => return NumericValue::NaN,
+            NumericValue::PositiveInfinity => return NumericValue::PositiveInfinity,
+            NumericValue::NegativeInfinity if odd => return NumericValue::NegativeInfinity,
+            NumericValue::NegativeInfinity => return NumericValue::NaN,
+            NumericValue::NegativeZero if odd => return NumericValue::NegativeZero,
+            NumericValue::NegativeZero => return NumericValue::zero(),
+            _ => {}
+        }
+        if n == 1 {
+            return self;
+        }
+        if n == 2 {
+            return self.sqrt();
+        }
+
+        // Perfect powers stay exact: cbrt(-27/8) = -3/2
+        let r = self.to_big_rational().unwrap_or_default();
+        if let Some(root) = exact_root(&r, n) {
+            return NumericValue::from_big_rational(root);
+        }
+        if r.is_negative() && !odd {
+            return NumericValue::NaN;
+        }
+
+        #[cfg(feature = "high_precision")]
+        {
+            // Use high-precision rug::Float
+            let precision = crate::precision::get_default_precision();
+            if let Some(f) = to_rug_float(&self, precision) {
+                let result = f.root(n);
+                return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
+            }
+        }
+        pure_series!(nth_root(&self, n));
+
+        // Fallback to f64
+        let f = self.to_f64();
+        let root = if n == 3 {
+            f.cbrt()
+        } else {
+            f.abs().powf(1.0 / f64::from(n)).copysign(f)
+        };
+        NumericValue::from(root)
+    }
+
     pub fn pow(self, exponent: NumericValue) -> NumericValue {
+        // Perfect powers stay exact: (27/8)^(1/3) = 3/2
+        if let Some(exact) = self.exact_pow(&exponent) {
+            return exact;
+        }
+        self.approximate_pow(exponent)
+    }
+
+    /// `pow` when the result has no exact rational form
+    fn approximate_pow(self, exponent: NumericValue) -> NumericValue {
         match (self, exponent) {
             // Rational base: handle sqrt specially, otherwise convert to Decimal
             (NumericValue::Rational(base, _), exp) => {
@@ -349,6 +642,18 @@ impl NumericValue {
                 let base_decimal = Decimal::from(*base.numer()) / Decimal::from(*base.denom());
                 NumericValue::Decimal(base_decimal).pow(exp)
             }
+            // BigRational base: exact for integer exponents, otherwise graduate to BigDecimal
+            (NumericValue::BigRational(base), exp) => {
+                if let NumericValue::Rational(exp_r, _) = &exp
+                    && exp_r.is_integer()
+                    && let Ok(n) = i32::try_from(*exp_r.numer())
+                    && (-1000..=1000).contains(&n)
+                {
+                    return NumericValue::from_big_rational(base.pow(n));
+                }
+                use crate::representation::big_rational_to_bigdecimal;
+                NumericValue::BigDecimal(big_rational_to_bigdecimal(&base)).pow(exp)
+            }
             // BigDecimal base: use high-precision or convert to f64
             (NumericValue::BigDecimal(base), exp) => {
                 #[cfg(feature = "high_precision")]
@@ -362,6 +667,7 @@ impl NumericValue {
                         return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
                     }
                 }
+                pure_series!(pow(&NumericValue::BigDecimal(base.clone()), &exp));
                 // Fallback to f64
                 let base_f64 = base.to_f64().unwrap_or(0.0);
                 let exp_f64 = exp.to_f64();
@@ -385,12 +691,19 @@ impl NumericValue {
                         return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
                     }
                 }
+                pure_series!(pow(&base, &NumericValue::BigDecimal(exp.clone())));
                 // Fallback to f64
                 let base_f64 = base.to_f64();
                 let exp_f64 = exp.to_f64().unwrap_or(0.0);
                 NumericValue::from(base_f64.powf(exp_f64))
             }
 
+            // BigRational exponent: graduate to BigDecimal
+            (base, NumericValue::BigRational(exp)) => {
+                use crate::representation::big_rational_to_bigdecimal;
+                base.pow(NumericValue::BigDecimal(big_rational_to_bigdecimal(&exp)))
+            }
+
             // Handle NaN cases first
             (NumericValue::NaN, NumericValue::Decimal(exp)) if exp.is_zero() => NumericValue::one(), // NaN**0 = 1 in JS
             (NumericValue::NaN, _) => NumericValue::NaN,
@@ -631,6 +944,10 @@ impl NumericValue {
                             }
                         }
                     }
+                    pure_series!(pow(
+                        &NumericValue::Decimal(base),
+                        &NumericValue::Decimal(exp)
+                    ));
 
                     // Fallback to f64
                     let ln_base = NumericValue::Decimal(base).log();
@@ -656,6 +973,11 @@ impl NumericValue {
             _ => {}
         }
 
+        // ln x is rational only at x = 1
+        if self.is_exactly(1) {
+            return NumericValue::zero();
+        }
+
         #[cfg(feature = "high_precision")]
         {
             // Use high-precision rug::Float
@@ -671,10 +993,13 @@ impl NumericValue {
                 return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
             }
         }
+        pure_series!(ln(&self));
 
         // Fallback to f64 (when high_precision is disabled or conversion failed)
         match self {
-            NumericValue::Rational(_, _) | NumericValue::BigDecimal(_) => {
+            NumericValue::Rational(_, _)
+            | NumericValue::BigRational(_)
+            | NumericValue::BigDecimal(_) => {
                 let f = self.to_f64();
                 if f <= 0.0 {
                     if f == 0.0 {
@@ -712,6 +1037,11 @@ impl NumericValue {
             _ => {}
         }
 
+        // Integer powers of ten have exact logarithms
+        if let Some(k) = self.integer_log(10) {
+            return NumericValue::from_rational(Ratio::from_integer(k));
+        }
+
         #[cfg(feature = "high_precision")]
         {
             // Use high-precision rug::Float
@@ -727,10 +1057,13 @@ impl NumericValue {
                 return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
             }
         }
+        pure_series!(log10(&self));
 
         // Fallback to f64
         match self {
-            NumericValue::Rational(_, _) | NumericValue::BigDecimal(_) => {
+            NumericValue::Rational(_, _)
+            | NumericValue::BigRational(_)
+            | NumericValue::BigDecimal(_) => {
                 let f = self.to_f64();
                 if f <= 0.0 {
                     if f == 0.0 {
@@ -768,6 +1101,11 @@ impl NumericValue {
             _ => {}
         }
 
+        // Integer powers of two have exact logarithms
+        if let Some(k) = self.integer_log(2) {
+            return NumericValue::from_rational(Ratio::from_integer(k));
+        }
+
         #[cfg(feature = "high_precision")]
         {
             // Use high-precision rug::Float
@@ -783,10 +1121,13 @@ impl NumericValue {
                 return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
             }
         }
+        pure_series!(log2(&self));
 
         // Fallback to f64
         match self {
-            NumericValue::Rational(_, _) | NumericValue::BigDecimal(_) => {
+            NumericValue::Rational(_, _)
+            | NumericValue::BigRational(_)
+            | NumericValue::BigDecimal(_) => {
                 let f = self.to_f64();
                 if f <= 0.0 {
                     if f == 0.0 {
@@ -814,6 +1155,47 @@ impl NumericValue {
         }
     }
 
+    /// ln(1 + x), accurate for x near 0 where `(1 + x).log()` loses digits
+    pub fn log1p(self) -> NumericValue {
+        // Special value handling first
+        match &self {
+            NumericValue::NegativeZero => return NumericValue::NegativeZero,
+            NumericValue::NaN | NumericValue::NegativeInfinity => return NumericValue::NaN,
+            NumericValue::PositiveInfinity => return NumericValue::PositiveInfinity,
+            _ => {}
+        }
+
+        // ln(1) = 0 exactly
+        if self.is_exactly(0) {
+            return NumericValue::zero();
+        }
+
+        // Pole at -1, undefined below
+        if let Some(r) = self.to_big_rational() {
+            let one_plus = r + BigRational::one();
+            if one_plus.is_negative() {
+                return NumericValue::NaN;
+            }
+            if one_plus.is_zero() {
+                return NumericValue::NegativeInfinity;
+            }
+        }
+
+        #[cfg(feature = "high_precision")]
+        {
+            // Use high-precision rug::Float
+            let precision = crate::precision::get_default_precision();
+            if let Some(f) = to_rug_float(&self, precision) {
+                let result = f.ln_1p();
+                return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
+            }
+        }
+        pure_series!(log1p(&self));
+
+        // Fallback to f64
+        NumericValue::from(self.to_f64().ln_1p())
+    }
+
     pub fn exp(self) -> NumericValue {
         // Special value handling first
         match &self {
@@ -824,6 +1206,11 @@ impl NumericValue {
             _ => {}
         }
 
+        // e^0 = 1 is the only rational value of exp at a rational point
+        if self.is_exactly(0) {
+            return NumericValue::one();
+        }
+
         #[cfg(feature = "high_precision")]
         {
             // Use high-precision rug::Float
@@ -833,10 +1220,13 @@ impl NumericValue {
                 return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
             }
         }
+        pure_series!(exp(&self));
 
         // Fallback to f64
         match self {
-            NumericValue::Rational(_, _) | NumericValue::BigDecimal(_) => {
+            NumericValue::Rational(_, _)
+            | NumericValue::BigRational(_)
+            | NumericValue::BigDecimal(_) => {
                 let f = self.to_f64();
                 NumericValue::from(f.exp())
             }
@@ -848,6 +1238,39 @@ impl NumericValue {
         }
     }
 
+    /// e^x - 1, accurate for x near 0 where `exp(x) - 1` cancels
+    pub fn expm1(self) -> NumericValue {
+        // Special value handling first
+        match &self {
+            NumericValue::NegativeZero => return NumericValue::NegativeZero,
+            NumericValue::NaN => return NumericValue::NaN,
+            NumericValue::PositiveInfinity => return NumericValue::PositiveInfinity,
+            NumericValue::NegativeInfinity => {
+                return NumericValue::from_rational(Ratio::from_integer(-1));
+            }
+            _ => {}
+        }
+
+        // e^0 - 1 = 0 exactly
+        if self.is_exactly(0) {
+            return NumericValue::zero();
+        }
+
+        #[cfg(feature = "high_precision")]
+        {
+            // Use high-precision rug::Float
+            let precision = crate::precision::get_default_precision();
+            if let Some(f) = to_rug_float(&self, precision) {
+                let result = f.exp_m1();
+                return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
+            }
+        }
+        pure_series!(expm1(&self));
+
+        // Fallback to f64
+        NumericValue::from(self.to_f64().exp_m1())
+    }
+
     pub fn sin(self) -> NumericValue {
         // Special value handling first
         match &self {
@@ -859,6 +1282,11 @@ impl NumericValue {
             _ => {}
         }
 
+        // sin(0) = 0 exactly
+        if self.is_exactly(0) {
+            return NumericValue::zero();
+        }
+
         #[cfg(feature = "high_precision")]
         {
             // Use high-precision rug::Float
@@ -868,10 +1296,13 @@ impl NumericValue {
                 return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
             }
         }
+        pure_series!(sin(&self));
 
         // Fallback to f64
         match self {
-            NumericValue::Rational(_, _) | NumericValue::BigDecimal(_) => {
+            NumericValue::Rational(_, _)
+            | NumericValue::BigRational(_)
+            | NumericValue::BigDecimal(_) => {
                 let f = self.to_f64();
                 NumericValue::from(f.sin())
             }
@@ -894,6 +1325,11 @@ impl NumericValue {
             _ => {}
         }
 
+        // cos(0) = 1 exactly
+        if self.is_exactly(0) {
+            return NumericValue::one();
+        }
+
         #[cfg(feature = "high_precision")]
         {
             // Use high-precision rug::Float
@@ -903,10 +1339,13 @@ impl NumericValue {
                 return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
             }
         }
+        pure_series!(cos(&self));
 
         // Fallback to f64
         match self {
-            NumericValue::Rational(_, _) | NumericValue::BigDecimal(_) => {
+            NumericValue::Rational(_, _)
+            | NumericValue::BigRational(_)
+            | NumericValue::BigDecimal(_) => {
                 let f = self.to_f64();
                 NumericValue::from(f.cos())
             }
@@ -929,6 +1368,11 @@ impl NumericValue {
             _ => {}
         }
 
+        // tan(0) = 0 exactly
+        if self.is_exactly(0) {
+            return NumericValue::zero();
+        }
+
         #[cfg(feature = "high_precision")]
         {
             // Use high-precision rug::Float
@@ -938,10 +1382,13 @@ impl NumericValue {
                 return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
             }
         }
+        pure_series!(tan(&self));
 
         // Fallback to f64
         match self {
-            NumericValue::Rational(_, _) | NumericValue::BigDecimal(_) => {
+            NumericValue::Rational(_, _)
+            | NumericValue::BigRational(_)
+            | NumericValue::BigDecimal(_) => {
                 let f = self.to_f64();
                 NumericValue::from(f.tan())
             }
@@ -964,6 +1411,11 @@ impl NumericValue {
             _ => {}
         }
 
+        // asin(0) = 0 exactly
+        if self.is_exactly(0) {
+            return NumericValue::zero();
+        }
+
         #[cfg(feature = "high_precision")]
         {
             // Use high-precision rug::Float
@@ -976,10 +1428,13 @@ impl NumericValue {
                 return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
             }
         }
+        pure_series!(asin(&self));
 
         // Fallback to f64
         match self {
-            NumericValue::Rational(_, _) | NumericValue::BigDecimal(_) => {
+            NumericValue::Rational(_, _)
+            | NumericValue::BigRational(_)
+            | NumericValue::BigDecimal(_) => {
                 let f = self.to_f64();
                 if f.abs() > 1.0 {
                     NumericValue::NaN
@@ -1010,6 +1465,11 @@ impl NumericValue {
             _ => {}
         }
 
+        // acos(1) = 0 exactly
+        if self.is_exactly(1) {
+            return NumericValue::zero();
+        }
+
         #[cfg(feature = "high_precision")]
         {
             // Use high-precision rug::Float
@@ -1022,10 +1482,13 @@ impl NumericValue {
                 return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
             }
         }
+        pure_series!(acos(&self));
 
         // Fallback to f64
         match self {
-            NumericValue::Rational(_, _) | NumericValue::BigDecimal(_) => {
+            NumericValue::Rational(_, _)
+            | NumericValue::BigRational(_)
+            | NumericValue::BigDecimal(_) => {
                 let f = self.to_f64();
                 if f.abs() > 1.0 {
                     NumericValue::NaN
@@ -1059,6 +1522,11 @@ impl NumericValue {
             _ => {}
         }
 
+        // atan(0) = 0 exactly
+        if self.is_exactly(0) {
+            return NumericValue::zero();
+        }
+
         #[cfg(feature = "high_precision")]
         {
             // Use high-precision rug::Float
@@ -1068,10 +1536,13 @@ impl NumericValue {
                 return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
             }
         }
+        pure_series!(atan(&self));
 
         // Fallback to f64
         match self {
-            NumericValue::Rational(_, _) | NumericValue::BigDecimal(_) => {
+            NumericValue::Rational(_, _)
+            | NumericValue::BigRational(_)
+            | NumericValue::BigDecimal(_) => {
                 let f = self.to_f64();
                 NumericValue::from(f.atan())
             }
@@ -1090,6 +1561,11 @@ impl NumericValue {
             _ => {}
         }
 
+        // atan2(0, x) = 0 exactly for x > 0
+        if self.is_exactly(0) && x.to_big_rational().is_some_and(|r| r.is_positive()) {
+            return NumericValue::zero();
+        }
+
         #[cfg(feature = "high_precision")]
         {
             // Try high-precision path
@@ -1101,6 +1577,7 @@ impl NumericValue {
                 return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
             }
         }
+        pure_series!(atan2(&self, &x));
 
         // Fallback to f64 for all cases (special handling for -0.0)
         let y_f64 = match &self {
@@ -1144,54 +1621,441 @@ impl NumericValue {
         }
     }
 
-    pub fn to_i32(&self) -> Option<i32> {
-        match self {
-            NumericValue::Rational(r, _) => {
-                if r.is_integer() {
-                    r.to_integer().to_i32()
-                } else {
-                    None
-                }
-            }
-            NumericValue::Decimal(d) => d.to_i32(),
-            NumericValue::BigDecimal(bd) => {
-                use bigdecimal::ToPrimitive;
-                bd.to_i32()
+    /// √(self² + other²), exact when the sum of squares is a perfect square
+    pub fn hypot(self, other: NumericValue) -> NumericValue {
+        // An infinite side wins over NaN, as in IEEE 754
+        match (&self, &other) {
+            (NumericValue::PositiveInfinity | NumericValue::NegativeInfinity, _)
+            | (_, NumericValue::PositiveInfinity | NumericValue::NegativeInfinity) => {
+                return NumericValue::PositiveInfinity;
             }
-            NumericValue::NegativeZero => Some(0),
-            NumericValue::NaN => None,
-            NumericValue::PositiveInfinity => None,
-            NumericValue::NegativeInfinity => None,
+            (NumericValue::NaN, _) | (_, NumericValue::NaN) => return NumericValue::NaN,
+            _ => {}
         }
-    }
 
-    pub fn to_u32(&self) -> Option<u32> {
-        match self {
-            NumericValue::Rational(r, _) => {
-                if r.is_integer() {
-                    r.to_integer().to_u32()
-                } else {
-                    None
-                }
-            }
-            NumericValue::Decimal(d) => d.to_u32(),
-            NumericValue::BigDecimal(bd) => {
-                use bigdecimal::ToPrimitive;
-                bd.to_u32()
-            }
-            NumericValue::NegativeZero => Some(0),
-            NumericValue::NaN => None,
-            NumericValue::PositiveInfinity => None,
-            NumericValue::NegativeInfinity => None,
+        let (Some(a), Some(b)) = (self.to_big_rational(), other.to_big_rational()) else {
+            return NumericValue::NaN;
+        };
+        let sum = &a * &a + &b * &b;
+
+        // Pythagorean triples (and any rational perfect square) stay exact
+        if let Some(root) = exact_root(&sum, 2) {
+            return NumericValue::from_big_rational(root);
         }
-    }
 
-    pub fn to_i64(&self) -> Option<i64> {
-        match self {
-            NumericValue::Rational(r, _) => {
-                if r.is_integer() {
-                    Some(*r.numer())
-                } else {
+        #[cfg(feature = "high_precision")]
+        {
+            // Use high-precision rug::Float
+            let precision = crate::precision::get_default_precision();
+            if let (Some(a_f), Some(b_f)) = (
+                to_rug_float(&self, precision),
+                to_rug_float(&other, precision),
+            ) {
+                let result = a_f.hypot(&b_f);
+                return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
+            }
+        }
+        pure_series!(sqrt(&NumericValue::from_big_rational(sum)));
+
+        // Fallback to f64 (hypot avoids overflow in the squares)
+        NumericValue::from(self.to_f64().hypot(other.to_f64()))
+    }
+
+    pub fn sinh(self) -> NumericValue {
+        // Special value handling first
+        match &self {
+            NumericValue::NegativeZero => return NumericValue::NegativeZero,
+            NumericValue::NaN => return NumericValue::NaN,
+            NumericValue::PositiveInfinity => return NumericValue::PositiveInfinity,
+            NumericValue::NegativeInfinity => return NumericValue::NegativeInfinity,
+            _ => {}
+        }
+
+        // sinh(0) = 0 exactly
+        if self.is_exactly(0) {
+            return NumericValue::zero();
+        }
+
+        #[cfg(feature = "high_precision")]
+        {
+            // Use high-precision rug::Float
+            let precision = crate::precision::get_default_precision();
+            if let Some(f) = to_rug_float(&self, precision) {
+                let result = f.sinh();
+                return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
+            }
+        }
+        pure_series!(sinh(&self));
+
+        // Fallback to f64
+        NumericValue::from(self.to_f64().sinh())
+    }
+
+    pub fn cosh(self) -> NumericValue {
+        // Special value handling first
+        match &self {
+            NumericValue::NegativeZero => return NumericValue::one(),
+            NumericValue::NaN => return NumericValue::NaN,
+            NumericValue::PositiveInfinity | NumericValue::NegativeInfinity => {
+                return NumericValue::PositiveInfinity;
+            }
+            _ => {}
+        }
+
+        // cosh(0) = 1 exactly
+        if self.is_exactly(0) {
+            return NumericValue::one();
+        }
+
+        #[cfg(feature = "high_precision")]
+        {
+            // Use high-precision rug::Float
+            let precision = crate::precision::get_default_precision();
+            if let Some(f) = to_rug_float(&self, precision) {
+                let result = f.cosh();
+                return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
+            }
+        }
+        pure_series!(cosh(&self));
+
+        // Fallback to f64
+        NumericValue::from(self.to_f64().cosh())
+    }
+
+    pub fn tanh(self) -> NumericValue {
+        // Special value handling first
+        match &self {
+            NumericValue::NegativeZero => return NumericValue::NegativeZero,
+            NumericValue::NaN => return NumericValue::NaN,
+            NumericValue::PositiveInfinity => return NumericValue::one(),
+            NumericValue::NegativeInfinity => {
+                return NumericValue::from_rational(Ratio::from_integer(-1));
+            }
+            _ => {}
+        }
+
+        // tanh(0) = 0 exactly
+        if self.is_exactly(0) {
+            return NumericValue::zero();
+        }
+
+        #[cfg(feature = "high_precision")]
+        {
+            // Use high-precision rug::Float
+            let precision = crate::precision::get_default_precision();
+            if let Some(f) = to_rug_float(&self, precision) {
+                let result = f.tanh();
+                return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
+            }
+        }
+        pure_series!(tanh(&self));
+
+        // Fallback to f64
+        NumericValue::from(self.to_f64().tanh())
+    }
+
+    pub fn asinh(self) -> NumericValue {
+        // Special value handling first
+        match &self {
+            NumericValue::NegativeZero => return NumericValue::NegativeZero,
+            NumericValue::NaN => return NumericValue::NaN,
+            NumericValue::PositiveInfinity => return NumericValue::PositiveInfinity,
+            NumericValue::NegativeInfinity => return NumericValue::NegativeInfinity,
+            _ => {}
+        }
+
+        // asinh(0) = 0 exactly
+        if self.is_exactly(0) {
+            return NumericValue::zero();
+        }
+
+        #[cfg(feature = "high_precision")]
+        {
+            // Use high-precision rug::Float
+            let precision = crate::precision::get_default_precision();
+            if let Some(f) = to_rug_float(&self, precision) {
+                let result = f.asinh();
+                return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
+            }
+        }
+        pure_series!(asinh(&self));
+
+        // Fallback to f64
+        NumericValue::from(self.to_f64().asinh())
+    }
+
+    pub fn acosh(self) -> NumericValue {
+        // Special value handling first
+        match &self {
+            NumericValue::NaN | NumericValue::NegativeInfinity | NumericValue::NegativeZero => {
+                return NumericValue::NaN;
+            }
+            NumericValue::PositiveInfinity => return NumericValue::PositiveInfinity,
+            _ => {}
+        }
+
+        // acosh(1) = 0 exactly
+        if self.is_exactly(1) {
+            return NumericValue::zero();
+        }
+
+        // Defined only for x >= 1
+        if self
+            .to_big_rational()
+            .is_some_and(|r| r < BigRational::one())
+        {
+            return NumericValue::NaN;
+        }
+
+        #[cfg(feature = "high_precision")]
+        {
+            // Use high-precision rug::Float
+            let precision = crate::precision::get_default_precision();
+            if let Some(f) = to_rug_float(&self, precision) {
+                let result = f.acosh();
+                return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
+            }
+        }
+        pure_series!(acosh(&self));
+
+        // Fallback to f64
+        NumericValue::from(self.to_f64().acosh())
+    }
+
+    pub fn atanh(self) -> NumericValue {
+        // Special value handling first
+        match &self {
+            NumericValue::NegativeZero => return NumericValue::NegativeZero,
+            NumericValue::NaN => return NumericValue::NaN,
+            NumericValue::PositiveInfinity | NumericValue::NegativeInfinity => {
+                return NumericValue::NaN;
+            }
+            _ => {}
+        }
+
+        // atanh(0) = 0 exactly
+        if self.is_exactly(0) {
+            return NumericValue::zero();
+        }
+
+        // Poles at ±1, undefined beyond
+        if let Some(r) = self.to_big_rational() {
+            let magnitude = r.abs();
+            if magnitude > BigRational::one() {
+                return NumericValue::NaN;
+            }
+            if magnitude.is_one() {
+                return if r.is_negative() {
+                    NumericValue::NegativeInfinity
+                } else {
+                    NumericValue::PositiveInfinity
+                };
+            }
+        }
+
+        #[cfg(feature = "high_precision")]
+        {
+            // Use high-precision rug::Float
+            let precision = crate::precision::get_default_precision();
+            if let Some(f) = to_rug_float(&self, precision) {
+                let result = f.atanh();
+                return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
+            }
+        }
+        pure_series!(atanh(&self));
+
+        // Fallback to f64
+        NumericValue::from(self.to_f64().atanh())
+    }
+
+    /// Γ(self) when it is exact: (n − 1)! for a positive integer n up to 10 001
+    pub(crate) fn exact_gamma(&self) -> Option<NumericValue> {
+        use crate::number_theory::{MAX_EXACT_FACTORIAL, factorial_big, from_integer};
+        let n = self.to_integer()?.to_u64()?;
+        (1..=MAX_EXACT_FACTORIAL + 1)
+            .contains(&n)
+            .then(|| from_integer(factorial_big(n - 1)))
+    }
+
+    /// Γ(x). Exact for positive integers (Γ(n) = (n − 1)!); half-integers are an
+    /// exact rational times √π, e.g. Γ(1/2) = √π and Γ(5/2) = (3/4)√π.
+    pub fn gamma(self) -> NumericValue {
+        // Special value handling first
+        match &self {
+            NumericValue::NaN | NumericValue::NegativeInfinity => return NumericValue::NaN,
+            NumericValue::PositiveInfinity => return NumericValue::PositiveInfinity,
+            NumericValue::NegativeZero => return NumericValue::NegativeInfinity,
+            _ => {}
+        }
+
+        if let Some(exact) = self.exact_gamma() {
+            return exact;
+        }
+
+        if let Some(r) = self.to_big_rational() {
+            // Poles at 0 and the negative integers
+            if r.is_zero() {
+                return NumericValue::PositiveInfinity;
+            }
+            if r.is_integer() && r.is_negative() {
+                return NumericValue::NaN;
+            }
+            if let Some(c) = half_integer_gamma_coefficient(&r) {
+                return sqrt_pi_times(c);
+            }
+        }
+
+        #[cfg(feature = "high_precision")]
+        {
+            // Use high-precision rug::Float
+            let precision = crate::precision::get_default_precision();
+            if let Some(f) = to_rug_float(&self, precision) {
+                let result = f.gamma();
+                return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
+            }
+        }
+
+        // Fallback to f64 (Lanczos; no pure-Rust series for Γ)
+        let x = self.to_f64();
+        let result = gamma_f64(x);
+        if result.is_infinite() && x > 0.0 {
+            return exp_beyond_f64(ln_gamma_f64(x));
+        }
+        NumericValue::from(result)
+    }
+
+    /// ln |Γ(x)|. Exactly 0 at x = 1 and x = 2; +∞ at the poles.
+    pub fn ln_gamma(self) -> NumericValue {
+        // Special value handling first
+        match &self {
+            NumericValue::NaN => return NumericValue::NaN,
+            NumericValue::PositiveInfinity
+            | NumericValue::NegativeInfinity
+            | NumericValue::NegativeZero => return NumericValue::PositiveInfinity,
+            _ => {}
+        }
+
+        // Γ(1) = Γ(2) = 1
+        if self.is_exactly(1) || self.is_exactly(2) {
+            return NumericValue::zero();
+        }
+
+        // Poles at 0 and the negative integers
+        if self
+            .to_big_rational()
+            .is_some_and(|r| r.is_integer() && !r.is_positive())
+        {
+            return NumericValue::PositiveInfinity;
+        }
+
+        #[cfg(feature = "high_precision")]
+        {
+            // Use high-precision rug::Float
+            let precision = crate::precision::get_default_precision();
+            if let Some(f) = to_rug_float(&self, precision) {
+                let (result, _sign) = f.ln_abs_gamma();
+                return NumericValue::BigDecimal(rug_float_to_bigdecimal(&result));
+            }
+        }
+
+        // Fallback to f64 (Lanczos; no pure-Rust series for Γ)
+        NumericValue::from(ln_gamma_f64(self.to_f64()))
+    }
+
+    /// self · a + b with a single rounding.
+    ///
+    /// Finite operands are combined exactly as rationals and converted once, so
+    /// no digits are lost between the product and the sum.
+    pub fn mul_add(self, a: NumericValue, b: NumericValue) -> NumericValue {
+        match (
+            self.to_big_rational(),
+            a.to_big_rational(),
+            b.to_big_rational(),
+        ) {
+            (Some(x), Some(y), Some(z))
+                if !matches!(
+                    (&self, &a, &b),
+                    (NumericValue::NegativeZero, _, _)
+                        | (_, NumericValue::NegativeZero, _)
+                        | (_, _, NumericValue::NegativeZero)
+                ) =>
+            {
+                NumericValue::from_big_rational(x * y + z)
+            }
+            // NaN, infinities and signed zeros follow the usual operators
+            _ => ((self * a).0 + b).0,
+        }
+    }
+
+    pub fn to_i32(&self) -> Option<i32> {
+        match self {
+            NumericValue::Rational(r, _) => {
+                if r.is_integer() {
+                    r.to_integer().to_i32()
+                } else {
+                    None
+                }
+            }
+            NumericValue::BigRational(r) => {
+                if r.is_integer() {
+                    r.to_integer().to_i32()
+                } else {
+                    None
+                }
+            }
+            NumericValue::Decimal(d) => d.to_i32(),
+            NumericValue::BigDecimal(bd) => {
+                use bigdecimal::ToPrimitive;
+                bd.to_i32()
+            }
+            NumericValue::NegativeZero => Some(0),
+            NumericValue::NaN => None,
+            NumericValue::PositiveInfinity => None,
+            NumericValue::NegativeInfinity => None,
+        }
+    }
+
+    pub fn to_u32(&self) -> Option<u32> {
+        match self {
+            NumericValue::Rational(r, _) => {
+                if r.is_integer() {
+                    r.to_integer().to_u32()
+                } else {
+                    None
+                }
+            }
+            NumericValue::BigRational(r) => {
+                if r.is_integer() {
+                    r.to_integer().to_u32()
+                } else {
+                    None
+                }
+            }
+            NumericValue::Decimal(d) => d.to_u32(),
+            NumericValue::BigDecimal(bd) => {
+                use bigdecimal::ToPrimitive;
+                bd.to_u32()
+            }
+            NumericValue::NegativeZero => Some(0),
+            NumericValue::NaN => None,
+            NumericValue::PositiveInfinity => None,
+            NumericValue::NegativeInfinity => None,
+        }
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        match self {
+            NumericValue::Rational(r, _) => {
+                if r.is_integer() {
+                    Some(*r.numer())
+                } else {
+                    None
+                }
+            }
+            NumericValue::BigRational(r) => {
+                if r.is_integer() {
+                    r.to_integer().to_i64()
+                } else {
                     None
                 }
             }
@@ -1212,6 +2076,7 @@ impl NumericValue {
             NumericValue::Rational(r, _) => {
                 r.numer().to_f64().unwrap_or(0.0) / r.denom().to_f64().unwrap_or(1.0)
             }
+            NumericValue::BigRational(r) => r.to_f64().unwrap_or(f64::NAN),
             NumericValue::Decimal(d) => d.to_f64().expect("Decimal always fits in f64"),
             NumericValue::BigDecimal(bd) => {
                 // BigDecimal to f64 conversion may lose precision
@@ -1237,6 +2102,11 @@ impl NumericValue {
                     None
                 }
             }
+            // Arithmetic only makes non-terminating BigRationals, but `to_repr` can hold any value
+            NumericValue::BigRational(_) => match self.convert_to(crate::Repr::Decimal) {
+                Ok(NumericValue::Decimal(d)) => Some(d),
+                _ => None,
+            },
             NumericValue::Decimal(d) => Some(*d),
             NumericValue::BigDecimal(_) => None, // TODO: implement conversion
             NumericValue::NegativeZero => Some(Decimal::ZERO),
@@ -1250,6 +2120,23 @@ impl NumericValue {
     // }
 }
 
+/// Approximation carried by a math-function result: `Transcendental` when the
+/// result had to be rounded, otherwise whatever the input already carried
+/// (an exact point like `log(1)` is only exact for an exact input).
+fn transcendental_apprx(
+    result: &NumericValue,
+    input: Option<crate::ApproximationType>,
+) -> Option<crate::ApproximationType> {
+    if matches!(
+        result,
+        NumericValue::Decimal(_) | NumericValue::BigDecimal(_)
+    ) {
+        Some(crate::ApproximationType::Transcendental)
+    } else {
+        input
+    }
+}
+
 // Add this implementation block for Number in math.rs
 impl Number {
     // Mathematical functions - delegating to NumericValue
@@ -1258,6 +2145,8 @@ impl Number {
         Number {
             value: self.value.abs(),
             apprx: self.apprx,
+            err_bound: self.err_bound,
+            provenance: self.provenance,
         }
     }
 
@@ -1266,6 +2155,8 @@ impl Number {
             value: self.value.floor(),
             // Rounding removes approximate decimal digits - result is exact
             apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
         }
     }
 
@@ -1274,6 +2165,8 @@ impl Number {
             value: self.value.ceil(),
             // Rounding removes approximate decimal digits - result is exact
             apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
         }
     }
 
@@ -1282,6 +2175,8 @@ impl Number {
             value: self.value.round(),
             // Rounding removes approximate decimal digits - result is exact
             apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
         }
     }
 
@@ -1290,6 +2185,8 @@ impl Number {
             value: self.value.round_dp(dp),
             // Rounding removes approximate decimal digits - result is exact
             apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
         }
     }
 
@@ -1298,227 +2195,589 @@ impl Number {
             value: self.value.trunc(),
             // Truncation removes approximate decimal digits - result is exact
             apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
         }
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn sqrt(self) -> Number {
-        use crate::ApproximationType;
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
         let result_value = self.value.sqrt();
 
         // Transcendental if result is Decimal or BigDecimal (approximation)
-        // If result is Rational (like sqrt(4) = 2), it's exact
-        let apprx = if matches!(
-            result_value,
-            NumericValue::Decimal(_) | NumericValue::BigDecimal(_)
-        ) {
-            Some(ApproximationType::Transcendental)
-        } else {
-            None
+        // If result is Rational (like sqrt(4) = 2), it's as exact as the input
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::sqrt_slope)
+        });
+
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
         };
+        context::audit("sqrt", result, operands)
+    }
 
-        Number {
+    /// Cube root. Exact for perfect cubes, including negative ones (`cbrt(-27) = -3`).
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn cbrt(self) -> Number {
+        self.root("cbrt", 3)
+    }
+
+    /// n-th root. Exact for perfect n-th powers; odd roots of negative numbers are real,
+    /// even roots of negative numbers are NaN.
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn nth_root(self, n: u32) -> Number {
+        self.root("nth_root", n)
+    }
+
+    #[cfg_attr(feature = "provenance", track_caller)]
+    fn root(self, op: &'static str, n: u32) -> Number {
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
+        let result_value = self.value.nth_root(n);
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::root_slope(n))
+        });
+        let result = Number {
             value: result_value,
             apprx,
-        }
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit(op, result, operands)
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn pow(self, exponent: Number) -> Number {
+        let operands = context::Operands::capture(&self, Some(&exponent));
         use crate::ApproximationType;
+        let exact_value = self.value.exact_pow(&exponent.value);
         let is_approximated = self.is_transcendental()
             || exponent.is_transcendental()
-            || self.is_transcendental_pow(&exponent);
+            || (exact_value.is_none() && self.is_transcendental_pow(&exponent));
 
-        Number {
-            value: self.value.pow(exponent.value),
+        // An exact integer exponent only needs the base's slope
+        let exact_int_exponent = match &exponent.value {
+            NumericValue::Rational(r, _) if exponent.is_exact() && r.is_integer() => {
+                i32::try_from(*r.numer()).ok()
+            }
+            _ => None,
+        };
+        let base_input = error_bound::Tracked::of(&self);
+        let exponent_input = error_bound::Tracked::of(&exponent);
+
+        let result_value = match exact_value {
+            Some(value) => value,
+            None => self.value.approximate_pow(exponent.value),
+        };
+        let err_bound = if !is_approximated {
+            None
+        } else if let Some(n) = exact_int_exponent {
+            error_bound::transcendental(base_input, &result_value, error_bound::powi_slope(n))
+        } else {
+            error_bound::transcendental2(
+                base_input,
+                exponent_input,
+                &result_value,
+                error_bound::pow_partials,
+            )
+        };
+
+        let result = Number {
+            value: result_value,
             apprx: if is_approximated {
                 Some(ApproximationType::Transcendental)
             } else {
                 None
             },
-        }
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("pow", result, operands)
     }
 
     // Transcendental functions - mark as transcendental only if result is approximated
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn log(self) -> Number {
-        use crate::ApproximationType;
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
         let result_value = self.value.log();
-        Number {
-            value: result_value.clone(),
-            apprx: if matches!(
-                result_value,
-                NumericValue::Decimal(_) | NumericValue::BigDecimal(_)
-            ) {
-                Some(ApproximationType::Transcendental)
-            } else {
-                None
-            },
-        }
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx
+            .as_ref()
+            .and_then(|_| error_bound::transcendental(input, &result_value, error_bound::ln_slope));
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("log", result, operands)
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn log10(self) -> Number {
-        use crate::ApproximationType;
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
         let result_value = self.value.log10();
-        Number {
-            value: result_value.clone(),
-            apprx: if matches!(
-                result_value,
-                NumericValue::Decimal(_) | NumericValue::BigDecimal(_)
-            ) {
-                Some(ApproximationType::Transcendental)
-            } else {
-                None
-            },
-        }
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::log10_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("log10", result, operands)
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn log2(self) -> Number {
-        use crate::ApproximationType;
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
         let result_value = self.value.log2();
-        Number {
-            value: result_value.clone(),
-            apprx: if matches!(
-                result_value,
-                NumericValue::Decimal(_) | NumericValue::BigDecimal(_)
-            ) {
-                Some(ApproximationType::Transcendental)
-            } else {
-                None
-            },
-        }
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::log2_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("log2", result, operands)
+    }
+
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn log1p(self) -> Number {
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
+        let result_value = self.value.log1p();
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::log1p_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("log1p", result, operands)
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn exp(self) -> Number {
-        use crate::ApproximationType;
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
         let result_value = self.value.exp();
-        Number {
-            value: result_value.clone(),
-            apprx: if matches!(
-                result_value,
-                NumericValue::Decimal(_) | NumericValue::BigDecimal(_)
-            ) {
-                Some(ApproximationType::Transcendental)
-            } else {
-                None
-            },
-        }
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::exp_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("exp", result, operands)
+    }
+
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn expm1(self) -> Number {
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
+        let result_value = self.value.expm1();
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::exp_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("expm1", result, operands)
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn sin(self) -> Number {
-        use crate::ApproximationType;
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
         let result_value = self.value.sin();
-        Number {
-            value: result_value.clone(),
-            apprx: if matches!(
-                result_value,
-                NumericValue::Decimal(_) | NumericValue::BigDecimal(_)
-            ) {
-                Some(ApproximationType::Transcendental)
-            } else {
-                None
-            },
-        }
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::unit_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("sin", result, operands)
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn cos(self) -> Number {
-        use crate::ApproximationType;
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
         let result_value = self.value.cos();
-        Number {
-            value: result_value.clone(),
-            apprx: if matches!(
-                result_value,
-                NumericValue::Decimal(_) | NumericValue::BigDecimal(_)
-            ) {
-                Some(ApproximationType::Transcendental)
-            } else {
-                None
-            },
-        }
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::unit_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("cos", result, operands)
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn tan(self) -> Number {
-        use crate::ApproximationType;
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
         let result_value = self.value.tan();
-        Number {
-            value: result_value.clone(),
-            apprx: if matches!(
-                result_value,
-                NumericValue::Decimal(_) | NumericValue::BigDecimal(_)
-            ) {
-                Some(ApproximationType::Transcendental)
-            } else {
-                None
-            },
-        }
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::tan_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("tan", result, operands)
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn asin(self) -> Number {
-        use crate::ApproximationType;
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
         let result_value = self.value.asin();
-        Number {
-            value: result_value.clone(),
-            apprx: if matches!(
-                result_value,
-                NumericValue::Decimal(_) | NumericValue::BigDecimal(_)
-            ) {
-                Some(ApproximationType::Transcendental)
-            } else {
-                None
-            },
-        }
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::asin_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("asin", result, operands)
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn acos(self) -> Number {
-        use crate::ApproximationType;
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
         let result_value = self.value.acos();
-        Number {
-            value: result_value.clone(),
-            apprx: if matches!(
-                result_value,
-                NumericValue::Decimal(_) | NumericValue::BigDecimal(_)
-            ) {
-                Some(ApproximationType::Transcendental)
-            } else {
-                None
-            },
-        }
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::asin_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("acos", result, operands)
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn atan(self) -> Number {
-        use crate::ApproximationType;
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
         let result_value = self.value.atan();
-        Number {
-            value: result_value.clone(),
-            apprx: if matches!(
-                result_value,
-                NumericValue::Decimal(_) | NumericValue::BigDecimal(_)
-            ) {
-                Some(ApproximationType::Transcendental)
-            } else {
-                None
-            },
-        }
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::unit_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("atan", result, operands)
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn atan2(self, x: Number) -> Number {
-        use crate::ApproximationType;
+        let operands = context::Operands::capture(&self, Some(&x));
+        let inputs = (
+            error_bound::Tracked::of(&self),
+            error_bound::Tracked::of(&x),
+        );
         let result_value = self.value.atan2(x.value);
-        Number {
-            value: result_value.clone(),
-            apprx: if matches!(
-                result_value,
-                NumericValue::Decimal(_) | NumericValue::BigDecimal(_)
-            ) {
-                Some(ApproximationType::Transcendental)
-            } else {
-                None
-            },
-        }
+        let apprx = transcendental_apprx(&result_value, self.apprx.or(x.apprx));
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental2(
+                inputs.0,
+                inputs.1,
+                &result_value,
+                error_bound::atan2_partials,
+            )
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("atan2", result, operands)
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn hypot(self, other: Number) -> Number {
+        let operands = context::Operands::capture(&self, Some(&other));
+        let inputs = (
+            error_bound::Tracked::of(&self),
+            error_bound::Tracked::of(&other),
+        );
+        let result_value = self.value.hypot(other.value);
+        let apprx = transcendental_apprx(&result_value, self.apprx.or(other.apprx));
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental2(
+                inputs.0,
+                inputs.1,
+                &result_value,
+                error_bound::hypot_partials,
+            )
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("hypot", result, operands)
+    }
+
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn sinh(self) -> Number {
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
+        let result_value = self.value.sinh();
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::sinh_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("sinh", result, operands)
+    }
+
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn cosh(self) -> Number {
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
+        let result_value = self.value.cosh();
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::cosh_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("cosh", result, operands)
+    }
+
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn tanh(self) -> Number {
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
+        let result_value = self.value.tanh();
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::unit_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("tanh", result, operands)
+    }
+
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn asinh(self) -> Number {
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
+        let result_value = self.value.asinh();
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::unit_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("asinh", result, operands)
+    }
+
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn acosh(self) -> Number {
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
+        let result_value = self.value.acosh();
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::acosh_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("acosh", result, operands)
+    }
+
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn atanh(self) -> Number {
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
+        let result_value = self.value.atanh();
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx.as_ref().and_then(|_| {
+            error_bound::transcendental(input, &result_value, error_bound::atanh_slope)
+        });
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("atanh", result, operands)
+    }
+
+    /// Gamma function Γ(x), with Γ(n) = (n − 1)! exact for positive integers.
+    ///
+    /// Half-integers are computed as an exact rational times √π. NaN at the
+    /// negative integers, ±∞ at ±0.
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn gamma(self) -> Number {
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
+        // (n − 1)! can be a BigDecimal integer and still exact
+        let (result_value, apprx) = match self.value.exact_gamma() {
+            Some(value) => (value, self.apprx),
+            None => {
+                let value = self.value.gamma();
+                let apprx = transcendental_apprx(&value, self.apprx);
+                (value, apprx)
+            }
+        };
+        let err_bound = apprx
+            .as_ref()
+            .and_then(|_| error_bound::gamma(input, &result_value));
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("gamma", result, operands)
+    }
+
+    /// ln |Γ(x)|, which stays finite where Γ itself overflows (x > 171)
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn ln_gamma(self) -> Number {
+        let operands = context::Operands::capture(&self, None);
+        let input = error_bound::Tracked::of(&self);
+        let result_value = self.value.ln_gamma();
+        let apprx = transcendental_apprx(&result_value, self.apprx);
+        let err_bound = apprx
+            .as_ref()
+            .and_then(|_| error_bound::ln_gamma(input, &result_value));
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("ln_gamma", result, operands)
+    }
+
+    /// self · a + b, rounded once instead of after both the product and the sum
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn mul_add(self, a: Number, b: Number) -> Number {
+        // Provenance holds two operands: the product's, or the addend when only it is approximate
+        let operands = if self.apprx.is_none() && a.apprx.is_none() {
+            context::Operands::capture(&b, None)
+        } else {
+            context::Operands::capture(&self, Some(&a))
+        };
+        let transcendental =
+            self.is_transcendental() || a.is_transcendental() || b.is_transcendental();
+        let rational_approximation = self.is_rational_approximation()
+            || a.is_rational_approximation()
+            || b.is_rational_approximation();
+        let tracked = (
+            error_bound::Tracked::of(&self),
+            error_bound::Tracked::of(&a),
+            error_bound::Tracked::of(&b),
+        );
+
+        let result_value = self.value.mul_add(a.value, b.value);
+        let apprx = crate::ops::arithmetic::combine_approximation_flags(
+            transcendental,
+            false,
+            rational_approximation,
+            false,
+            false,
+            &result_value,
+        );
+        let err_bound = match (&apprx, tracked) {
+            (Some(_), (Some(x), Some(y), Some(z))) => {
+                Some(error_bound::mul_add(x, y, z, &result_value))
+            }
+            _ => None,
+        };
+        let result = Number {
+            value: result_value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("mul_add", result, operands)
+    }
+
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn increment(self) -> Number {
+        let operands = context::Operands::capture(&self, None);
         // Extract flags BEFORE moving self.value
         let self_trans = self.is_transcendental();
         let self_rat_approx = self.is_rational_approximation();
+        let tracked = error_bound::Tracked::pair(&self, &Number::ONE());
 
         let (value, rat_overflow) = self.value + NumericValue::one();
 
@@ -1531,15 +2790,28 @@ impl Number {
             rat_overflow,
             &value,
         );
+        let err_bound = apprx
+            .as_ref()
+            .and(tracked)
+            .map(|(a, b)| error_bound::add(a, b, &value));
 
-        Number { value, apprx }
+        let result = Number {
+            value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("increment", result, operands)
     }
 
+    #[cfg_attr(feature = "provenance", track_caller)]
     pub fn decrement(self) -> Number {
+        let operands = context::Operands::capture(&self, None);
         use crate::ops::arithmetic::combine_approximation_flags;
 
         let self_trans = self.is_transcendental();
         let self_rat_approx = self.is_rational_approximation();
+        let tracked = error_bound::Tracked::pair(&self, &Number::ONE());
 
         let (value, rat_overflow) = self.value - NumericValue::one();
 
@@ -1551,8 +2823,18 @@ impl Number {
             rat_overflow,
             &value,
         );
+        let err_bound = apprx
+            .as_ref()
+            .and(tracked)
+            .map(|(a, b)| error_bound::add(a, b, &value));
 
-        Number { value, apprx }
+        let result = Number {
+            value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("decrement", result, operands)
     }
 
     pub fn to_primitive(&self) -> Number {
@@ -1565,6 +2847,7 @@ impl Number {
         match &exponent.value {
             NumericValue::Decimal(d) => !d.fract().is_zero(),
             NumericValue::Rational(r, _) => !r.is_integer(),
+            NumericValue::BigRational(r) => !r.is_integer(),
             _ => false,
         }
     }
@@ -0,0 +1,400 @@
+use crate::context;
+use crate::error_bound;
+use crate::provenance::Trail;
+use crate::{ApproximationType, Number, NumericValue};
+use bigdecimal::num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+/// Bases for Miller-Rabin: the first 13 primes make the test deterministic
+/// for every n < 3.3·10^24
+const WITNESSES: [u32; 13] = [2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41];
+
+/// Greatest common divisor of |a| and |b| (Euclid); gcd(0, 0) = 0
+fn gcd_big(a: &BigInt, b: &BigInt) -> BigInt {
+    let (mut a, mut b) = (a.abs(), b.abs());
+    while !b.is_zero() {
+        let r = &a % &b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Least common multiple of |a| and |b|; 0 when either is 0
+fn lcm_big(a: &BigInt, b: &BigInt) -> BigInt {
+    if a.is_zero() || b.is_zero() {
+        return BigInt::zero();
+    }
+    (a / gcd_big(a, b) * b).abs()
+}
+
+/// Largest n for which n! (or a product of n factors) is expanded exactly
+pub(crate) const MAX_EXACT_FACTORIAL: u64 = 10_000;
+
+pub(crate) fn factorial_big(n: u64) -> BigInt {
+    (2..=n).fold(BigInt::one(), |acc, k| acc * k)
+}
+
+/// C(n, k) for an integer n. Each partial product is C(n, i + 1), an integer, so
+/// every division is exact and nothing grows past the result.
+fn binomial_big(n: &BigInt, k: u64) -> BigInt {
+    (0..k).fold(BigInt::one(), |acc, i| acc * (n - i) / (i + 1))
+}
+
+/// x (x − 1) ⋯ (x − k + 1), exactly
+fn falling_big(x: &BigRational, k: u64) -> BigRational {
+    (0..k).fold(BigRational::one(), |acc, i| {
+        acc * (x - BigRational::from_integer(i.into()))
+    })
+}
+
+pub(crate) fn from_integer(n: BigInt) -> NumericValue {
+    NumericValue::from_big_rational(BigRational::from_integer(n))
+}
+
+impl NumericValue {
+    /// The value as a BigInt when it is a finite integer (in any representation)
+    pub(crate) fn to_integer(&self) -> Option<BigInt> {
+        self.to_big_rational()
+            .filter(|r| r.is_integer())
+            .map(|r| r.numer().clone())
+    }
+
+    /// The value as a u64 when it is a non-negative integer that fits
+    fn to_u64_integer(&self) -> Option<u64> {
+        self.to_integer().and_then(|n| n.to_u64())
+    }
+
+    /// True for finite integer values, including -0
+    pub fn is_integer(&self) -> bool {
+        self.to_integer().is_some()
+    }
+
+    /// Greatest common divisor, always non-negative.
+    ///
+    /// For non-integer rationals this is gcd(numerators) / lcm(denominators),
+    /// the largest rational that divides both an integer number of times.
+    pub fn gcd(self, other: NumericValue) -> NumericValue {
+        let (Some(a), Some(b)) = (self.to_big_rational(), other.to_big_rational()) else {
+            return NumericValue::NaN;
+        };
+        NumericValue::from_big_rational(BigRational::new(
+            gcd_big(a.numer(), b.numer()),
+            lcm_big(a.denom(), b.denom()),
+        ))
+    }
+
+    /// Least common multiple, always non-negative; lcm(numerators) / gcd(denominators)
+    /// for non-integer rationals
+    pub fn lcm(self, other: NumericValue) -> NumericValue {
+        let (Some(a), Some(b)) = (self.to_big_rational(), other.to_big_rational()) else {
+            return NumericValue::NaN;
+        };
+        NumericValue::from_big_rational(BigRational::new(
+            lcm_big(a.numer(), b.numer()),
+            gcd_big(a.denom(), b.denom()),
+        ))
+    }
+
+    /// self^exponent mod modulus, with the result taking the sign of the modulus
+    /// (in [0, m) for m > 0).
+    ///
+    /// NaN for non-integer operands, a negative exponent or a zero modulus.
+    pub fn mod_pow(self, exponent: NumericValue, modulus: NumericValue) -> NumericValue {
+        match (
+            self.to_integer(),
+            exponent.to_integer(),
+            modulus.to_integer(),
+        ) {
+            (Some(b), Some(e), Some(m)) if !e.is_negative() && !m.is_zero() => {
+                from_integer(b.modpow(&e, &m))
+            }
+            _ => NumericValue::NaN,
+        }
+    }
+
+    /// floor(√self) for a non-negative integer; NaN otherwise
+    pub fn isqrt(self) -> NumericValue {
+        match self.to_integer() {
+            Some(n) if !n.is_negative() => from_integer(n.sqrt()),
+            _ => NumericValue::NaN,
+        }
+    }
+
+    /// n! for a non-negative integer, exact up to 10 000! and Γ(n + 1) beyond.
+    ///
+    /// NaN for negative or non-integer values (see `gamma`).
+    pub fn factorial(self) -> NumericValue {
+        if matches!(self, NumericValue::PositiveInfinity) {
+            return NumericValue::PositiveInfinity;
+        }
+        match self.to_integer() {
+            Some(n) if n.is_negative() => NumericValue::NaN,
+            Some(n) => match n.to_u64().filter(|&n| n <= MAX_EXACT_FACTORIAL) {
+                Some(n) => from_integer(factorial_big(n)),
+                None => from_integer(n + 1u32).gamma(),
+            },
+            None => NumericValue::NaN,
+        }
+    }
+
+    /// self (self − 1) ⋯ (self − k + 1) for a non-negative integer k, exact for
+    /// any rational self. NaN when k is not an integer in 0..=10 000.
+    pub fn falling_factorial(self, k: NumericValue) -> NumericValue {
+        let (Some(x), Some(k)) = (self.to_big_rational(), k.to_u64_integer()) else {
+            return NumericValue::NaN;
+        };
+        if k > MAX_EXACT_FACTORIAL {
+            return NumericValue::NaN;
+        }
+        NumericValue::from_big_rational(falling_big(&x, k))
+    }
+
+    /// Binomial coefficient C(self, k), exact for any rational self and integer k.
+    ///
+    /// Zero for k < 0, and for k > n when self is a non-negative integer n. Past
+    /// 10 000 factors it is Γ(n + 1) / (Γ(k + 1) Γ(n − k + 1)) for a non-negative
+    /// integer n, and NaN otherwise.
+    pub fn binomial(self, k: NumericValue) -> NumericValue {
+        if let Some((n, k)) = self.binomial_beyond_exact(&k) {
+            use crate::math::{exp_beyond_f64, ln_gamma_f64};
+            let ln = ln_gamma_f64(n + 1.0) - ln_gamma_f64(k + 1.0) - ln_gamma_f64(n - k + 1.0);
+            return exp_beyond_f64(ln);
+        }
+        let (Some(x), Some(k)) = (self.to_big_rational(), k.to_integer()) else {
+            return NumericValue::NaN;
+        };
+        if k.is_negative() {
+            return NumericValue::zero();
+        }
+        // C(n, k) = C(n, n − k) keeps the product short for non-negative integer n
+        let k = if x.is_integer() && !x.is_negative() {
+            let n = x.numer();
+            if k > *n {
+                return NumericValue::zero();
+            }
+            (n - &k).min(k)
+        } else {
+            k
+        };
+        match k.to_u64().filter(|&k| k <= MAX_EXACT_FACTORIAL) {
+            Some(k) if x.is_integer() => from_integer(binomial_big(x.numer(), k)),
+            Some(k) => NumericValue::from_big_rational(
+                falling_big(&x, k) / BigRational::from_integer(factorial_big(k)),
+            ),
+            None => NumericValue::NaN,
+        }
+    }
+
+    /// (n, k) when C(self, k) is taken from ln Γ: a non-negative integer n with
+    /// more than 10 000 factors in min(k, n − k)
+    pub(crate) fn binomial_beyond_exact(&self, k: &NumericValue) -> Option<(f64, f64)> {
+        let (n, k) = (self.to_integer()?, k.to_integer()?);
+        if n.is_negative() || k.is_negative() || k > n {
+            return None;
+        }
+        let factors = (&n - &k).min(k.clone());
+        if factors <= BigInt::from(MAX_EXACT_FACTORIAL) {
+            return None;
+        }
+        Some((n.to_f64()?, k.to_f64()?))
+    }
+
+    /// Miller-Rabin primality test; false for anything that isn't an integer > 1.
+    ///
+    /// Deterministic below 3.3·10^24. Above that a composite passes with
+    /// probability below 4^-13.
+    pub fn is_probable_prime(&self) -> bool {
+        let Some(n) = self.to_integer() else {
+            return false;
+        };
+        if n < BigInt::from(2) {
+            return false;
+        }
+        for p in WITNESSES {
+            if n == BigInt::from(p) {
+                return true;
+            }
+            if (&n % p).is_zero() {
+                return false;
+            }
+        }
+
+        // n - 1 = d · 2^s with d odd
+        let n_minus_1 = &n - 1u32;
+        let s = n_minus_1.trailing_zeros().unwrap_or(0);
+        let d = &n_minus_1 >> s;
+
+        'witness: for a in WITNESSES {
+            let mut x = BigInt::from(a).modpow(&d, &n);
+            if x.is_one() || x == n_minus_1 {
+                continue;
+            }
+            for _ in 1..s {
+                x = &x * &x % &n;
+                if x == n_minus_1 {
+                    continue 'witness;
+                }
+            }
+            return false;
+        }
+        true
+    }
+}
+
+/// Number-theoretic results are exact functions of the stored values, so they
+/// only carry the approximation their operands already had (Transcendental first)
+fn inherited_apprx(operands: &[&Number]) -> Option<ApproximationType> {
+    if operands.iter().any(|n| n.is_transcendental()) {
+        Some(ApproximationType::Transcendental)
+    } else if operands.iter().any(|n| n.is_rational_approximation()) {
+        Some(ApproximationType::RationalApproximation)
+    } else {
+        None
+    }
+}
+
+impl Number {
+    /// True for finite integer values in any representation (including -0)
+    pub fn is_integer(&self) -> bool {
+        self.value.is_integer()
+    }
+
+    /// Probable-prime test for integers; false for non-integers and values below 2.
+    ///
+    /// Deterministic for n < 3.3·10^24 (Miller-Rabin with the first 13 prime bases).
+    pub fn is_probable_prime(&self) -> bool {
+        self.value.is_probable_prime()
+    }
+
+    /// Greatest common divisor, always non-negative.
+    ///
+    /// Works on any exact rational: `gcd(1/2, 3/4) = 1/4` (gcd of the numerators
+    /// over the lcm of the denominators). NaN for NaN or infinite operands.
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn gcd(self, other: Number) -> Number {
+        let operands = context::Operands::capture(&self, Some(&other));
+        let apprx = inherited_apprx(&[&self, &other]);
+        let value = self.value.gcd(other.value);
+        Number::exact_result("gcd", value, apprx, operands)
+    }
+
+    /// Least common multiple, always non-negative; `lcm(1/2, 3/4) = 3/2` for rationals.
+    /// NaN for NaN or infinite operands.
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn lcm(self, other: Number) -> Number {
+        let operands = context::Operands::capture(&self, Some(&other));
+        let apprx = inherited_apprx(&[&self, &other]);
+        let value = self.value.lcm(other.value);
+        Number::exact_result("lcm", value, apprx, operands)
+    }
+
+    /// Modular exponentiation self^exponent mod modulus, without forming the full power.
+    ///
+    /// The result takes the sign of the modulus, e.g. `mod_pow(-2, 3, 5) = 2`.
+    /// NaN for non-integer operands, a negative exponent or a zero modulus.
+    ///
+    /// An approximate modulus is recorded in place of an exact base or exponent
+    /// (errors and provenance hold two operands; the base and exponent when all
+    /// three are approximate).
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn mod_pow(self, exponent: Number, modulus: Number) -> Number {
+        let operands =
+            if modulus.apprx.is_none() || self.apprx.is_some() && exponent.apprx.is_some() {
+                context::Operands::capture(&self, Some(&exponent))
+            } else if exponent.apprx.is_none() {
+                context::Operands::capture(&self, Some(&modulus))
+            } else {
+                context::Operands::capture(&exponent, Some(&modulus))
+            };
+        let apprx = inherited_apprx(&[&self, &exponent, &modulus]);
+        let value = self.value.mod_pow(exponent.value, modulus.value);
+        Number::exact_result("mod_pow", value, apprx, operands)
+    }
+
+    /// n! for a non-negative integer n, exact (promoting to a `BigDecimal` integer past
+    /// `i64`). Beyond 10 000! it falls back to an approximate Γ(n + 1).
+    ///
+    /// NaN for negative or non-integer values; use `gamma` for those.
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn factorial(self) -> Number {
+        let large = self
+            .value
+            .to_integer()
+            .is_some_and(|n| n > BigInt::from(MAX_EXACT_FACTORIAL));
+        if large {
+            return (self + Number::ONE()).gamma();
+        }
+        let operands = context::Operands::capture(&self, None);
+        let apprx = inherited_apprx(&[&self]);
+        let value = self.value.factorial();
+        Number::exact_result("factorial", value, apprx, operands)
+    }
+
+    /// Falling factorial self (self − 1) ⋯ (self − k + 1), exact for any rational self.
+    ///
+    /// `k` must be an integer in 0..=10 000; NaN otherwise.
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn falling_factorial(self, k: Number) -> Number {
+        let operands = context::Operands::capture(&self, Some(&k));
+        let apprx = inherited_apprx(&[&self, &k]);
+        let value = self.value.falling_factorial(k.value);
+        Number::exact_result("falling_factorial", value, apprx, operands)
+    }
+
+    /// Binomial coefficient C(self, k), exact: `binomial(52, 5) = 2598960`, and
+    /// `binomial(1/2, 2) = -1/8` for the generalized coefficient.
+    ///
+    /// Zero for k < 0 and for k > n when self is a non-negative integer n. Past
+    /// 10 000 factors an integer n falls back to an approximate
+    /// Γ(n + 1) / (Γ(k + 1) Γ(n − k + 1)), like `factorial`. NaN for a non-integer k,
+    /// or a non-integer self that would need more than 10 000 factors.
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn binomial(self, k: Number) -> Number {
+        let operands = context::Operands::capture(&self, Some(&k));
+        let apprx = inherited_apprx(&[&self, &k]);
+        let Some((n, k_f64)) = self.value.binomial_beyond_exact(&k.value) else {
+            let value = self.value.binomial(k.value);
+            return Number::exact_result("binomial", value, apprx, operands);
+        };
+        let exact_inputs = apprx.is_none();
+        let value = self.value.binomial(k.value);
+        let apprx = Some(ApproximationType::Transcendental);
+        let err_bound = exact_inputs
+            .then(|| error_bound::binomial(n, k_f64, &value))
+            .flatten();
+        let result = Number {
+            value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("binomial", result, operands)
+    }
+
+    /// Integer square root floor(√n) of a non-negative integer; NaN otherwise
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn isqrt(self) -> Number {
+        let operands = context::Operands::capture(&self, None);
+        let apprx = inherited_apprx(&[&self]);
+        let value = self.value.isqrt();
+        Number::exact_result("isqrt", value, apprx, operands)
+    }
+
+    #[cfg_attr(feature = "provenance", track_caller)]
+    fn exact_result(
+        op: &'static str,
+        value: NumericValue,
+        apprx: Option<ApproximationType>,
+        operands: context::Operands,
+    ) -> Number {
+        let result = Number {
+            value,
+            apprx,
+            err_bound: None,
+            provenance: Trail::NONE,
+        };
+        context::audit(op, result, operands)
+    }
+}
@@ -1,5 +1,13 @@
-use crate::{Number, NumericValue, forward_ref_binop};
+use crate::context;
+use crate::error_bound;
+use crate::provenance::Trail;
+use crate::representation::{
+    big_rational_to_bigdecimal, bigdecimal_to_big_rational, decimal_to_big_rational,
+    rational_to_big_rational,
+};
+use crate::{Number, NumericValue};
 use bigdecimal::BigDecimal;
+use num_rational::{BigRational, Ratio, Rational64};
 use num_traits::{CheckedAdd, CheckedDiv, CheckedMul, CheckedSub, Signed, Zero};
 use rust_decimal::Decimal;
 use std::ops::{Add, Div, Mul, Neg, Rem, Sub};
@@ -13,6 +21,37 @@ pub(crate) fn decimal_to_bigdecimal(d: Decimal) -> BigDecimal {
     BigDecimal::new(mantissa.into(), scale)
 }
 
+/// Exact BigRational view of a finite operand.
+/// Callers must have already excluded NaN and the infinities.
+#[inline]
+fn finite_to_big_rational(v: &NumericValue) -> BigRational {
+    v.to_big_rational()
+        .expect("finite values always convert exactly to BigRational")
+}
+
+/// Exact BigDecimal for a terminating Rational64
+#[inline]
+fn terminating_to_bigdecimal(r: &Rational64) -> BigDecimal {
+    big_rational_to_bigdecimal(&rational_to_big_rational(r))
+}
+
+/// Exact Rational64 op computed in i128, reduced by gcd, then narrowed back to i64.
+///
+/// A single add/sub/mul/div of two Rational64 values can't overflow i128, so this
+/// only returns None when the reduced result itself doesn't fit in Rational64.
+#[inline]
+fn rational_op_via_i128(
+    a: &Rational64,
+    b: &Rational64,
+    op: fn(Ratio<i128>, Ratio<i128>) -> Ratio<i128>,
+) -> Option<Rational64> {
+    let widen = |r: &Rational64| Ratio::new_raw(i128::from(*r.numer()), i128::from(*r.denom()));
+    let result = op(widen(a), widen(b));
+    let numer = i64::try_from(*result.numer()).ok()?;
+    let denom = i64::try_from(*result.denom()).ok()?;
+    Some(Ratio::new_raw(numer, denom))
+}
+
 /// Check if BigDecimal division truncated by multiplying back and comparing.
 /// Returns true if the division was truncated (i.e., result * divisor != dividend)
 #[inline]
@@ -44,22 +83,19 @@ impl Add for NumericValue {
                     }
                 }
 
-                // Try rational addition
-                if let Some(result) = a.checked_add(&b) {
+                // Try rational addition, falling back to i128 when only the intermediates overflow
+                if let Some(result) = a
+                    .checked_add(&b)
+                    .or_else(|| rational_op_via_i128(&a, &b, |x, y| x + y))
+                {
                     let is_term = a_term && b_term; // Cached!
                     (NumericValue::Rational(result, is_term), false)
                 } else {
                     // Use cached terminating flags - no recomputation needed!
                     if !a_term || !b_term {
-                        // Non-terminating: promote directly to BigDecimal
-                        use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                        let a_numer_bd = BigDecimal::from(BigInt::from(*a.numer()));
-                        let a_denom_bd = BigDecimal::from(BigInt::from(*a.denom()));
-                        let a_bd = a_numer_bd / a_denom_bd;
-                        let b_numer_bd = BigDecimal::from(BigInt::from(*b.numer()));
-                        let b_denom_bd = BigDecimal::from(BigInt::from(*b.denom()));
-                        let b_bd = b_numer_bd / b_denom_bd;
-                        (NumericValue::BigDecimal(a_bd + b_bd), true) // Non-terminating overflow
+                        // Non-terminating: promote to BigRational so the result stays exact
+                        let result = rational_to_big_rational(&a) + rational_to_big_rational(&b);
+                        (NumericValue::from_big_rational(result), false)
                     } else {
                         // Terminating: try Decimal first
                         let a_dec = Decimal::from(*a.numer()) / Decimal::from(*a.denom());
@@ -81,13 +117,9 @@ impl Add for NumericValue {
             | (NumericValue::Decimal(b), NumericValue::Rational(a, a_term)) => {
                 // Use cached terminating flag - no recomputation needed!
                 if !a_term {
-                    // Non-terminating: promote directly to BigDecimal
-                    use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                    let numer_bd = BigDecimal::from(BigInt::from(*a.numer()));
-                    let denom_bd = BigDecimal::from(BigInt::from(*a.denom()));
-                    let a_bd = numer_bd / denom_bd;
-                    let b_bd = decimal_to_bigdecimal(b);
-                    (NumericValue::BigDecimal(a_bd + b_bd), true) // Non-terminating overflow
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = rational_to_big_rational(&a) + decimal_to_big_rational(b);
+                    (NumericValue::from_big_rational(result), false)
                 } else {
                     // Terminating: try Decimal first
                     let a_dec = Decimal::from(*a.numer()) / Decimal::from(*a.denom());
@@ -104,13 +136,18 @@ impl Add for NumericValue {
             }
 
             // Rational + BigDecimal: graduate Rational to BigDecimal
-            (NumericValue::Rational(a, _), NumericValue::BigDecimal(b))
-            | (NumericValue::BigDecimal(b), NumericValue::Rational(a, _)) => {
-                use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                let numer_bd = BigDecimal::from(BigInt::from(*a.numer()));
-                let denom_bd = BigDecimal::from(BigInt::from(*a.denom()));
-                let a_bd = numer_bd / denom_bd;
-                (NumericValue::BigDecimal(a_bd + b), false)
+            (NumericValue::Rational(a, a_term), NumericValue::BigDecimal(b))
+            | (NumericValue::BigDecimal(b), NumericValue::Rational(a, a_term)) => {
+                if !a_term {
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = rational_to_big_rational(&a) + bigdecimal_to_big_rational(&b);
+                    (NumericValue::from_big_rational(result), false)
+                } else {
+                    (
+                        NumericValue::BigDecimal(terminating_to_bigdecimal(&a) + b),
+                        false,
+                    )
+                }
             }
 
             // Decimal + Decimal
@@ -150,6 +187,16 @@ impl Add for NumericValue {
                 (NumericValue::BigDecimal(a + b_bd), false)
             }
 
+            // BigRational + finite: exact arithmetic in the BigRational tier
+            (NumericValue::BigRational(a), b) | (b, NumericValue::BigRational(a))
+                if b.is_finite() =>
+            {
+                (
+                    NumericValue::from_big_rational(a + finite_to_big_rational(&b)),
+                    false,
+                )
+            }
+
             // NaN and Infinity handling
             (NumericValue::NaN, _) | (_, NumericValue::NaN) => (NumericValue::NaN, false),
             (NumericValue::PositiveInfinity, NumericValue::NegativeInfinity)
@@ -162,6 +209,10 @@ impl Add for NumericValue {
             (NumericValue::NegativeInfinity, _) | (_, NumericValue::NegativeInfinity) => {
                 (NumericValue::NegativeInfinity, false)
             }
+            // BigRational with a finite operand is handled above; NaN/∞ by the special-value arms
+            (NumericValue::BigRational(_), _) | (_, NumericValue::BigRational(_)) => {
+                unreachable!("BigRational operand pairs are covered above")
+            }
         }
     }
 }
@@ -172,21 +223,18 @@ impl Sub for NumericValue {
         match (self, rhs) {
             // Rational - Rational: stays Rational, or graduates to Decimal if denominator overflows
             (NumericValue::Rational(a, a_term), NumericValue::Rational(b, b_term)) => {
-                if let Some(result) = a.checked_sub(&b) {
+                if let Some(result) = a
+                    .checked_sub(&b)
+                    .or_else(|| rational_op_via_i128(&a, &b, |x, y| x - y))
+                {
                     let is_term = a_term && b_term; // Cached!
                     (NumericValue::Rational(result, is_term), false)
                 } else {
                     // Use cached terminating flags - no recomputation needed!
                     if !a_term || !b_term {
-                        // Non-terminating: promote directly to BigDecimal
-                        use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                        let a_numer_bd = BigDecimal::from(BigInt::from(*a.numer()));
-                        let a_denom_bd = BigDecimal::from(BigInt::from(*a.denom()));
-                        let a_bd = a_numer_bd / a_denom_bd;
-                        let b_numer_bd = BigDecimal::from(BigInt::from(*b.numer()));
-                        let b_denom_bd = BigDecimal::from(BigInt::from(*b.denom()));
-                        let b_bd = b_numer_bd / b_denom_bd;
-                        (NumericValue::BigDecimal(a_bd - b_bd), true) // Non-terminating overflow
+                        // Non-terminating: promote to BigRational so the result stays exact
+                        let result = rational_to_big_rational(&a) - rational_to_big_rational(&b);
+                        (NumericValue::from_big_rational(result), false)
                     } else {
                         // Terminating: try Decimal first
                         let a_dec = Decimal::from(*a.numer()) / Decimal::from(*a.denom());
@@ -207,13 +255,9 @@ impl Sub for NumericValue {
             (NumericValue::Rational(a, a_term), NumericValue::Decimal(b)) => {
                 // Use cached terminating flag - no recomputation needed!
                 if !a_term {
-                    // Non-terminating: promote directly to BigDecimal
-                    use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                    let numer_bd = BigDecimal::from(BigInt::from(*a.numer()));
-                    let denom_bd = BigDecimal::from(BigInt::from(*a.denom()));
-                    let a_bd = numer_bd / denom_bd;
-                    let b_bd = decimal_to_bigdecimal(b);
-                    (NumericValue::BigDecimal(a_bd - b_bd), true) // Non-terminating overflow
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = rational_to_big_rational(&a) - decimal_to_big_rational(b);
+                    (NumericValue::from_big_rational(result), false)
                 } else {
                     // Terminating: try Decimal first
                     let a_dec = Decimal::from(*a.numer()) / Decimal::from(*a.denom());
@@ -232,13 +276,9 @@ impl Sub for NumericValue {
             (NumericValue::Decimal(a), NumericValue::Rational(b, b_term)) => {
                 // Use cached terminating flag - no recomputation needed!
                 if !b_term {
-                    // Non-terminating: promote directly to BigDecimal
-                    use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                    let numer_bd = BigDecimal::from(BigInt::from(*b.numer()));
-                    let denom_bd = BigDecimal::from(BigInt::from(*b.denom()));
-                    let b_bd = numer_bd / denom_bd;
-                    let a_bd = decimal_to_bigdecimal(a);
-                    (NumericValue::BigDecimal(a_bd - b_bd), true) // Non-terminating overflow
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = decimal_to_big_rational(a) - rational_to_big_rational(&b);
+                    (NumericValue::from_big_rational(result), false)
                 } else {
                     // Terminating: try Decimal first
                     let b_dec = Decimal::from(*b.numer()) / Decimal::from(*b.denom());
@@ -255,19 +295,29 @@ impl Sub for NumericValue {
             }
 
             // Rational - BigDecimal: graduate Rational to BigDecimal
-            (NumericValue::Rational(a, _), NumericValue::BigDecimal(b)) => {
-                use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                let numer_bd = BigDecimal::from(BigInt::from(*a.numer()));
-                let denom_bd = BigDecimal::from(BigInt::from(*a.denom()));
-                let a_bd = numer_bd / denom_bd;
-                (NumericValue::BigDecimal(a_bd - b), false)
+            (NumericValue::Rational(a, a_term), NumericValue::BigDecimal(b)) => {
+                if !a_term {
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = rational_to_big_rational(&a) - bigdecimal_to_big_rational(&b);
+                    (NumericValue::from_big_rational(result), false)
+                } else {
+                    (
+                        NumericValue::BigDecimal(terminating_to_bigdecimal(&a) - b),
+                        false,
+                    )
+                }
             }
-            (NumericValue::BigDecimal(a), NumericValue::Rational(b, _)) => {
-                use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                let numer_bd = BigDecimal::from(BigInt::from(*b.numer()));
-                let denom_bd = BigDecimal::from(BigInt::from(*b.denom()));
-                let b_bd = numer_bd / denom_bd;
-                (NumericValue::BigDecimal(a - b_bd), false)
+            (NumericValue::BigDecimal(a), NumericValue::Rational(b, b_term)) => {
+                if !b_term {
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = bigdecimal_to_big_rational(&a) - rational_to_big_rational(&b);
+                    (NumericValue::from_big_rational(result), false)
+                } else {
+                    (
+                        NumericValue::BigDecimal(a - terminating_to_bigdecimal(&b)),
+                        false,
+                    )
+                }
             }
 
             // Decimal - Decimal
@@ -320,6 +370,16 @@ impl Sub for NumericValue {
                 (NumericValue::BigDecimal(a_bd - b), false)
             }
 
+            // BigRational - finite / finite - BigRational: exact arithmetic in the BigRational tier
+            (NumericValue::BigRational(a), b) if b.is_finite() => (
+                NumericValue::from_big_rational(a - finite_to_big_rational(&b)),
+                false,
+            ),
+            (a, NumericValue::BigRational(b)) if a.is_finite() => (
+                NumericValue::from_big_rational(finite_to_big_rational(&a) - b),
+                false,
+            ),
+
             (NumericValue::NaN, _) | (_, NumericValue::NaN) => (NumericValue::NaN, false),
             (NumericValue::PositiveInfinity, NumericValue::PositiveInfinity)
             | (NumericValue::NegativeInfinity, NumericValue::NegativeInfinity) => {
@@ -329,6 +389,10 @@ impl Sub for NumericValue {
             (NumericValue::NegativeInfinity, _) => (NumericValue::NegativeInfinity, false),
             (_, NumericValue::PositiveInfinity) => (NumericValue::NegativeInfinity, false),
             (_, NumericValue::NegativeInfinity) => (NumericValue::PositiveInfinity, false),
+            // BigRational with a finite operand is handled above; NaN/∞ by the special-value arms
+            (NumericValue::BigRational(_), _) | (_, NumericValue::BigRational(_)) => {
+                unreachable!("BigRational operand pairs are covered above")
+            }
         }
     }
 }
@@ -353,19 +417,18 @@ impl Mul for NumericValue {
                     }
                 }
 
-                if let Some(result) = a.checked_mul(&b) {
+                if let Some(result) = a
+                    .checked_mul(&b)
+                    .or_else(|| rational_op_via_i128(&a, &b, |x, y| x * y))
+                {
                     let is_term = a_term && b_term; // Cached!
                     (NumericValue::Rational(result, is_term), false)
                 } else {
                     // Use cached terminating flags - no recomputation needed!
                     if !a_term || !b_term {
-                        // Non-terminating: use BigDecimal to preserve precision for recovery
-                        use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                        let a_bd = BigDecimal::from(BigInt::from(*a.numer()))
-                            / BigDecimal::from(BigInt::from(*a.denom()));
-                        let b_bd = BigDecimal::from(BigInt::from(*b.numer()))
-                            / BigDecimal::from(BigInt::from(*b.denom()));
-                        (NumericValue::BigDecimal(a_bd * b_bd), true) // Non-terminating overflow
+                        // Non-terminating: promote to BigRational so the result stays exact
+                        let result = rational_to_big_rational(&a) * rational_to_big_rational(&b);
+                        (NumericValue::from_big_rational(result), false)
                     } else {
                         // Terminating: try Decimal first (faster), then BigDecimal if needed
                         let a_dec = Decimal::from(*a.numer()) / Decimal::from(*a.denom());
@@ -388,13 +451,9 @@ impl Mul for NumericValue {
             | (NumericValue::Decimal(b), NumericValue::Rational(a, a_term)) => {
                 // Use cached terminating flag - no recomputation needed!
                 if !a_term {
-                    // Non-terminating: promote directly to BigDecimal
-                    use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                    let numer_bd = BigDecimal::from(BigInt::from(*a.numer()));
-                    let denom_bd = BigDecimal::from(BigInt::from(*a.denom()));
-                    let a_bd = numer_bd / denom_bd;
-                    let b_bd = decimal_to_bigdecimal(b);
-                    (NumericValue::BigDecimal(a_bd * b_bd), true) // Non-terminating overflow
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = rational_to_big_rational(&a) * decimal_to_big_rational(b);
+                    (NumericValue::from_big_rational(result), false)
                 } else {
                     // Terminating: try Decimal first
                     let a_dec = Decimal::from(*a.numer()) / Decimal::from(*a.denom());
@@ -411,13 +470,18 @@ impl Mul for NumericValue {
             }
 
             // Rational * BigDecimal: graduate Rational to BigDecimal
-            (NumericValue::Rational(a, _), NumericValue::BigDecimal(b))
-            | (NumericValue::BigDecimal(b), NumericValue::Rational(a, _)) => {
-                use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                let numer_bd = BigDecimal::from(BigInt::from(*a.numer()));
-                let denom_bd = BigDecimal::from(BigInt::from(*a.denom()));
-                let a_bd = numer_bd / denom_bd;
-                (NumericValue::BigDecimal(a_bd * b), false)
+            (NumericValue::Rational(a, a_term), NumericValue::BigDecimal(b))
+            | (NumericValue::BigDecimal(b), NumericValue::Rational(a, a_term)) => {
+                if !a_term {
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = rational_to_big_rational(&a) * bigdecimal_to_big_rational(&b);
+                    (NumericValue::from_big_rational(result), false)
+                } else {
+                    (
+                        NumericValue::BigDecimal(terminating_to_bigdecimal(&a) * b),
+                        false,
+                    )
+                }
             }
 
             // Decimal * Decimal
@@ -473,6 +537,25 @@ impl Mul for NumericValue {
                 (NumericValue::zero(), false)
             } // (-0) * (-0) = +0
 
+            // BigRational is never zero, so only its sign matters against -0
+            (NumericValue::BigRational(a), NumericValue::NegativeZero)
+            | (NumericValue::NegativeZero, NumericValue::BigRational(a)) => {
+                if a.is_positive() {
+                    (NumericValue::NegativeZero, false)
+                } else {
+                    (NumericValue::zero(), false)
+                }
+            }
+            // BigRational * finite: exact arithmetic in the BigRational tier
+            (NumericValue::BigRational(a), b) | (b, NumericValue::BigRational(a))
+                if b.is_finite() =>
+            {
+                (
+                    NumericValue::from_big_rational(a * finite_to_big_rational(&b)),
+                    false,
+                )
+            }
+
             (NumericValue::NaN, _) | (_, NumericValue::NaN) => (NumericValue::NaN, false),
 
             // 0 * ∞ = NaN in JavaScript (Rational case)
@@ -570,6 +653,27 @@ impl Mul for NumericValue {
                     (NumericValue::PositiveInfinity, false)
                 }
             }
+            // Infinity * finite BigRational (never zero)
+            (NumericValue::PositiveInfinity, NumericValue::BigRational(b))
+            | (NumericValue::BigRational(b), NumericValue::PositiveInfinity) => {
+                if b.is_positive() {
+                    (NumericValue::PositiveInfinity, false)
+                } else {
+                    (NumericValue::NegativeInfinity, false)
+                }
+            }
+            (NumericValue::NegativeInfinity, NumericValue::BigRational(b))
+            | (NumericValue::BigRational(b), NumericValue::NegativeInfinity) => {
+                if b.is_positive() {
+                    (NumericValue::NegativeInfinity, false)
+                } else {
+                    (NumericValue::PositiveInfinity, false)
+                }
+            }
+            // BigRational with a finite operand is handled above; NaN/∞ by the special-value arms
+            (NumericValue::BigRational(_), _) | (_, NumericValue::BigRational(_)) => {
+                unreachable!("BigRational operand pairs are covered above")
+            }
         }
     }
 }
@@ -580,8 +684,8 @@ impl Div for NumericValue {
         use num_rational::Ratio;
 
         match (self, rhs) {
-            // Rational / Rational: stays Rational (invert and multiply), or graduates to Decimal if overflow
-            (NumericValue::Rational(a, a_term), NumericValue::Rational(b, b_term)) => {
+            // Rational / Rational: stays Rational (invert and multiply), or graduates exactly on overflow
+            (NumericValue::Rational(a, _), NumericValue::Rational(b, _)) => {
                 if b.is_zero() {
                     if a.is_zero() {
                         (NumericValue::NaN, false) // 0/0 = NaN
@@ -590,7 +694,10 @@ impl Div for NumericValue {
                     } else {
                         (NumericValue::NegativeInfinity, false) // negative/0 = -∞
                     }
-                } else if let Some(result) = a.checked_div(&b) {
+                } else if let Some(result) = a
+                    .checked_div(&b)
+                    .or_else(|| rational_op_via_i128(&a, &b, |x, y| x / y))
+                {
                     // Must recompute terminating flag based on RESULT denominator
                     // Dividing terminating rationals can produce non-terminating results
                     // e.g., 1/1 / 3/1 = 1/3 (non-terminating)
@@ -598,29 +705,11 @@ impl Div for NumericValue {
                     let is_term = is_terminating_decimal(*result.numer(), *result.denom());
                     (NumericValue::Rational(result, is_term), false)
                 } else {
-                    // Use cached terminating flags - no recomputation needed!
-                    if !a_term || !b_term {
-                        // Non-terminating: use BigDecimal to preserve precision for recovery
-                        use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                        let a_bd = BigDecimal::from(BigInt::from(*a.numer()))
-                            / BigDecimal::from(BigInt::from(*a.denom()));
-                        let b_bd = BigDecimal::from(BigInt::from(*b.numer()))
-                            / BigDecimal::from(BigInt::from(*b.denom()));
-                        (NumericValue::BigDecimal(a_bd / b_bd), true)
-                    } else {
-                        // Terminating: try Decimal first (faster), then BigDecimal if needed
-                        let a_dec = Decimal::from(*a.numer()) / Decimal::from(*a.denom());
-                        let b_dec = Decimal::from(*b.numer()) / Decimal::from(*b.denom());
-                        match a_dec.checked_div(b_dec) {
-                            Some(result) => (NumericValue::Decimal(result), false),
-                            None => {
-                                // Graduate to BigDecimal - use fast conversion
-                                let a_bd = decimal_to_bigdecimal(a_dec);
-                                let b_bd = decimal_to_bigdecimal(b_dec);
-                                (NumericValue::BigDecimal(a_bd / b_bd), false)
-                            }
-                        }
-                    }
+                    // The quotient of two terminating rationals need not terminate
+                    // (i64::MAX / 0.3), so the cached flags can't pick the tier: build the
+                    // exact quotient and let from_big_rational pick BigDecimal or BigRational
+                    let result = rational_to_big_rational(&a) / rational_to_big_rational(&b);
+                    (NumericValue::from_big_rational(result), false)
                 }
             }
 
@@ -637,13 +726,9 @@ impl Div for NumericValue {
                 } else {
                     // Use cached terminating flag - no recomputation needed!
                     if !a_term {
-                        // Non-terminating: promote directly to BigDecimal
-                        use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                        let numer_bd = BigDecimal::from(BigInt::from(*a.numer()));
-                        let denom_bd = BigDecimal::from(BigInt::from(*a.denom()));
-                        let a_bd = numer_bd / denom_bd;
-                        let b_bd = decimal_to_bigdecimal(b);
-                        (NumericValue::BigDecimal(a_bd / b_bd), true) // Non-terminating overflow
+                        // Non-terminating: promote to BigRational so the result stays exact
+                        let result = rational_to_big_rational(&a) / decimal_to_big_rational(b);
+                        (NumericValue::from_big_rational(result), false)
                     } else {
                         // Terminating: try Decimal first
                         let a_dec = Decimal::from(*a.numer()) / Decimal::from(*a.denom());
@@ -672,13 +757,9 @@ impl Div for NumericValue {
                 } else {
                     // Use cached terminating flag - no recomputation needed!
                     if !b_term {
-                        // Non-terminating: promote directly to BigDecimal
-                        use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                        let numer_bd = BigDecimal::from(BigInt::from(*b.numer()));
-                        let denom_bd = BigDecimal::from(BigInt::from(*b.denom()));
-                        let b_bd = numer_bd / denom_bd;
-                        let a_bd = decimal_to_bigdecimal(a);
-                        (NumericValue::BigDecimal(a_bd / b_bd), true) // Non-terminating overflow
+                        // Non-terminating: promote to BigRational so the result stays exact
+                        let result = decimal_to_big_rational(a) / rational_to_big_rational(&b);
+                        (NumericValue::from_big_rational(result), false)
                     } else {
                         // Terminating: try Decimal first
                         let b_dec = Decimal::from(*b.numer()) / Decimal::from(*b.denom());
@@ -696,7 +777,7 @@ impl Div for NumericValue {
             }
 
             // Rational / BigDecimal: graduate Rational to BigDecimal
-            (NumericValue::Rational(a, _), NumericValue::BigDecimal(b)) => {
+            (NumericValue::Rational(a, a_term), NumericValue::BigDecimal(b)) => {
                 if b.is_zero() {
                     if a.is_zero() {
                         (NumericValue::NaN, false)
@@ -705,18 +786,19 @@ impl Div for NumericValue {
                     } else {
                         (NumericValue::NegativeInfinity, false)
                     }
+                } else if !a_term {
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = rational_to_big_rational(&a) / bigdecimal_to_big_rational(&b);
+                    (NumericValue::from_big_rational(result), false)
                 } else {
-                    use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                    let numer_bd = BigDecimal::from(BigInt::from(*a.numer()));
-                    let denom_bd = BigDecimal::from(BigInt::from(*a.denom()));
-                    let a_bd = numer_bd / denom_bd;
+                    let a_bd = terminating_to_bigdecimal(&a);
                     let result = &a_bd / &b;
                     let truncated = bigdecimal_division_truncated(&a_bd, &b, &result);
                     (NumericValue::BigDecimal(result), truncated)
                 }
             }
             // BigDecimal / Rational: graduate Rational to BigDecimal
-            (NumericValue::BigDecimal(a), NumericValue::Rational(b, _)) => {
+            (NumericValue::BigDecimal(a), NumericValue::Rational(b, b_term)) => {
                 if b.is_zero() {
                     if a.is_zero() {
                         (NumericValue::NaN, false)
@@ -725,11 +807,12 @@ impl Div for NumericValue {
                     } else {
                         (NumericValue::NegativeInfinity, false)
                     }
+                } else if !b_term {
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = bigdecimal_to_big_rational(&a) / rational_to_big_rational(&b);
+                    (NumericValue::from_big_rational(result), false)
                 } else {
-                    use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                    let numer_bd = BigDecimal::from(BigInt::from(*b.numer()));
-                    let denom_bd = BigDecimal::from(BigInt::from(*b.denom()));
-                    let b_bd = numer_bd / denom_bd;
+                    let b_bd = terminating_to_bigdecimal(&b);
                     let result = &a / &b_bd;
                     let truncated = bigdecimal_division_truncated(&a, &b_bd, &result);
                     (NumericValue::BigDecimal(result), truncated)
@@ -908,6 +991,39 @@ impl Div for NumericValue {
                 }
             }
             (NumericValue::NegativeZero, NumericValue::NegativeZero) => (NumericValue::NaN, false), // (-0)/(-0) = NaN
+
+            // Special cases with NegativeZero and BigRational (BigRational is never zero)
+            (NumericValue::BigRational(a), NumericValue::NegativeZero) => {
+                if a.is_positive() {
+                    (NumericValue::NegativeInfinity, false) // positive/(-0) = -∞
+                } else {
+                    (NumericValue::PositiveInfinity, false) // negative/(-0) = +∞
+                }
+            }
+            (NumericValue::NegativeZero, NumericValue::BigRational(b)) => {
+                if b.is_positive() {
+                    (NumericValue::NegativeZero, false) // (-0)/positive = -0
+                } else {
+                    (NumericValue::zero(), false) // (-0)/negative = +0
+                }
+            }
+            // BigRational / finite and finite / BigRational: exact in the BigRational tier
+            (NumericValue::BigRational(a), b) if b.is_finite() => {
+                let b = finite_to_big_rational(&b);
+                if b.is_zero() {
+                    if a.is_positive() {
+                        (NumericValue::PositiveInfinity, false)
+                    } else {
+                        (NumericValue::NegativeInfinity, false)
+                    }
+                } else {
+                    (NumericValue::from_big_rational(a / b), false)
+                }
+            }
+            (a, NumericValue::BigRational(b)) if a.is_finite() => (
+                NumericValue::from_big_rational(finite_to_big_rational(&a) / b),
+                false,
+            ),
             (NumericValue::NaN, _) | (_, NumericValue::NaN) => (NumericValue::NaN, false),
             // ∞ / ∞ = NaN, 0 / ∞ = 0
             (NumericValue::PositiveInfinity, NumericValue::PositiveInfinity)
@@ -930,11 +1046,30 @@ impl Div for NumericValue {
                 use bigdecimal::BigDecimal;
                 (NumericValue::BigDecimal(BigDecimal::from(0)), false)
             }
+            (NumericValue::BigRational(_), NumericValue::PositiveInfinity)
+            | (NumericValue::BigRational(_), NumericValue::NegativeInfinity) => {
+                (NumericValue::zero(), false)
+            }
             (NumericValue::NegativeZero, NumericValue::PositiveInfinity)
             | (NumericValue::NegativeZero, NumericValue::NegativeInfinity) => {
                 (NumericValue::NegativeZero, false)
             }
 
+            // ∞ / finite BigRational (never zero)
+            (NumericValue::PositiveInfinity, NumericValue::BigRational(b)) => {
+                if b.is_positive() {
+                    (NumericValue::PositiveInfinity, false)
+                } else {
+                    (NumericValue::NegativeInfinity, false)
+                }
+            }
+            (NumericValue::NegativeInfinity, NumericValue::BigRational(b)) => {
+                if b.is_positive() {
+                    (NumericValue::NegativeInfinity, false)
+                } else {
+                    (NumericValue::PositiveInfinity, false)
+                }
+            }
             // ∞ / finite Rational
             (NumericValue::PositiveInfinity, NumericValue::Rational(b, _)) => {
                 if *b.numer() > 0 {
@@ -987,6 +1122,10 @@ impl Div for NumericValue {
             (NumericValue::NegativeInfinity, NumericValue::NegativeZero) => {
                 (NumericValue::PositiveInfinity, false)
             }
+            // BigRational with a finite operand is handled above; NaN/∞ by the special-value arms
+            (NumericValue::BigRational(_), _) | (_, NumericValue::BigRational(_)) => {
+                unreachable!("BigRational operand pairs are covered above")
+            }
         }
     }
 }
@@ -1022,53 +1161,62 @@ impl Rem for NumericValue {
                         let is_term = a_term && b_term;
                         (NumericValue::Rational(result, is_term), false)
                     } else {
-                        // Overflow - promote to BigDecimal
-                        use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                        let a_bd = BigDecimal::from(BigInt::from(a_numer))
-                            / BigDecimal::from(BigInt::from(a_denom));
-                        let b_bd = BigDecimal::from(BigInt::from(b_numer))
-                            / BigDecimal::from(BigInt::from(b_denom));
-                        // Flag as approximation since we lost exact rational representation
-                        (NumericValue::BigDecimal(a_bd % b_bd), true)
+                        // Overflow - promote to BigRational (exact)
+                        let result = rational_to_big_rational(&a) % rational_to_big_rational(&b);
+                        (NumericValue::from_big_rational(result), false)
                     }
                 }
             }
-            (NumericValue::Rational(a, _), NumericValue::Decimal(b)) => {
+            (NumericValue::Rational(a, a_term), NumericValue::Decimal(b)) => {
                 if b.is_zero() {
                     (NumericValue::NaN, false)
+                } else if !a_term {
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = rational_to_big_rational(&a) % decimal_to_big_rational(b);
+                    (NumericValue::from_big_rational(result), false)
                 } else {
                     let a_dec = Decimal::from(*a.numer()) / Decimal::from(*a.denom());
                     (NumericValue::Decimal(a_dec % b), false)
                 }
             }
-            (NumericValue::Decimal(a), NumericValue::Rational(b, _)) => {
+            (NumericValue::Decimal(a), NumericValue::Rational(b, b_term)) => {
                 if b.is_zero() {
                     (NumericValue::NaN, false)
+                } else if !b_term {
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = decimal_to_big_rational(a) % rational_to_big_rational(&b);
+                    (NumericValue::from_big_rational(result), false)
                 } else {
                     let b_dec = Decimal::from(*b.numer()) / Decimal::from(*b.denom());
                     (NumericValue::Decimal(a % b_dec), false)
                 }
             }
-            (NumericValue::Rational(a, _), NumericValue::BigDecimal(b)) => {
+            (NumericValue::Rational(a, a_term), NumericValue::BigDecimal(b)) => {
                 if b.is_zero() {
                     (NumericValue::NaN, false)
+                } else if !a_term {
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = rational_to_big_rational(&a) % bigdecimal_to_big_rational(&b);
+                    (NumericValue::from_big_rational(result), false)
                 } else {
-                    use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                    let numer_bd = BigDecimal::from(BigInt::from(*a.numer()));
-                    let denom_bd = BigDecimal::from(BigInt::from(*a.denom()));
-                    let a_bd = numer_bd / denom_bd;
-                    (NumericValue::BigDecimal(a_bd % b), false)
+                    (
+                        NumericValue::BigDecimal(terminating_to_bigdecimal(&a) % b),
+                        false,
+                    )
                 }
             }
-            (NumericValue::BigDecimal(a), NumericValue::Rational(b, _)) => {
+            (NumericValue::BigDecimal(a), NumericValue::Rational(b, b_term)) => {
                 if b.is_zero() {
                     (NumericValue::NaN, false)
+                } else if !b_term {
+                    // Non-terminating: promote to BigRational so the result stays exact
+                    let result = bigdecimal_to_big_rational(&a) % rational_to_big_rational(&b);
+                    (NumericValue::from_big_rational(result), false)
                 } else {
-                    use bigdecimal::{BigDecimal, num_bigint::BigInt};
-                    let numer_bd = BigDecimal::from(BigInt::from(*b.numer()));
-                    let denom_bd = BigDecimal::from(BigInt::from(*b.denom()));
-                    let b_bd = numer_bd / denom_bd;
-                    (NumericValue::BigDecimal(a % b_bd), false)
+                    (
+                        NumericValue::BigDecimal(a % terminating_to_bigdecimal(&b)),
+                        false,
+                    )
                 }
             }
             (NumericValue::Rational(_a, _), NumericValue::NegativeZero) => {
@@ -1137,6 +1285,26 @@ impl Rem for NumericValue {
                 (NumericValue::NaN, false) // (-0) % (-0) = NaN
             }
 
+            // BigRational % operations: exact in the BigRational tier
+            (NumericValue::BigRational(_), NumericValue::NegativeZero) => {
+                (NumericValue::NaN, false)
+            }
+            (NumericValue::NegativeZero, NumericValue::BigRational(_)) => {
+                (NumericValue::NegativeZero, false)
+            }
+            (NumericValue::BigRational(a), b) if b.is_finite() => {
+                let b = finite_to_big_rational(&b);
+                if b.is_zero() {
+                    (NumericValue::NaN, false)
+                } else {
+                    (NumericValue::from_big_rational(a % b), false)
+                }
+            }
+            (a, NumericValue::BigRational(b)) if a.is_finite() => (
+                NumericValue::from_big_rational(finite_to_big_rational(&a) % b),
+                false,
+            ),
+
             (NumericValue::NaN, _) | (_, NumericValue::NaN) => (NumericValue::NaN, false),
 
             // ∞ % anything = NaN, anything % ∞ = the anything
@@ -1155,10 +1323,18 @@ impl Rem for NumericValue {
             | (NumericValue::BigDecimal(a), NumericValue::NegativeInfinity) => {
                 (NumericValue::BigDecimal(a), false)
             }
+            (NumericValue::BigRational(a), NumericValue::PositiveInfinity)
+            | (NumericValue::BigRational(a), NumericValue::NegativeInfinity) => {
+                (NumericValue::BigRational(a), false)
+            }
             (NumericValue::NegativeZero, NumericValue::PositiveInfinity)
             | (NumericValue::NegativeZero, NumericValue::NegativeInfinity) => {
                 (NumericValue::NegativeZero, false)
             }
+            // BigRational with a finite operand is handled above; NaN/∞ by the special-value arms
+            (NumericValue::BigRational(_), _) | (_, NumericValue::BigRational(_)) => {
+                unreachable!("BigRational operand pairs are covered above")
+            }
         }
     }
 }
@@ -1179,6 +1355,7 @@ impl Neg for NumericValue {
             NumericValue::PositiveInfinity => NumericValue::NegativeInfinity,
             NumericValue::NegativeInfinity => NumericValue::PositiveInfinity,
             NumericValue::Rational(r, r_term) => NumericValue::Rational(-r, r_term),
+            NumericValue::BigRational(r) => NumericValue::BigRational(-r),
             NumericValue::BigDecimal(bd) => NumericValue::BigDecimal(-bd),
         }
     }
@@ -1224,12 +1401,16 @@ pub(crate) fn combine_approximation_flags(
 // Number wrapper implementations
 impl Add for Number {
     type Output = Number;
+    #[cfg_attr(feature = "provenance", track_caller)]
     fn add(self, rhs: Number) -> Number {
+        let operands = context::Operands::capture(&self, Some(&rhs));
+
         // Check flags BEFORE moving
         let self_trans = self.is_transcendental();
         let rhs_trans = rhs.is_transcendental();
         let self_rat_approx = self.is_rational_approximation();
         let rhs_rat_approx = rhs.is_rational_approximation();
+        let tracked = error_bound::Tracked::pair(&self, &rhs);
 
         // Compute ONCE - lower layer returns flag for non-terminating rational overflow
         let (result_value, rat_overflow) = self.value + rhs.value;
@@ -1243,22 +1424,33 @@ impl Add for Number {
             rat_overflow,
             &result_value,
         );
+        let err_bound = apprx
+            .as_ref()
+            .and(tracked)
+            .map(|(a, b)| error_bound::add(a, b, &result_value));
 
-        Number {
+        let result = Number {
             value: result_value,
             apprx,
-        }
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("add", result, operands)
     }
 }
 
 impl Sub for Number {
     type Output = Number;
+    #[cfg_attr(feature = "provenance", track_caller)]
     fn sub(self, rhs: Number) -> Number {
+        let operands = context::Operands::capture(&self, Some(&rhs));
+
         // Check flags BEFORE moving
         let self_trans = self.is_transcendental();
         let rhs_trans = rhs.is_transcendental();
         let self_rat_approx = self.is_rational_approximation();
         let rhs_rat_approx = rhs.is_rational_approximation();
+        let tracked = error_bound::Tracked::pair(&self, &rhs);
 
         // Compute ONCE - lower layer returns flag for non-terminating rational overflow
         let (result_value, rat_overflow) = self.value - rhs.value;
@@ -1272,10 +1464,16 @@ impl Sub for Number {
             rat_overflow,
             &result_value,
         );
+        let err_bound = apprx
+            .as_ref()
+            .and(tracked)
+            .map(|(a, b)| error_bound::add(a, b, &result_value));
 
         let result = Number {
             value: result_value,
             apprx,
+            err_bound,
+            provenance: Trail::NONE,
         };
 
         // Skip demotion for obviously-large BigDecimals (saves ~20-30ns)
@@ -1283,22 +1481,26 @@ impl Sub for Number {
             use bigdecimal::BigDecimal;
             const LARGE_THRESHOLD: i64 = i64::MAX / 1000;
             if bd.abs() > BigDecimal::from(LARGE_THRESHOLD) {
-                return result; // Too large to demote, skip expensive checks
+                return context::audit("sub", result, operands); // Too large to demote, skip expensive checks
             }
         }
 
-        result.try_demote()
+        context::audit("sub", result.try_demote(), operands)
     }
 }
 
 impl Mul for Number {
     type Output = Number;
+    #[cfg_attr(feature = "provenance", track_caller)]
     fn mul(self, rhs: Number) -> Number {
+        let operands = context::Operands::capture(&self, Some(&rhs));
+
         // Check flags BEFORE moving
         let self_trans = self.is_transcendental();
         let rhs_trans = rhs.is_transcendental();
         let self_rat_approx = self.is_rational_approximation();
         let rhs_rat_approx = rhs.is_rational_approximation();
+        let tracked = error_bound::Tracked::pair(&self, &rhs);
 
         // Compute ONCE - lower layer handles terminating checks and returns flag
         let (result_value, rat_overflow) = self.value * rhs.value;
@@ -1312,10 +1514,16 @@ impl Mul for Number {
             rat_overflow,
             &result_value,
         );
+        let err_bound = apprx
+            .as_ref()
+            .and(tracked)
+            .map(|(a, b)| error_bound::mul(a, b, &result_value));
 
         let result = Number {
             value: result_value,
             apprx,
+            err_bound,
+            provenance: Trail::NONE,
         };
 
         // Skip demotion for obviously-large BigDecimals (saves ~20-30ns)
@@ -1323,22 +1531,26 @@ impl Mul for Number {
             use bigdecimal::BigDecimal;
             const LARGE_THRESHOLD: i64 = i64::MAX / 1000;
             if bd.abs() > BigDecimal::from(LARGE_THRESHOLD) {
-                return result; // Too large to demote, skip expensive checks
+                return context::audit("mul", result, operands); // Too large to demote, skip expensive checks
             }
         }
 
-        result.try_demote()
+        context::audit("mul", result.try_demote(), operands)
     }
 }
 
 impl Div for Number {
     type Output = Number;
+    #[cfg_attr(feature = "provenance", track_caller)]
     fn div(self, rhs: Number) -> Number {
+        let operands = context::Operands::capture(&self, Some(&rhs));
+
         // Check flags BEFORE moving
         let self_trans = self.is_transcendental();
         let rhs_trans = rhs.is_transcendental();
         let self_rat_approx = self.is_rational_approximation();
         let rhs_rat_approx = rhs.is_rational_approximation();
+        let tracked = error_bound::Tracked::pair(&self, &rhs);
 
         // Compute ONCE - lower layer returns flag for non-terminating rational overflow
         let (result_value, rat_overflow) = self.value / rhs.value;
@@ -1352,10 +1564,16 @@ impl Div for Number {
             rat_overflow,
             &result_value,
         );
+        let err_bound = apprx
+            .as_ref()
+            .and(tracked)
+            .map(|(a, b)| error_bound::div(a, b, &result_value));
 
         let result = Number {
             value: result_value,
             apprx,
+            err_bound,
+            provenance: Trail::NONE,
         };
 
         // Skip demotion for obviously-large BigDecimals (saves ~20-30ns)
@@ -1363,17 +1581,20 @@ impl Div for Number {
             use bigdecimal::BigDecimal;
             const LARGE_THRESHOLD: i64 = i64::MAX / 1000;
             if bd.abs() > BigDecimal::from(LARGE_THRESHOLD) {
-                return result; // Too large to demote, skip expensive checks
+                return context::audit("div", result, operands); // Too large to demote, skip expensive checks
             }
         }
 
-        result.try_demote()
+        context::audit("div", result.try_demote(), operands)
     }
 }
 
 impl Rem for Number {
     type Output = Number;
+    #[cfg_attr(feature = "provenance", track_caller)]
     fn rem(self, rhs: Number) -> Number {
+        let operands = context::Operands::capture(&self, Some(&rhs));
+
         // Check flags BEFORE moving
         let self_trans = self.is_transcendental();
         let rhs_trans = rhs.is_transcendental();
@@ -1396,10 +1617,13 @@ impl Rem for Number {
         let result = Number {
             value: result_value,
             apprx,
+            // Remainder is discontinuous in its inputs, so no bound carries through
+            err_bound: None,
+            provenance: Trail::NONE,
         };
 
         // Try to demote Decimal result back to Rational when possible
-        result.try_demote()
+        context::audit("rem", result.try_demote(), operands)
     }
 }
 
@@ -1409,6 +1633,8 @@ impl Neg for Number {
         Number {
             value: -self.value,
             apprx: self.apprx,
+            err_bound: self.err_bound,
+            provenance: self.provenance,
         }
     }
 }
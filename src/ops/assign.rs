@@ -3,30 +3,35 @@ use std::ops::{AddAssign, DivAssign, MulAssign, RemAssign, SubAssign};
 
 // Arithmetic assignment operators - always available
 impl AddAssign for Number {
+    #[cfg_attr(feature = "provenance", track_caller)]
     fn add_assign(&mut self, rhs: Number) {
         *self = self.clone() + rhs;
     }
 }
 
 impl SubAssign for Number {
+    #[cfg_attr(feature = "provenance", track_caller)]
     fn sub_assign(&mut self, rhs: Number) {
         *self = self.clone() - rhs;
     }
 }
 
 impl MulAssign for Number {
+    #[cfg_attr(feature = "provenance", track_caller)]
     fn mul_assign(&mut self, rhs: Number) {
         *self = self.clone() * rhs;
     }
 }
 
 impl DivAssign for Number {
+    #[cfg_attr(feature = "provenance", track_caller)]
     fn div_assign(&mut self, rhs: Number) {
         *self = self.clone() / rhs;
     }
 }
 
 impl RemAssign for Number {
+    #[cfg_attr(feature = "provenance", track_caller)]
     fn rem_assign(&mut self, rhs: Number) {
         *self = self.clone() % rhs;
     }
@@ -0,0 +1,142 @@
+// Checked arithmetic
+// Contains: checked_* methods that refuse to lose exactness, ExactnessError
+
+use crate::context;
+use crate::core::{ApproximationType, Number, Repr};
+
+/// What a checked operation would have lost.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ExactnessErrorKind {
+    /// The result would carry this approximation flag
+    Approximation(ApproximationType),
+    /// NaN would be produced from finite inputs
+    NaN,
+    /// An infinity would be produced from finite inputs
+    Infinity,
+    /// The value can't be held by this representation at all (see `Number::to_repr`)
+    Unrepresentable(Repr),
+}
+
+/// Error returned by the `checked_*` methods on `Number` when the result
+/// would not be exact.
+///
+/// Carries the operation, what would have been lost, and the operands that
+/// caused it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExactnessError {
+    /// Operation name, e.g. `"mul"` or `"sqrt"`
+    pub op: &'static str,
+    /// What would have been lost
+    pub kind: ExactnessErrorKind,
+    /// Left operand (or the only operand for unary operations)
+    pub lhs: Box<Number>,
+    /// Right operand, for binary operations
+    pub rhs: Option<Box<Number>>,
+}
+
+impl std::fmt::Display for ExactnessError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}({}", self.op, self.lhs)?;
+        if let Some(rhs) = &self.rhs {
+            write!(f, ", {}", rhs)?;
+        }
+        write!(f, ") ")?;
+        match &self.kind {
+            ExactnessErrorKind::Approximation(apprx) => {
+                write!(f, "would be approximate ({:?})", apprx)
+            }
+            ExactnessErrorKind::NaN => write!(f, "would be NaN"),
+            ExactnessErrorKind::Infinity => write!(f, "would be infinite"),
+            ExactnessErrorKind::Unrepresentable(repr) => {
+                write!(f, "would not be representable as {}", repr)
+            }
+        }
+    }
+}
+
+impl std::error::Error for ExactnessError {}
+
+/// Accept `result` only if it is exact, and finite whenever the inputs were.
+///
+/// The operation runs outside the thread's `ArithmeticContext`: checked calls
+/// report loss of exactness through their return value, not by flag or panic.
+fn check(
+    op: &'static str,
+    compute: impl FnOnce() -> Number,
+    lhs: &Number,
+    rhs: Option<&Number>,
+) -> Result<Number, ExactnessError> {
+    let result = context::unaudited(compute);
+    let inputs_finite = lhs.is_finite() && rhs.is_none_or(Number::is_finite);
+
+    let kind = if let Some(apprx) = &result.apprx {
+        ExactnessErrorKind::Approximation(apprx.clone())
+    } else if inputs_finite && result.is_nan() {
+        ExactnessErrorKind::NaN
+    } else if inputs_finite && result.is_infinite() {
+        ExactnessErrorKind::Infinity
+    } else {
+        return Ok(result);
+    };
+
+    Err(ExactnessError {
+        op,
+        kind,
+        lhs: Box::new(lhs.clone()),
+        rhs: rhs.map(|r| Box::new(r.clone())),
+    })
+}
+
+impl Number {
+    /// Addition that fails instead of losing exactness.
+    pub fn checked_add(&self, rhs: &Number) -> Result<Number, ExactnessError> {
+        check("add", || self + rhs, self, Some(rhs))
+    }
+
+    /// Subtraction that fails instead of losing exactness.
+    pub fn checked_sub(&self, rhs: &Number) -> Result<Number, ExactnessError> {
+        check("sub", || self - rhs, self, Some(rhs))
+    }
+
+    /// Multiplication that fails instead of losing exactness.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::Number;
+    /// use std::str::FromStr;
+    ///
+    /// let price = Number::from_str("19.99").unwrap();
+    /// assert_eq!(price.checked_mul(&Number::from(3)).unwrap(), Number::from_str("59.97").unwrap());
+    ///
+    /// let err = Number::from(2).sqrt().checked_mul(&price).unwrap_err();
+    /// println!("{}", err); // mul(1.41..., 19.99) would be approximate (Transcendental)
+    /// ```
+    pub fn checked_mul(&self, rhs: &Number) -> Result<Number, ExactnessError> {
+        check("mul", || self * rhs, self, Some(rhs))
+    }
+
+    /// Division that fails instead of losing exactness. Division by zero is an error.
+    pub fn checked_div(&self, rhs: &Number) -> Result<Number, ExactnessError> {
+        check("div", || self / rhs, self, Some(rhs))
+    }
+
+    /// Remainder that fails instead of losing exactness. Remainder by zero is an error.
+    pub fn checked_rem(&self, rhs: &Number) -> Result<Number, ExactnessError> {
+        check("rem", || self % rhs, self, Some(rhs))
+    }
+
+    /// Square root that fails unless the result is exact (e.g. perfect squares).
+    pub fn checked_sqrt(&self) -> Result<Number, ExactnessError> {
+        check("sqrt", || self.clone().sqrt(), self, None)
+    }
+
+    /// Power that fails unless the result is exact.
+    pub fn checked_pow(&self, exponent: &Number) -> Result<Number, ExactnessError> {
+        check(
+            "pow",
+            || self.clone().pow(exponent.clone()),
+            self,
+            Some(exponent),
+        )
+    }
+}
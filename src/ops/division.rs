@@ -0,0 +1,204 @@
+// Integer division
+// Contains: div_euclid, rem_euclid, div_floor, mod_floor, div_trunc, num_traits::Euclid
+
+use crate::context;
+use crate::number_theory::from_integer;
+use crate::ops::arithmetic::combine_approximation_flags;
+use crate::provenance::Trail;
+use crate::{Number, NumericValue};
+use bigdecimal::num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Euclid, Signed, Zero};
+
+/// Which way an integer quotient is rounded
+#[derive(Clone, Copy)]
+enum Quotient {
+    /// Toward zero; the remainder takes the dividend's sign (`%`)
+    Trunc,
+    /// Toward −∞; the remainder takes the divisor's sign (Python `//` and `%`)
+    Floor,
+    /// Toward −∞ for positive divisors, +∞ for negative; the remainder is never negative
+    Euclid,
+}
+
+impl Quotient {
+    /// The integer quotient of finite, non-zero `a` and `b`
+    fn of(self, a: &BigRational, b: &BigRational) -> BigInt {
+        let q = a / b;
+        match self {
+            Quotient::Trunc => q.trunc().to_integer(),
+            Quotient::Floor => q.floor().to_integer(),
+            Quotient::Euclid if b.is_positive() => q.floor().to_integer(),
+            Quotient::Euclid => q.ceil().to_integer(),
+        }
+    }
+
+    /// The quotient of a finite, non-zero `a` by an infinite divisor: 0 unless
+    /// the rounding direction pulls it to ±1
+    fn of_infinite(self, a: &BigRational, divisor_negative: bool) -> Option<i64> {
+        match self {
+            Quotient::Floor if a.is_negative() != divisor_negative => Some(-1),
+            Quotient::Euclid if a.is_negative() => Some(if divisor_negative { 1 } else { -1 }),
+            _ => None,
+        }
+    }
+}
+
+impl NumericValue {
+    fn integer_quotient(self, rhs: NumericValue, kind: Quotient) -> NumericValue {
+        match (self.to_big_rational(), rhs.to_big_rational()) {
+            (Some(a), Some(b)) if !a.is_zero() && !b.is_zero() => from_integer(kind.of(&a, &b)),
+            (Some(a), None) if rhs.is_infinite() && !a.is_zero() => {
+                match kind.of_infinite(&a, rhs.is_negative_infinity()) {
+                    Some(q) => from_integer(BigInt::from(q)),
+                    None => (self / rhs).0,
+                }
+            }
+            // Signed zeros, infinities and NaN follow the IEEE quotient
+            _ => (self / rhs).0.trunc(),
+        }
+    }
+
+    fn integer_remainder(self, rhs: NumericValue, kind: Quotient) -> NumericValue {
+        match (self.to_big_rational(), rhs.to_big_rational()) {
+            (Some(a), _) if a.is_zero() && !rhs.is_nan() && !rhs.is_zero() => self,
+            (Some(a), Some(b)) if !b.is_zero() => {
+                let q = BigRational::from_integer(kind.of(&a, &b));
+                NumericValue::from_big_rational(a - b * q)
+            }
+            // a - (±∞)·(∓1) is an infinity; with a zero quotient the remainder is a
+            (Some(a), None) if rhs.is_infinite() => {
+                match kind.of_infinite(&a, rhs.is_negative_infinity()) {
+                    Some(_) => match kind {
+                        Quotient::Euclid => NumericValue::PositiveInfinity,
+                        _ => rhs,
+                    },
+                    None => self,
+                }
+            }
+            // x mod 0, ∞ mod y and NaN
+            _ => NumericValue::NaN,
+        }
+    }
+
+    fn is_zero(&self) -> bool {
+        matches!(self, NumericValue::NegativeZero)
+            || self.to_big_rational().is_some_and(|r| r.is_zero())
+    }
+
+    pub fn div_euclid(self, rhs: NumericValue) -> NumericValue {
+        self.integer_quotient(rhs, Quotient::Euclid)
+    }
+
+    pub fn rem_euclid(self, rhs: NumericValue) -> NumericValue {
+        self.integer_remainder(rhs, Quotient::Euclid)
+    }
+
+    pub fn div_floor(self, rhs: NumericValue) -> NumericValue {
+        self.integer_quotient(rhs, Quotient::Floor)
+    }
+
+    pub fn mod_floor(self, rhs: NumericValue) -> NumericValue {
+        self.integer_remainder(rhs, Quotient::Floor)
+    }
+
+    pub fn div_trunc(self, rhs: NumericValue) -> NumericValue {
+        self.integer_quotient(rhs, Quotient::Trunc)
+    }
+}
+
+impl Number {
+    #[cfg_attr(feature = "provenance", track_caller)]
+    fn integer_division(
+        self,
+        rhs: Number,
+        op: &'static str,
+        f: fn(NumericValue, NumericValue) -> NumericValue,
+    ) -> Number {
+        let operands = context::Operands::capture(&self, Some(&rhs));
+        let self_trans = self.is_transcendental();
+        let rhs_trans = rhs.is_transcendental();
+        let self_rat_approx = self.is_rational_approximation();
+        let rhs_rat_approx = rhs.is_rational_approximation();
+
+        let result_value = f(self.value, rhs.value);
+        let apprx = combine_approximation_flags(
+            self_trans,
+            rhs_trans,
+            self_rat_approx,
+            rhs_rat_approx,
+            false,
+            &result_value,
+        );
+        let result = Number {
+            value: result_value,
+            apprx,
+            // Integer quotients are discontinuous in their inputs, so no bound carries through
+            err_bound: None,
+            provenance: Trail::NONE,
+        };
+        context::audit(op, result, operands)
+    }
+
+    /// Euclidean quotient: the integer q with `self = q·rhs + r` and `0 <= r < |rhs|`.
+    ///
+    /// Exact in every finite tier. As with `f64::div_euclid`, dividing by zero
+    /// gives ±∞ (NaN for 0/0) and dividing a negative value by ±∞ gives ∓1.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::Number;
+    ///
+    /// assert_eq!(Number::from(-7).div_euclid(Number::from(2)), Number::from(-4));
+    /// assert_eq!(Number::from(-7).div_euclid(Number::from(-2)), Number::from(4));
+    /// assert_eq!(Number::from(-7).rem_euclid(Number::from(-2)), Number::from(1));
+    /// ```
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn div_euclid(self, rhs: Number) -> Number {
+        self.integer_division(rhs, "div_euclid", NumericValue::div_euclid)
+    }
+
+    /// Euclidean remainder, never negative. NaN when `rhs` is zero or `self` is infinite.
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn rem_euclid(self, rhs: Number) -> Number {
+        self.integer_division(rhs, "rem_euclid", NumericValue::rem_euclid)
+    }
+
+    /// Floor quotient `⌊self / rhs⌋`, like Python's `//`.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::Number;
+    ///
+    /// assert_eq!(Number::from(-7).div_floor(Number::from(2)), Number::from(-4));
+    /// assert_eq!(Number::from(7).div_floor(Number::from(-2)), Number::from(-4));
+    /// assert_eq!(Number::from(7).mod_floor(Number::from(-2)), Number::from(-1));
+    /// ```
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn div_floor(self, rhs: Number) -> Number {
+        self.integer_division(rhs, "div_floor", NumericValue::div_floor)
+    }
+
+    /// Remainder of `div_floor`, with the sign of `rhs`, like Python's `%`.
+    /// NaN when `rhs` is zero or `self` is infinite.
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn mod_floor(self, rhs: Number) -> Number {
+        self.integer_division(rhs, "mod_floor", NumericValue::mod_floor)
+    }
+
+    /// Quotient rounded toward zero; `%` is its remainder.
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn div_trunc(self, rhs: Number) -> Number {
+        self.integer_division(rhs, "div_trunc", NumericValue::div_trunc)
+    }
+}
+
+impl Euclid for Number {
+    fn div_euclid(&self, v: &Self) -> Self {
+        Number::div_euclid(self.clone(), v.clone())
+    }
+
+    fn rem_euclid(&self, v: &Self) -> Self {
+        Number::rem_euclid(self.clone(), v.clone())
+    }
+}
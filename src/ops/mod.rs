@@ -3,4 +3,6 @@
 pub mod arithmetic;
 pub mod assign;
 pub mod bitwise;
+pub mod checked;
 pub mod compound;
+pub mod division;
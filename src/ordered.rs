@@ -6,7 +6,6 @@
 
 use crate::Number;
 use crate::core::NumericValue;
-use num_traits::Zero;
 use std::cmp::Ordering;
 use std::hash::{Hash, Hasher};
 
@@ -94,58 +93,10 @@ impl Ord for OrderedNumber {
 
 impl Hash for OrderedNumber {
     fn hash<H: Hasher>(&self, state: &mut H) {
-        // Hash must be consistent with Eq: equal values must have equal hashes
-        match self.0.value() {
-            NumericValue::NaN => {
-                0u8.hash(state); // discriminant
-                // All NaN values hash the same
-            }
-            NumericValue::PositiveInfinity => {
-                1u8.hash(state);
-            }
-            NumericValue::NegativeInfinity => {
-                2u8.hash(state);
-            }
-            NumericValue::NegativeZero => {
-                // -0 == +0, so they must hash the same
-                3u8.hash(state);
-                0i64.hash(state);
-            }
-            NumericValue::Rational(r, _) => {
-                // For consistent hashing, normalize the representation
-                // Convert to canonical form for hashing
-                if r.is_zero() {
-                    3u8.hash(state);
-                    0i64.hash(state);
-                } else {
-                    3u8.hash(state);
-                    // Use numerator and denominator directly (already reduced)
-                    r.numer().hash(state);
-                    r.denom().hash(state);
-                }
-            }
-            NumericValue::Decimal(d) => {
-                // Check if it equals zero (same hash as Rational zero and -0)
-                if d.is_zero() {
-                    3u8.hash(state);
-                    0i64.hash(state);
-                } else {
-                    // For decimals, normalize and hash
-                    3u8.hash(state);
-                    d.normalize().hash(state);
-                }
-            }
-            NumericValue::BigDecimal(bd) => {
-                if bd.is_zero() {
-                    3u8.hash(state);
-                    0i64.hash(state);
-                } else {
-                    // BigDecimal doesn't have a great hash, use string repr
-                    3u8.hash(state);
-                    bd.to_string().hash(state);
-                }
-            }
-        }
+        // Number's Hash already hashes all NaNs alike and normalizes across
+        // representations (e.g. Decimal 0.5 and Rational 1/2, including values
+        // produced by `to_repr`), so it's consistent with Eq above
+        self.0.hash(state);
     }
 }
 
@@ -1,16 +1,17 @@
 //! Precision control for high-precision arithmetic operations.
 //!
-//! When the `high_precision` feature is enabled, this module provides
-//! thread-local precision configuration for transcendental operations.
+//! When the `high_precision` or `high_precision_pure` feature is enabled, this
+//! module provides thread-local precision configuration for transcendental
+//! operations.
 
-#[cfg(feature = "high_precision")]
+#[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
 use std::cell::RefCell;
 
-#[cfg(feature = "high_precision")]
+#[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
 thread_local! {
     /// Default precision in bits for high-precision operations.
     /// Default is 256 bits (~71 decimal digits).
-    static PRECISION: RefCell<u32> = RefCell::new(256);
+    static PRECISION: RefCell<u32> = const { RefCell::new(256) };
 }
 
 /// Set the default precision for high-precision transcendental operations.
@@ -26,7 +27,7 @@ thread_local! {
 /// ```
 /// use faithful_number::Number;
 ///
-/// #[cfg(feature = "high_precision")]
+/// #[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
 /// {
 ///     // Set precision to 200 bits (~60 decimal digits)
 ///     Number::set_default_precision(200);
@@ -36,7 +37,7 @@ thread_local! {
 ///     println!("{}", result.to_f64());
 /// }
 /// ```
-#[cfg(feature = "high_precision")]
+#[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
 pub fn set_default_precision(bits: u32) {
     PRECISION.with(|p| *p.borrow_mut() = bits);
 }
@@ -45,19 +46,19 @@ pub fn set_default_precision(bits: u32) {
 ///
 /// # Returns
 /// The current precision setting for the current thread.
-#[cfg(feature = "high_precision")]
+#[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
 pub fn get_default_precision() -> u32 {
     PRECISION.with(|p| *p.borrow())
 }
 
-/// No-op version when high_precision feature is disabled.
-#[cfg(not(feature = "high_precision"))]
+/// No-op version when neither high-precision feature is enabled.
+#[cfg(not(any(feature = "high_precision", feature = "high_precision_pure")))]
 pub fn set_default_precision(_bits: u32) {
-    // No-op: precision control only available with high_precision feature
+    // No-op: precision control only available with a high-precision feature
 }
 
-/// Returns 0 when high_precision feature is disabled (uses f64).
-#[cfg(not(feature = "high_precision"))]
+/// Returns 0 when neither high-precision feature is enabled (uses f64).
+#[cfg(not(any(feature = "high_precision", feature = "high_precision_pure")))]
 pub fn get_default_precision() -> u32 {
     0 // Indicates f64 precision
 }
@@ -67,7 +68,7 @@ mod tests {
     use super::*;
 
     #[test]
-    #[cfg(feature = "high_precision")]
+    #[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
     fn test_precision_control() {
         let default = get_default_precision();
         assert_eq!(default, 256); // Default is 256 bits (~71 decimal digits)
@@ -80,7 +81,7 @@ mod tests {
     }
 
     #[test]
-    #[cfg(not(feature = "high_precision"))]
+    #[cfg(not(any(feature = "high_precision", feature = "high_precision_pure")))]
     fn test_precision_control_disabled() {
         assert_eq!(get_default_precision(), 0);
         set_default_precision(200); // Should be no-op
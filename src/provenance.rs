@@ -0,0 +1,116 @@
+//! Approximation provenance (`provenance` feature).
+//!
+//! With the feature on, every approximate `Number` points at the step where its
+//! value first became approximate: the operation, its operands, and the call
+//! site. Values derived from it share that record through an `Arc`, so the cost
+//! is one pointer per `Number`. With the feature off, `Trail` is zero-sized and
+//! nothing is recorded.
+
+#[cfg(feature = "provenance")]
+use crate::core::{ApproximationType, Number};
+#[cfg(feature = "provenance")]
+use std::panic::Location;
+#[cfg(feature = "provenance")]
+use std::sync::Arc;
+
+/// Provenance slot stored in every `Number`.
+#[cfg(feature = "provenance")]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Trail(Option<Arc<Provenance>>);
+
+/// Provenance slot stored in every `Number` (zero-sized without the `provenance` feature).
+#[cfg(not(feature = "provenance"))]
+#[derive(Debug, Clone, Default)]
+pub(crate) struct Trail;
+
+impl Trail {
+    #[cfg(feature = "provenance")]
+    pub(crate) const NONE: Trail = Trail(None);
+    #[cfg(not(feature = "provenance"))]
+    pub(crate) const NONE: Trail = Trail;
+}
+
+/// Where and how a value first became approximate.
+///
+/// Returned by `Number::explain()`. `Display` gives a one-line summary such as
+/// `sqrt(2) at src/report.rs:42:17 (Transcendental)`.
+#[cfg(feature = "provenance")]
+#[derive(Debug, Clone)]
+pub struct Provenance {
+    op: &'static str,
+    kind: ApproximationType,
+    operands: Vec<Number>,
+    location: &'static Location<'static>,
+}
+
+#[cfg(feature = "provenance")]
+impl Provenance {
+    /// Operation that introduced the approximation, e.g. `"sqrt"` or `"div"`
+    pub fn op(&self) -> &'static str {
+        self.op
+    }
+
+    /// Approximation introduced by the operation
+    pub fn kind(&self) -> &ApproximationType {
+        &self.kind
+    }
+
+    /// Operand values, left to right
+    pub fn operands(&self) -> &[Number] {
+        &self.operands
+    }
+
+    /// Call site of the operation
+    pub fn location(&self) -> &'static Location<'static> {
+        self.location
+    }
+}
+
+#[cfg(feature = "provenance")]
+impl std::fmt::Display for Provenance {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}(", self.op)?;
+        for (i, operand) in self.operands.iter().enumerate() {
+            if i > 0 {
+                write!(f, ", ")?;
+            }
+            write!(f, "{}", operand)?;
+        }
+        write!(f, ") at {} ({:?})", self.location, self.kind)
+    }
+}
+
+#[cfg(feature = "provenance")]
+impl Trail {
+    pub(crate) fn get(&self) -> Option<&Provenance> {
+        self.0.as_deref()
+    }
+
+    /// Provenance for an approximate result: inherited from the first operand
+    /// that already has one, otherwise a new record for this step.
+    pub(crate) fn record(
+        op: &'static str,
+        kind: &ApproximationType,
+        lhs: &Number,
+        rhs: Option<&Number>,
+        location: &'static Location<'static>,
+    ) -> Trail {
+        let inherited = lhs
+            .provenance
+            .0
+            .as_ref()
+            .or_else(|| rhs.and_then(|r| r.provenance.0.as_ref()));
+        if let Some(existing) = inherited {
+            return Trail(Some(Arc::clone(existing)));
+        }
+
+        let mut operands = vec![lhs.clone()];
+        operands.extend(rhs.cloned());
+        Trail(Some(Arc::new(Provenance {
+            op,
+            kind: kind.clone(),
+            operands,
+            location,
+        })))
+    }
+}
@@ -0,0 +1,193 @@
+//! Exact decimal strings: terminating digits in full, repeating digits marked
+//! with parentheses ("0.1(6)") or a combining overline ("0.16̅").
+
+use crate::{Number, NumericValue};
+use bigdecimal::num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{One, Signed, ToPrimitive, Zero};
+
+/// Longest repeating block `to_exact_string` will write out. The period of 1/q
+/// can be q - 1 digits, which is out of reach for most i64 denominators.
+const MAX_PERIOD: usize = 100_000;
+
+/// Combining overline, written after each repeating digit
+const OVERLINE: char = '\u{0305}';
+
+/// How `to_exact_string_with` marks the repeating block
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum RepeatingNotation {
+    /// `0.1(6)`
+    #[default]
+    Parentheses,
+    /// `0.16̅`: U+0305 after every repeating digit
+    Overline,
+}
+
+/// Digits of |value|: integer part, the digits before the repeating block and
+/// the block itself (empty for terminating values). None past `MAX_PERIOD`.
+fn expansion(value: &BigRational) -> Option<(BigInt, String, String)> {
+    let value = value.abs();
+    let denom = value.denom();
+    let integer = value.numer() / denom;
+    let mut remainder = value.numer() % denom;
+
+    // The digits before the block: one per factor 2 or 5 of the denominator
+    let (mut twos, mut fives, mut rest) = (0usize, 0usize, denom.clone());
+    while (&rest % 2u8).is_zero() {
+        rest /= 2u8;
+        twos += 1;
+    }
+    while (&rest % 5u8).is_zero() {
+        rest /= 5u8;
+        fives += 1;
+    }
+    let next_digit = |remainder: &mut BigInt| {
+        *remainder *= 10u8;
+        let digit = (&*remainder / denom).to_u8().expect("a single digit");
+        *remainder %= denom;
+        char::from(b'0' + digit)
+    };
+
+    let prefix: String = (0..twos.max(fives))
+        .map(|_| next_digit(&mut remainder))
+        .collect();
+    if rest.is_one() {
+        return Some((integer, prefix, String::new()));
+    }
+
+    // 10^k ≡ 1 (mod rest), so the remainder comes back to where the block started
+    let start = remainder.clone();
+    let mut block = String::new();
+    loop {
+        block.push(next_digit(&mut remainder));
+        if remainder == start {
+            return Some((integer, prefix, block));
+        }
+        if block.len() >= MAX_PERIOD {
+            return None;
+        }
+    }
+}
+
+/// Parse `[sign]digits.digits(block)` or the overline form; None for anything else
+pub(crate) fn parse(s: &str) -> Option<NumericValue> {
+    if !s.contains('(') && !s.contains(OVERLINE) {
+        return None;
+    }
+    let (negative, unsigned) = match s.as_bytes().first()? {
+        b'-' => (true, &s[1..]),
+        b'+' => (false, &s[1..]),
+        _ => (false, s),
+    };
+    let (integer, fraction) = unsigned.split_once('.')?;
+
+    let (prefix, block) = match fraction.strip_suffix(')') {
+        Some(open) => {
+            let (prefix, block) = open.split_once('(')?;
+            (prefix, block.to_string())
+        }
+        None => {
+            // Overlined digits form the block and must come last
+            let first = fraction.find(OVERLINE)?.checked_sub(1)?;
+            let mut block = String::new();
+            let mut chars = fraction.get(first..)?.chars();
+            while let Some(digit) = chars.next() {
+                if chars.next() != Some(OVERLINE) {
+                    return None;
+                }
+                block.push(digit);
+            }
+            (fraction.get(..first)?, block)
+        }
+    };
+    value_of(negative, integer, prefix, &block)
+}
+
+/// ±(integer.prefix + block / (10^len(prefix) · (10^len(block) − 1)))
+fn value_of(negative: bool, integer: &str, prefix: &str, block: &str) -> Option<NumericValue> {
+    let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if block.is_empty() || !all_digits(integer) || !all_digits(prefix) || !all_digits(block) {
+        return None;
+    }
+    let number = |s: &str| {
+        if s.is_empty() {
+            Some(BigInt::zero())
+        } else {
+            s.parse::<BigInt>().ok()
+        }
+    };
+    let ten = BigInt::from(10u8);
+    let prefix_scale = num_traits::pow(ten.clone(), prefix.len());
+    let block_scale = num_traits::pow(ten, block.len()) - 1u8;
+
+    let whole = number(integer)? * &prefix_scale + number(prefix)?;
+    let value = BigRational::new(whole, prefix_scale.clone())
+        + BigRational::new(number(block)?, prefix_scale * block_scale);
+    Some(NumericValue::from_big_rational(if negative {
+        -value
+    } else {
+        value
+    }))
+}
+
+impl Number {
+    /// The stored value as an exact decimal string, with any repeating block in
+    /// parentheses: `1/6` is `"0.1(6)"`, `1/8` is `"0.125"`.
+    ///
+    /// Unlike `Display`, nothing is rounded and `-0` keeps its sign, so the
+    /// result parses back to the same value. None when the repeating block is
+    /// longer than 100 000 digits.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::Number;
+    /// use std::str::FromStr;
+    ///
+    /// let seventh = Number::from(1) / Number::from(7);
+    /// assert_eq!(seventh.to_exact_string().unwrap(), "0.(142857)");
+    /// assert_eq!(Number::from_str("0.(142857)").unwrap(), seventh);
+    /// ```
+    pub fn to_exact_string(&self) -> Option<String> {
+        self.to_exact_string_with(RepeatingNotation::Parentheses)
+    }
+
+    /// `to_exact_string` with a choice of notation for the repeating block
+    pub fn to_exact_string_with(&self, notation: RepeatingNotation) -> Option<String> {
+        let exact = match &self.value {
+            NumericValue::NegativeZero => return Some("-0".to_string()),
+            NumericValue::NaN | NumericValue::PositiveInfinity | NumericValue::NegativeInfinity => {
+                return Some(self.to_string());
+            }
+            value => value.to_big_rational()?,
+        };
+        let (integer, prefix, block) = expansion(&exact)?;
+
+        let mut s = String::new();
+        if exact.is_negative() {
+            s.push('-');
+        }
+        s.push_str(&integer.to_string());
+        if prefix.is_empty() && block.is_empty() {
+            return Some(s);
+        }
+        s.push('.');
+        s.push_str(&prefix);
+        if block.is_empty() {
+            return Some(s);
+        }
+        match notation {
+            RepeatingNotation::Parentheses => {
+                s.push('(');
+                s.push_str(&block);
+                s.push(')');
+            }
+            RepeatingNotation::Overline => {
+                for digit in block.chars() {
+                    s.push(digit);
+                    s.push(OVERLINE);
+                }
+            }
+        }
+        Some(s)
+    }
+}
@@ -1,2 +1,204 @@
 // Representation management and conversion logic
 // Contains: upgrade/downgrade logic, simplification, representation conversions
+
+use bigdecimal::BigDecimal;
+use bigdecimal::num_bigint::BigInt;
+use num_rational::{BigRational, Ratio, Rational64};
+use num_traits::{ToPrimitive, Zero};
+use rust_decimal::Decimal;
+
+use crate::core::{NumericValue, Repr, is_terminating_decimal, try_bigdecimal_to_decimal};
+use crate::{ApproximationType, ExactnessErrorKind};
+
+/// Widen a Rational64 to an arbitrary-size BigRational (always exact)
+#[inline]
+pub(crate) fn rational_to_big_rational(r: &Rational64) -> BigRational {
+    Ratio::new_raw(BigInt::from(*r.numer()), BigInt::from(*r.denom()))
+}
+
+/// Convert a Decimal to BigRational exactly: mantissa / 10^scale
+#[inline]
+pub(crate) fn decimal_to_big_rational(d: Decimal) -> BigRational {
+    Ratio::new(BigInt::from(d.mantissa()), BigInt::from(10).pow(d.scale()))
+}
+
+/// Convert a BigDecimal to BigRational exactly: bigint × 10^(-exponent)
+pub(crate) fn bigdecimal_to_big_rational(bd: &BigDecimal) -> BigRational {
+    let (bigint, exponent) = bd.as_bigint_and_exponent();
+    if exponent >= 0 {
+        Ratio::new(bigint, BigInt::from(10).pow(exponent as u32))
+    } else {
+        Ratio::from_integer(bigint * BigInt::from(10).pow((-exponent) as u32))
+    }
+}
+
+/// Narrow a BigRational back to Rational64 if numerator and denominator both fit in i64
+#[inline]
+pub(crate) fn try_big_rational_to_rational64(r: &BigRational) -> Option<Rational64> {
+    let numer = r.numer().to_i64()?;
+    let denom = r.denom().to_i64()?;
+    // Already reduced, so no need to re-normalize
+    Some(Ratio::new_raw(numer, denom))
+}
+
+/// If denom = 2^a × 5^b, return (a, b). Otherwise None (non-terminating).
+fn two_five_exponents(denom: &BigInt) -> Option<(u32, u32)> {
+    let mut d = denom.clone();
+    let twos = d.trailing_zeros().unwrap_or(0);
+    d >>= twos;
+
+    let five = BigInt::from(5);
+    let mut fives = 0u32;
+    while (&d % &five).is_zero() {
+        d /= &five;
+        fives += 1;
+    }
+
+    (d == BigInt::from(1)).then_some((twos as u32, fives))
+}
+
+/// Check if a BigRational denominator only has factors of 2 and 5
+#[inline]
+pub(crate) fn is_terminating_big(denom: &BigInt) -> bool {
+    two_five_exponents(denom).is_some()
+}
+
+/// Convert a BigRational to BigDecimal.
+///
+/// Exact for terminating values (denominator scaled up to a power of ten);
+/// non-terminating values are rounded to BigDecimal's default precision.
+pub(crate) fn big_rational_to_bigdecimal(r: &BigRational) -> BigDecimal {
+    if let Some((twos, fives)) = two_five_exponents(r.denom()) {
+        let scale = twos.max(fives);
+        let factor = BigInt::from(2).pow(scale - twos) * BigInt::from(5).pow(scale - fives);
+        BigDecimal::new(r.numer() * factor, scale as i64)
+    } else {
+        BigDecimal::from(r.numer().clone()) / BigDecimal::from(r.denom().clone())
+    }
+}
+
+impl NumericValue {
+    /// Exact BigRational view of any finite value (-0 maps to 0).
+    /// Returns None for NaN and the infinities.
+    pub(crate) fn to_big_rational(&self) -> Option<BigRational> {
+        match self {
+            NumericValue::Rational(r, _) => Some(rational_to_big_rational(r)),
+            NumericValue::BigRational(r) => Some(r.clone()),
+            NumericValue::Decimal(d) => Some(decimal_to_big_rational(*d)),
+            NumericValue::BigDecimal(bd) => Some(bigdecimal_to_big_rational(bd)),
+            NumericValue::NegativeZero => Some(BigRational::zero()),
+            NumericValue::NaN | NumericValue::PositiveInfinity | NumericValue::NegativeInfinity => {
+                None
+            }
+        }
+    }
+}
+
+impl NumericValue {
+    /// Convert to `target` without changing the value.
+    ///
+    /// Returns `Approximation(RationalApproximation)` when the value only fits after
+    /// rounding, and `Unrepresentable` when `target` can't hold it at all.
+    pub(crate) fn convert_to(&self, target: Repr) -> Result<NumericValue, ExactnessErrorKind> {
+        if self.representation() == target {
+            return Ok(self.clone());
+        }
+        let unrepresentable = Err(ExactnessErrorKind::Unrepresentable(target));
+        let lossy = Err(ExactnessErrorKind::Approximation(
+            ApproximationType::RationalApproximation,
+        ));
+
+        // -0 only converts to itself: the finite representations would drop the sign
+        if matches!(self, NumericValue::NegativeZero) {
+            return unrepresentable;
+        }
+        let Some(exact) = self.to_big_rational() else {
+            return unrepresentable;
+        };
+
+        match target {
+            Repr::Rational => match try_big_rational_to_rational64(&exact) {
+                Some(r) => Ok(NumericValue::Rational(
+                    r,
+                    is_terminating_decimal(*r.numer(), *r.denom()),
+                )),
+                None => unrepresentable,
+            },
+            // Arithmetic relies on a BigRational never being zero
+            Repr::BigRational if exact.is_zero() => unrepresentable,
+            Repr::BigRational => Ok(NumericValue::BigRational(exact)),
+            Repr::Decimal => {
+                let bd = big_rational_to_bigdecimal(&exact);
+                if is_terminating_big(exact.denom())
+                    && let Some(d) = try_bigdecimal_to_decimal(&bd)
+                {
+                    Ok(NumericValue::Decimal(d))
+                } else if bd.abs() <= BigDecimal::from(Decimal::MAX.mantissa()) {
+                    lossy
+                } else {
+                    unrepresentable
+                }
+            }
+            Repr::BigDecimal => {
+                if is_terminating_big(exact.denom()) {
+                    Ok(NumericValue::BigDecimal(big_rational_to_bigdecimal(&exact)))
+                } else {
+                    lossy
+                }
+            }
+            Repr::NaN | Repr::PositiveInfinity | Repr::NegativeInfinity | Repr::NegativeZero => {
+                unrepresentable
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::str::FromStr;
+
+    #[test]
+    fn decimal_round_trips_through_big_rational() {
+        let d = Decimal::from_str("-12345678901234567890.125").unwrap();
+        let r = decimal_to_big_rational(d);
+        assert_eq!(big_rational_to_bigdecimal(&r).to_string(), d.to_string());
+    }
+
+    #[test]
+    fn bigdecimal_with_negative_exponent_is_integer() {
+        let bd = BigDecimal::new(BigInt::from(12), -30);
+        let r = bigdecimal_to_big_rational(&bd);
+        assert!(r.is_integer());
+        assert_eq!(r.numer(), &(BigInt::from(12) * BigInt::from(10).pow(30)));
+    }
+
+    #[test]
+    fn terminating_detection() {
+        assert!(is_terminating_big(&BigInt::from(1)));
+        assert!(is_terminating_big(&BigInt::from(1_000_000_000_000i64)));
+        assert!(is_terminating_big(&(BigInt::from(2).pow(80))));
+        assert!(!is_terminating_big(&BigInt::from(3)));
+        assert!(!is_terminating_big(&(BigInt::from(10).pow(30) * 7)));
+    }
+
+    #[test]
+    fn terminating_conversion_is_exact_beyond_default_precision() {
+        // 1 / 2^400 has 400 significant decimal digits - more than BigDecimal's default
+        let r = Ratio::new(BigInt::from(1), BigInt::from(2).pow(400));
+        let bd = big_rational_to_bigdecimal(&r);
+        assert_eq!(bigdecimal_to_big_rational(&bd), r);
+    }
+
+    #[test]
+    fn narrowing_to_rational64() {
+        let small = Ratio::new(BigInt::from(-7), BigInt::from(3));
+        assert_eq!(
+            try_big_rational_to_rational64(&small),
+            Some(Ratio::new(-7, 3))
+        );
+
+        let big = Ratio::new(BigInt::from(1), BigInt::from(i64::MAX) * 3);
+        assert_eq!(try_big_rational_to_rational64(&big), None);
+    }
+}
@@ -0,0 +1,355 @@
+//! Rounding to an integer, a number of decimal places, a number of significant
+//! digits or a multiple of an increment, with an explicit `RoundingMode`.
+//!
+//! Every mode is computed exactly on the value's rational view, so a Rational
+//! input never passes through `Decimal` and loses digits along the way. Decimal
+//! and BigDecimal inputs keep their representation.
+
+use crate::context;
+use crate::provenance::Trail;
+use crate::{Number, NumericValue};
+use bigdecimal::BigDecimal;
+use bigdecimal::num_bigint::BigInt;
+use num_rational::BigRational;
+use num_traits::{Signed, ToPrimitive, Zero};
+use rust_decimal::Decimal;
+
+/// How to pick a result when a value lies between two candidates.
+///
+/// The `Half*` modes round to the nearest candidate and only differ on ties.
+/// `HalfUp` and `HalfDown` break ties toward +∞ and −∞; the magnitude-based
+/// tie-breakers are `HalfAwayFromZero` and `HalfTowardZero` (see decision 020).
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum RoundingMode {
+    /// Nearest, ties to the even candidate (banker's rounding)
+    HalfEven,
+    /// Nearest, ties toward +∞ (JavaScript `Math.round`)
+    HalfUp,
+    /// Nearest, ties toward −∞
+    HalfDown,
+    /// Nearest, ties toward zero
+    HalfTowardZero,
+    /// Nearest, ties away from zero (the default `round()`)
+    HalfAwayFromZero,
+    /// Away from zero
+    Up,
+    /// Toward zero (truncation)
+    Down,
+    /// Toward +∞
+    Ceiling,
+    /// Toward −∞
+    Floor,
+}
+
+impl RoundingMode {
+    /// Round `value` to an integer under this mode
+    pub(crate) fn round_to_integer(self, value: &BigRational) -> BigInt {
+        let floor = value.floor().to_integer();
+        let fraction = value - BigRational::from_integer(floor.clone());
+        if fraction.is_zero() {
+            return floor;
+        }
+        let ceiling = &floor + 1;
+        let positive = value.is_positive();
+        let toward_zero = |floor: BigInt, ceiling: BigInt| if positive { floor } else { ceiling };
+        let away_from_zero =
+            |floor: BigInt, ceiling: BigInt| if positive { ceiling } else { floor };
+
+        let tie = match self {
+            RoundingMode::Floor => return floor,
+            RoundingMode::Ceiling => return ceiling,
+            RoundingMode::Down => return toward_zero(floor, ceiling),
+            RoundingMode::Up => return away_from_zero(floor, ceiling),
+            _ => fraction * BigInt::from(2) - BigRational::from_integer(BigInt::from(1)),
+        };
+        if tie.is_negative() {
+            return floor;
+        }
+        if tie.is_positive() {
+            return ceiling;
+        }
+        match self {
+            RoundingMode::HalfEven if (&floor % 2u8).is_zero() => floor,
+            RoundingMode::HalfEven => ceiling,
+            RoundingMode::HalfUp => ceiling,
+            RoundingMode::HalfDown => floor,
+            RoundingMode::HalfTowardZero => toward_zero(floor, ceiling),
+            _ => away_from_zero(floor, ceiling),
+        }
+    }
+}
+
+/// -0 for a negative value that rounded to zero, like `f64::round` and JS `Math.round`
+fn keep_sign_of_zero(rounded: NumericValue, negative: bool) -> NumericValue {
+    let zero = match &rounded {
+        NumericValue::Rational(r, _) => r.is_zero(),
+        NumericValue::Decimal(d) => d.is_zero(),
+        NumericValue::BigDecimal(bd) => bd.is_zero(),
+        _ => false,
+    };
+    if negative && zero {
+        NumericValue::NegativeZero
+    } else {
+        rounded
+    }
+}
+
+/// Whether a value is below zero (-0 is not)
+fn below_zero(value: &NumericValue) -> bool {
+    match value {
+        NumericValue::Rational(r, _) => r.is_negative(),
+        NumericValue::BigRational(r) => r.is_negative(),
+        NumericValue::Decimal(d) => d.is_sign_negative() && !d.is_zero(),
+        NumericValue::BigDecimal(bd) => bd.is_negative(),
+        _ => false,
+    }
+}
+
+/// 10^dp as a BigInt
+fn power_of_ten(dp: u64) -> BigInt {
+    num_traits::pow(BigInt::from(10), dp as usize)
+}
+
+/// `value` rounded to `dp` decimal places, as an exact rational
+fn round_rational(value: &BigRational, dp: u32, mode: RoundingMode) -> BigRational {
+    let scale = power_of_ten(u64::from(dp));
+    let scaled = value * BigRational::from_integer(scale.clone());
+    BigRational::new(mode.round_to_integer(&scaled), scale)
+}
+
+/// The integer nearest `mantissa · 10^-from_scale` under `mode`, counted in
+/// units of 10^-to_scale (`from_scale > to_scale`)
+fn round_mantissa(mantissa: BigInt, from_scale: u64, to_scale: u64, mode: RoundingMode) -> BigInt {
+    let divisor = power_of_ten(from_scale - to_scale);
+    mode.round_to_integer(&BigRational::new(mantissa, divisor))
+}
+
+/// floor(log10 |value|) for a non-zero `value`
+fn decimal_exponent(value: &BigRational) -> i64 {
+    let magnitude = value.abs();
+    let digits = |n: &BigInt| n.magnitude().to_string().len() as i64;
+    let mut exponent = digits(magnitude.numer()) - digits(magnitude.denom());
+    let power = |e: i64| {
+        if e >= 0 {
+            BigRational::from_integer(power_of_ten(e as u64))
+        } else {
+            BigRational::new(BigInt::from(1), power_of_ten(e.unsigned_abs()))
+        }
+    };
+    // The digit counts put the exponent within one of the answer
+    while power(exponent) > magnitude {
+        exponent -= 1;
+    }
+    while power(exponent + 1) <= magnitude {
+        exponent += 1;
+    }
+    exponent
+}
+
+impl NumericValue {
+    /// Round to a multiple of 10^-exponent, where `exponent` may be negative
+    fn round_to_exponent(self, exponent: i64, mode: RoundingMode) -> NumericValue {
+        if exponent >= 0 {
+            let dp = u32::try_from(exponent).unwrap_or(u32::MAX);
+            return self.round_dp_with(dp, mode);
+        }
+        let Some(exact) = self.to_big_rational() else {
+            return self;
+        };
+        let unit = power_of_ten(exponent.unsigned_abs());
+        let units = mode.round_to_integer(&(exact / BigRational::from_integer(unit.clone())));
+        let rounded = units * unit;
+        match self {
+            NumericValue::Decimal(_) => match rounded
+                .to_i128()
+                .and_then(|m| Decimal::try_from_i128_with_scale(m, 0).ok())
+            {
+                Some(d) => NumericValue::Decimal(d),
+                None => NumericValue::from_big_rational(BigRational::from_integer(rounded)),
+            },
+            NumericValue::BigDecimal(_) => NumericValue::BigDecimal(BigDecimal::from(rounded)),
+            NumericValue::NegativeZero => NumericValue::NegativeZero,
+            _ => NumericValue::from_big_rational(BigRational::from_integer(rounded)),
+        }
+    }
+
+    /// Round to `digits` significant digits under `mode`; NaN when `digits` is 0
+    pub fn round_sf(self, digits: u32, mode: RoundingMode) -> NumericValue {
+        if digits == 0 {
+            return NumericValue::NaN;
+        }
+        match self.to_big_rational() {
+            Some(exact) if !exact.is_zero() => {
+                let exponent = i64::from(digits) - 1 - decimal_exponent(&exact);
+                self.round_to_exponent(exponent, mode)
+            }
+            // Zeros, NaN and the infinities are unchanged
+            _ => self,
+        }
+    }
+
+    /// Round to the nearest multiple of `step` under `mode`, exactly.
+    ///
+    /// The sign of `step` is ignored. NaN when `step` is zero or not finite. A
+    /// negative value that rounds to zero gives -0.
+    pub fn round_to_increment(self, step: &NumericValue, mode: RoundingMode) -> NumericValue {
+        let step = match step.to_big_rational() {
+            Some(step) if !step.is_zero() => step.abs(),
+            _ => return NumericValue::NaN,
+        };
+        match self.to_big_rational() {
+            Some(_) if matches!(self, NumericValue::NegativeZero) => self,
+            Some(exact) => {
+                let negative = exact.is_negative();
+                let steps = mode.round_to_integer(&(exact / &step));
+                let rounded =
+                    NumericValue::from_big_rational(BigRational::from_integer(steps) * step);
+                keep_sign_of_zero(rounded, negative)
+            }
+            None => self,
+        }
+    }
+
+    /// Round to an integer under `mode`
+    pub fn round_with(self, mode: RoundingMode) -> NumericValue {
+        self.round_dp_with(0, mode)
+    }
+
+    /// Round to `dp` decimal places under `mode`, exactly. A negative value that
+    /// rounds to zero gives -0.
+    pub fn round_dp_with(self, dp: u32, mode: RoundingMode) -> NumericValue {
+        let negative = below_zero(&self);
+        let rounded = match self {
+            NumericValue::Rational(r, _) if r.is_integer() => NumericValue::from_rational(r),
+            NumericValue::Rational(..) | NumericValue::BigRational(_) => {
+                let exact = self.to_big_rational().expect("finite value");
+                NumericValue::from_big_rational(round_rational(&exact, dp, mode))
+            }
+            NumericValue::Decimal(d) => {
+                if d.scale() <= dp {
+                    return NumericValue::Decimal(d);
+                }
+                let rounded = round_mantissa(
+                    BigInt::from(d.mantissa()),
+                    u64::from(d.scale()),
+                    u64::from(dp),
+                    mode,
+                );
+                // At least one digit is dropped, so the mantissa still fits
+                let mantissa = rounded.to_i128().expect("rounded Decimal mantissa");
+                NumericValue::Decimal(Decimal::from_i128_with_scale(mantissa, dp))
+            }
+            NumericValue::BigDecimal(bd) => {
+                let (mantissa, scale) = bd.as_bigint_and_exponent();
+                if scale <= i64::from(dp) {
+                    return NumericValue::BigDecimal(bd);
+                }
+                // scale > dp >= 0 here
+                let rounded = round_mantissa(mantissa, scale as u64, u64::from(dp), mode);
+                NumericValue::BigDecimal(BigDecimal::new(rounded, i64::from(dp)))
+            }
+            NumericValue::NegativeZero => NumericValue::NegativeZero, // round(-0) = -0
+            NumericValue::NaN => NumericValue::NaN,
+            NumericValue::PositiveInfinity => NumericValue::PositiveInfinity,
+            NumericValue::NegativeInfinity => NumericValue::NegativeInfinity,
+        };
+        keep_sign_of_zero(rounded, negative)
+    }
+}
+
+impl Number {
+    /// Round to an integer under `mode`.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::{Number, RoundingMode};
+    ///
+    /// let x = Number::from(-5) / Number::from(2);
+    /// assert_eq!(x.clone().round_with(RoundingMode::HalfEven), Number::from(-2));
+    /// assert_eq!(x.clone().round_with(RoundingMode::HalfUp), Number::from(-2));
+    /// assert_eq!(x.round_with(RoundingMode::HalfDown), Number::from(-3));
+    /// ```
+    pub fn round_with(self, mode: RoundingMode) -> Number {
+        Number {
+            value: self.value.round_with(mode),
+            // Rounding removes approximate decimal digits - result is exact
+            apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
+        }
+    }
+
+    /// Round to `dp` decimal places under `mode`.
+    ///
+    /// Rationals are rounded exactly, so `2/3` to 30 places keeps all 30 digits.
+    pub fn round_dp_with(self, dp: u32, mode: RoundingMode) -> Number {
+        Number {
+            value: self.value.round_dp_with(dp, mode),
+            // Rounding removes approximate decimal digits - result is exact
+            apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
+        }
+    }
+
+    /// Round to `digits` significant digits under `mode`; NaN when `digits` is 0.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::{Number, RoundingMode};
+    ///
+    /// let x = Number::from(2) / Number::from(3);
+    /// assert_eq!(x.round_sf(3, RoundingMode::HalfEven).to_string(), "0.667");
+    /// let n = Number::from(123_456);
+    /// assert_eq!(n.round_sf(2, RoundingMode::Floor), Number::from(120_000));
+    /// ```
+    pub fn round_sf(self, digits: u32, mode: RoundingMode) -> Number {
+        Number {
+            value: self.value.round_sf(digits, mode),
+            // Rounding removes approximate decimal digits - result is exact
+            apprx: None,
+            err_bound: None,
+            provenance: Trail::NONE,
+        }
+    }
+
+    /// Round to the nearest multiple of `step` under `mode`.
+    ///
+    /// Exact whenever `step` is, including steps that are not powers of ten.
+    /// An approximate `step` passes its flag and error bound (times the
+    /// multiple) to the result. NaN when `step` is zero or not finite.
+    ///
+    /// # Example
+    /// ```
+    /// use faithful_number::{Number, RoundingMode};
+    /// use std::str::FromStr;
+    ///
+    /// // Swiss cash rounding to the nearest 0.05
+    /// let price = Number::from_str("12.43").unwrap();
+    /// let step = Number::from_str("0.05").unwrap();
+    /// let rounded = price.round_to_increment(step, RoundingMode::HalfAwayFromZero);
+    /// assert_eq!(rounded, Number::from_str("12.45").unwrap());
+    /// assert!(rounded.is_exact());
+    /// ```
+    #[cfg_attr(feature = "provenance", track_caller)]
+    pub fn round_to_increment(self, step: Number, mode: RoundingMode) -> Number {
+        let operands = context::Operands::capture(&self, Some(&step));
+        let value = self.value.round_to_increment(&step.value, mode);
+        let multiple = match (value.to_big_rational(), step.value.to_big_rational()) {
+            (Some(value), Some(step)) if !step.is_zero() => (value / step).abs().to_f64(),
+            _ => None,
+        };
+        // The multiple is exact; only the step's own error carries over
+        let (apprx, err_bound) = match step.apprx {
+            None => (None, None),
+            apprx => (apprx, step.err_bound.zip(multiple).map(|(e, k)| e * k)),
+        };
+        let result = Number {
+            value,
+            apprx,
+            err_bound,
+            provenance: Trail::NONE,
+        };
+        context::audit("round_to_increment", result, operands)
+    }
+}
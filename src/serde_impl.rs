@@ -14,6 +14,7 @@ use crate::core::ApproximationType;
 #[cfg(all(feature = "serde_str", not(feature = "serde_bin")))]
 mod str_impl {
     use super::*;
+    use crate::core::NumericValue;
     use serde::de::{SeqAccess, Visitor};
     use serde::ser::SerializeSeq;
     use serde::{Deserialize, Deserializer, Serialize, Serializer};
@@ -25,7 +26,11 @@ mod str_impl {
             S: Serializer,
         {
             // Serialize as array: ["value"] or ["value", "approx_type"]
-            let value_str = self.to_string();
+            // Display rounds a BigRational, so it's written as "n/d" for FromStr to read back exactly
+            let value_str = match &self.value {
+                NumericValue::BigRational(r) => format!("{}/{}", r.numer(), r.denom()),
+                _ => self.to_string(),
+            };
 
             match &self.apprx {
                 None => {
@@ -257,6 +262,7 @@ mod bin_impl {
                     Onum::from_number(ratio)
                 }
             }
+            NumericValue::BigRational(r) => Onum::from_number(r.clone()),
             NumericValue::Decimal(d) => {
                 // Convert Decimal to ratio: mantissa / 10^scale
                 let mantissa = d.mantissa();
@@ -317,6 +323,23 @@ mod tests {
             assert!(back.is_exact());
         }
 
+        #[test]
+        fn roundtrip_big_rational() {
+            let third = |d: i64| Number::from(1) / Number::from(d);
+            let original = third(3) * third(4_000_000_000) * third(3_000_000_000);
+            assert_eq!(original.representation(), crate::Repr::BigRational);
+            let json = serde_json::to_string(&original).unwrap();
+            assert_eq!(json, r#"["1/36000000000000000000"]"#);
+            let back: Number = serde_json::from_str(&json).unwrap();
+            assert_eq!(original, back);
+            assert!(back.is_exact());
+
+            let negative = -original * Number::from(7);
+            let json = serde_json::to_string(&negative).unwrap();
+            let back: Number = serde_json::from_str(&json).unwrap();
+            assert_eq!(negative, back);
+        }
+
         #[test]
         fn roundtrip_transcendental() {
             let original = Number::from(2).sqrt();
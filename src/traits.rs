@@ -3,8 +3,10 @@ use std::cmp::{Ordering, PartialEq, PartialOrd};
 use std::fmt::{Display, Formatter, Result as FmtResult};
 
 use std::hash::{Hash, Hasher};
+use std::num::IntErrorKind;
 
 use crate::Number;
+use crate::conversions::ParseNumberError;
 use crate::core::NumericValue;
 
 // num_traits for mathematical operations
@@ -19,6 +21,7 @@ impl Zero for Number {
     fn is_zero(&self) -> bool {
         match &self.value {
             NumericValue::Rational(r, _) => r.is_zero(),
+            NumericValue::BigRational(r) => r.is_zero(),
             NumericValue::Decimal(d) => d.is_zero(),
             NumericValue::BigDecimal(bd) => bd.is_zero(),
             NumericValue::NegativeZero => true,
@@ -58,6 +61,14 @@ impl Signed for Number {
                     -Number::one()
                 }
             }
+            // BigRational is never zero
+            NumericValue::BigRational(r) => {
+                if r.is_positive() {
+                    Number::one()
+                } else {
+                    -Number::one()
+                }
+            }
             NumericValue::Decimal(d) => {
                 if d.is_zero() {
                     Number::zero()
@@ -87,6 +98,7 @@ impl Signed for Number {
     fn is_positive(&self) -> bool {
         match &self.value {
             NumericValue::Rational(r, _) => r.is_positive(),
+            NumericValue::BigRational(r) => r.is_positive(),
             NumericValue::Decimal(d) => d.is_sign_positive(),
             NumericValue::BigDecimal(bd) => bd.is_positive(),
             NumericValue::NegativeZero => false, // -0 is not positive
@@ -98,6 +110,7 @@ impl Signed for Number {
     fn is_negative(&self) -> bool {
         match &self.value {
             NumericValue::Rational(r, _) => r.is_negative(),
+            NumericValue::BigRational(r) => r.is_negative(),
             NumericValue::Decimal(d) => d.is_sign_negative(),
             NumericValue::BigDecimal(bd) => bd.is_negative(),
             NumericValue::NegativeZero => true, // -0 is negative
@@ -108,22 +121,41 @@ impl Signed for Number {
 }
 
 impl Num for Number {
-    type FromStrRadixErr = ();
+    type FromStrRadixErr = ParseNumberError;
 
     fn from_str_radix(str: &str, radix: u32) -> Result<Self, Self::FromStrRadixErr> {
         // JavaScript's parseInt-like behavior
         if !(2..=36).contains(&radix) {
-            return Err(());
+            return Err(ParseNumberError::InvalidRadix(radix));
         }
 
         // Try to parse as i64 first, then fall back to f64 if needed
-        if let Ok(i) = i64::from_str_radix(str.trim(), radix) {
-            Ok(Number::from(i))
-        } else {
+        let trimmed = str.trim();
+        match i64::from_str_radix(trimmed, radix) {
+            Ok(i) => Ok(Number::from(i)),
             // For non-integer values or very large numbers, this is more complex
             // JavaScript parseInt has specific rules about parsing partial numbers
             // TODO: Implement full JavaScript parseInt semantics
-            Err(()) // Non-integer radix parsing not supported
+            Err(e) => Err(match e.kind() {
+                IntErrorKind::Empty => ParseNumberError::Empty,
+                IntErrorKind::PosOverflow | IntErrorKind::NegOverflow => ParseNumberError::Overflow,
+                _ => {
+                    // Positions count from the start of the untrimmed input
+                    let leading = str[..str.len() - str.trim_start().len()].chars().count();
+                    let sign = usize::from(trimmed.starts_with(['-', '+']));
+                    let (pos, ch) = trimmed
+                        .chars()
+                        .enumerate()
+                        .skip(sign)
+                        .find(|(_, c)| !c.is_digit(radix))
+                        // A lone sign
+                        .unwrap_or((0, trimmed.chars().next().expect("non-empty input")));
+                    ParseNumberError::InvalidDigit {
+                        pos: leading + pos,
+                        ch,
+                    }
+                }
+            }),
         }
     }
 }
@@ -138,6 +170,13 @@ impl ToPrimitive for Number {
                     None
                 }
             }
+            NumericValue::BigRational(r) => {
+                if r.is_integer() {
+                    r.to_integer().to_i64()
+                } else {
+                    None
+                }
+            }
             NumericValue::Decimal(d) => d.to_i64(),
             NumericValue::BigDecimal(bd) => bd.to_i64(),
             NumericValue::NegativeZero => Some(0),
@@ -154,6 +193,13 @@ impl ToPrimitive for Number {
                     None
                 }
             }
+            NumericValue::BigRational(r) => {
+                if r.is_integer() {
+                    r.to_integer().to_u64()
+                } else {
+                    None
+                }
+            }
             NumericValue::Decimal(d) => d.to_u64(),
             NumericValue::BigDecimal(bd) => bd.to_u64(),
             NumericValue::NegativeZero => Some(0),
@@ -194,6 +240,11 @@ impl Display for Number {
                     write!(f, "{}", decimal.normalize())
                 }
             }
+            NumericValue::BigRational(r) => {
+                // Usually non-terminating: show BigDecimal's default precision
+                use crate::representation::big_rational_to_bigdecimal;
+                write!(f, "{}", big_rational_to_bigdecimal(r))
+            }
             NumericValue::Decimal(d) => write!(f, "{}", d),
             NumericValue::BigDecimal(bd) => write!(f, "{}", bd),
             NumericValue::NegativeZero => write!(f, "0"), // -0 displays as "0"
@@ -218,50 +269,28 @@ impl Hash for Number {
             NumericValue::NegativeInfinity => {
                 2u8.hash(state);
             }
-            NumericValue::NegativeZero => {
-                // -0 == +0, so hash same as zero
-                3u8.hash(state);
-                0i64.hash(state);
-            }
             NumericValue::Rational(r, _) => {
-                if r.is_zero() {
-                    // Hash same as NegativeZero and Decimal(0)
-                    3u8.hash(state);
-                    0i64.hash(state);
-                } else {
-                    3u8.hash(state);
-                    // Rational is already in reduced form
-                    r.numer().hash(state);
-                    r.denom().hash(state);
-                }
-            }
-            NumericValue::Decimal(d) => {
-                if d.is_zero() {
-                    3u8.hash(state);
-                    0i64.hash(state);
-                } else {
-                    // Try to recover as Rational for consistent hashing with Rational values
-                    // e.g., Decimal(0.5) should hash same as Rational(1, 2)
-                    use crate::core::try_decimal_to_rational;
-                    if let Some(r) = try_decimal_to_rational(*d) {
-                        3u8.hash(state);
+                // Rational is already in reduced form
+                3u8.hash(state);
+                r.numer().hash(state);
+                r.denom().hash(state);
+            }
+            value => {
+                // Every other finite value (and -0, equal to +0) hashes through its reduced
+                // fraction, the same as the equal Rational when it fits one
+                let exact = value
+                    .to_big_rational()
+                    .expect("finite values have an exact rational");
+                3u8.hash(state);
+                match crate::representation::try_big_rational_to_rational64(&exact) {
+                    Some(r) => {
                         r.numer().hash(state);
                         r.denom().hash(state);
-                    } else {
-                        // Can't convert to rational - hash as normalized decimal string
-                        3u8.hash(state);
-                        d.normalize().hash(state);
                     }
-                }
-            }
-            NumericValue::BigDecimal(bd) => {
-                if bd.is_zero() {
-                    3u8.hash(state);
-                    0i64.hash(state);
-                } else {
-                    // BigDecimal doesn't impl Hash, use string representation
-                    3u8.hash(state);
-                    bd.to_string().hash(state);
+                    None => {
+                        exact.numer().hash(state);
+                        exact.denom().hash(state);
+                    }
                 }
             }
         }
@@ -335,6 +364,17 @@ impl PartialEq for Number {
             (NumericValue::BigDecimal(bd), NumericValue::NegativeZero)
             | (NumericValue::NegativeZero, NumericValue::BigDecimal(bd)) => bd.is_zero(),
 
+            // BigRational vs any finite value: compare exactly as BigRational
+            (NumericValue::BigRational(_), _) | (_, NumericValue::BigRational(_)) => {
+                match (
+                    self.value().to_big_rational(),
+                    other.value().to_big_rational(),
+                ) {
+                    (Some(a), Some(b)) => a == b,
+                    _ => false,
+                }
+            }
+
             // All other mixed-type comparisons are false
             _ => false,
         }
@@ -456,6 +496,13 @@ impl PartialOrd for Number {
             (_, NumericValue::NegativeInfinity) => Some(Ordering::Greater),
             (NumericValue::PositiveInfinity, _) => Some(Ordering::Greater),
             (_, NumericValue::PositiveInfinity) => Some(Ordering::Less),
+
+            // BigRational vs any finite value: compare exactly as BigRational
+            (NumericValue::BigRational(_), _) | (_, NumericValue::BigRational(_)) => {
+                let a = self.value().to_big_rational()?;
+                let b = other.value().to_big_rational()?;
+                a.partial_cmp(&b)
+            }
         }
     }
 }
@@ -580,6 +627,13 @@ impl Ord for Number {
                     Decimal::ZERO.cmp(a)
                 }
             }
+
+            // BigRational vs any finite value: compare exactly as BigRational
+            (NumericValue::BigRational(_), _) | (_, NumericValue::BigRational(_)) => {
+                let a = self.value().to_big_rational().expect("finite");
+                let b = other.value().to_big_rational().expect("finite");
+                a.cmp(&b)
+            }
         }
     }
 }
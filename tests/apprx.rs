@@ -189,7 +189,7 @@ fn test_mixed_approximation_types() {
 // PRECISION TESTS
 // ============================================================================
 
-#[cfg(feature = "high_precision")]
+#[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
 #[test]
 fn test_high_precision_sqrt() {
     Number::set_default_precision(200);
@@ -213,7 +213,7 @@ fn test_high_precision_sqrt() {
 }
 
 #[test]
-#[cfg(not(feature = "high_precision"))]
+#[cfg(not(any(feature = "high_precision", feature = "high_precision_pure")))]
 fn test_approximation_arithmetic_consistency() {
     // Test that arithmetic with approximations is consistent
     let sqrt_two = Number::from(2).sqrt();
@@ -230,7 +230,7 @@ fn test_approximation_arithmetic_consistency() {
 }
 
 #[test]
-#[cfg(feature = "high_precision")]
+#[cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
 fn test_approximation_arithmetic_consistency_high_precision() {
     // In high_precision mode, (√2)² produces a BigDecimal very close to 2
     let sqrt_two = Number::from(2).sqrt();
@@ -84,7 +84,7 @@ fn repeated_operations_preserve_value() {
     let a = Number::from(100);
     let b = Number::from(50);
 
-    let result = ((((a.clone() + b.clone()) - b.clone()) + b.clone()) - b);
+    let result = (((a.clone() + b.clone()) - b.clone()) + b.clone()) - b;
     assert_eq!(result, a);
 }
 
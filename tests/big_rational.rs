@@ -0,0 +1,150 @@
+//! Tests for the BigRational tier: exact promotion on i64 overflow and demotion back.
+
+mod common;
+use common::big;
+use faithful_number::{Number, OrderedNumber};
+use num_rational::Ratio;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+fn third() -> Number {
+    Number::from_rational(Ratio::new(1, 3))
+}
+
+fn overflowed() -> Number {
+    // i64::MAX + 1/3: numerator no longer fits in i64
+    Number::from(i64::MAX) + third()
+}
+
+#[test]
+fn overflow_promotes_to_big_rational() {
+    let result = overflowed();
+    assert_eq!(result.representation(), "BigRational");
+    assert!(result.is_exact());
+
+    let info = result.info();
+    assert_eq!(info.representation, "BigRational");
+    assert!(info.is_exact);
+    assert_eq!(info.approximation_type, None);
+}
+
+#[test]
+fn product_of_small_fractions_stays_exact() {
+    let a = Number::from_rational(Ratio::new(1, 4_000_000_000));
+    let b = Number::from_rational(Ratio::new(1, 3_000_000_000));
+    let product = third() * a.clone() * b.clone();
+
+    assert_eq!(product.representation(), "BigRational");
+    assert!(product.is_exact());
+
+    let recovered = product / a / b;
+    assert_eq!(recovered.representation(), "Rational");
+    assert_eq!(recovered.to_rational64(), Some(Ratio::new(1, 3)));
+}
+
+#[test]
+fn overflowing_quotient_of_terminating_values_stays_exact() {
+    // Both operands terminate, but 10/3 of i64::MAX does not
+    let three_tenths = Number::from_str("0.3").unwrap();
+    let result = Number::from(i64::MAX) / three_tenths.clone();
+    assert_eq!(result.representation(), "BigRational");
+    assert!(result.is_exact());
+    assert_eq!(result.error_bound(), Some(0.0));
+    assert_eq!(result * three_tenths, Number::from(i64::MAX));
+
+    // A terminating quotient still lands in the decimal tiers, exactly
+    let result = Number::from(i64::MAX) / Number::from_str("0.5").unwrap();
+    assert_eq!(result.representation(), "Decimal");
+    assert_eq!(result, Number::from(i64::MAX) * Number::from(2));
+}
+
+#[test]
+fn demotes_when_value_fits_again() {
+    let recovered = overflowed() - Number::from(i64::MAX);
+    assert_eq!(recovered.representation(), "Rational");
+    assert_eq!(recovered, third());
+}
+
+#[test]
+fn mixed_with_decimal_is_exact() {
+    // Non-terminating Rational with a Decimal goes through BigRational instead of rounding
+    let d: Number = "0.1234567890123456789012346".parse().unwrap();
+    assert_eq!(d.representation(), "Decimal");
+
+    let result = third() * d.clone();
+    assert_eq!(result.representation(), "BigRational");
+    assert!(result.is_exact());
+    assert_eq!(result * Number::from(3), d);
+}
+
+#[test]
+fn mixed_with_bigdecimal_is_exact() {
+    let b = big("123456789012345678901234567890.5");
+    assert_eq!(b.representation(), "BigDecimal");
+
+    for result in [
+        third() + b.clone(),
+        b.clone() - third(),
+        third() * b.clone(),
+        third() / b.clone(),
+    ] {
+        assert_eq!(result.representation(), "BigRational");
+        assert!(result.is_exact());
+    }
+    assert_eq!(third() + b.clone() - b.clone(), third());
+    assert_eq!(third() * b.clone() * Number::from(3), b);
+    assert_eq!(b.clone() / third(), b.clone() * Number::from(3));
+    assert_eq!(b.clone() % third(), Number::from_rational(Ratio::new(1, 6)));
+}
+
+#[test]
+fn negation_and_abs() {
+    let neg = -overflowed();
+    assert_eq!(neg.representation(), "BigRational");
+    assert!(neg < Number::from(i64::MIN + 1));
+    assert_eq!(neg.abs(), overflowed());
+}
+
+#[test]
+fn ordering_against_other_tiers() {
+    let big = overflowed();
+    assert!(big > Number::from(i64::MAX));
+    assert!(big < Number::from(i64::MAX) + Number::from(1));
+    assert!(big < Number::POSITIVE_INFINITY);
+    assert!(big > Number::NEGATIVE_INFINITY);
+    assert!(big.partial_cmp(&Number::NAN).is_none());
+}
+
+#[test]
+fn division_by_zero() {
+    assert!((overflowed() / Number::from(0)).is_positive_infinity());
+    assert!((-overflowed() / Number::from(0)).is_negative_infinity());
+    assert!((overflowed() % Number::from(0)).is_nan());
+}
+
+#[test]
+fn rounding_returns_integers() {
+    assert_eq!(overflowed().floor(), Number::from(i64::MAX));
+    assert_eq!(overflowed().trunc(), Number::from(i64::MAX));
+    assert_eq!(overflowed().round(), Number::from(i64::MAX));
+    assert_eq!(
+        overflowed().ceil(),
+        Number::from(i64::MAX) + Number::from(1)
+    );
+}
+
+#[test]
+fn equal_values_hash_equal() {
+    let mut set = HashSet::new();
+    set.insert(OrderedNumber::from(overflowed()));
+    set.insert(OrderedNumber::from(
+        Number::from(i64::MAX) + Number::from_rational(Ratio::new(2, 6)),
+    ));
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn display_shows_decimal_expansion() {
+    let s = overflowed().to_string();
+    assert!(s.starts_with("9223372036854775807.3333333333"), "{}", s);
+}
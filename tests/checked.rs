@@ -0,0 +1,138 @@
+//! Tests for checked arithmetic that refuses to lose exactness.
+
+mod common;
+use common::big;
+use faithful_number::{ApproximationType, ExactnessErrorKind, Number};
+use num_rational::Ratio;
+use std::str::FromStr;
+
+fn n(s: &str) -> Number {
+    Number::from_str(s).unwrap()
+}
+
+#[test]
+fn exact_operations_succeed() {
+    assert_eq!(n("0.1").checked_add(&n("0.2")).unwrap(), n("0.3"));
+    assert_eq!(
+        n("19.99").checked_mul(&Number::from(3)).unwrap(),
+        n("59.97")
+    );
+    assert_eq!(
+        Number::from(5).checked_sub(&Number::from(8)).unwrap(),
+        Number::from(-3)
+    );
+    assert_eq!(
+        Number::from(1).checked_div(&Number::from(3)).unwrap(),
+        Number::from_rational(Ratio::new(1, 3))
+    );
+    assert_eq!(
+        Number::from(7).checked_rem(&Number::from(4)).unwrap(),
+        Number::from(3)
+    );
+}
+
+#[test]
+fn overflow_stays_exact() {
+    let big = Number::from(i64::MAX);
+    let third = Number::from_rational(Ratio::new(1, 3));
+    assert!(big.checked_add(&third).unwrap().is_exact());
+    assert!(big.checked_mul(&big).unwrap().is_exact());
+}
+
+#[test]
+fn overflowing_division_is_never_truncated() {
+    // i64::MAX / 0.3 doesn't terminate, so it can't succeed as a decimal
+    let quotient = Number::from(i64::MAX).checked_div(&n("0.3")).unwrap();
+    assert_eq!(quotient.representation(), "BigRational");
+    assert_eq!(quotient * n("0.3"), Number::from(i64::MAX));
+
+    // Only a truncated BigDecimal could hold this quotient
+    let wide = big("1.000000000000000000000000000000000000001");
+    let err = wide.checked_div(&Number::from(3)).unwrap_err();
+    assert_eq!(err.op, "div");
+    assert_eq!(
+        err.kind,
+        ExactnessErrorKind::Approximation(ApproximationType::RationalApproximation)
+    );
+}
+
+#[test]
+fn sqrt_of_perfect_square_is_exact() {
+    assert_eq!(Number::from(16).checked_sqrt().unwrap(), Number::from(4));
+
+    let err = Number::from(2).checked_sqrt().unwrap_err();
+    assert_eq!(err.op, "sqrt");
+    assert_eq!(
+        err.kind,
+        ExactnessErrorKind::Approximation(ApproximationType::Transcendental)
+    );
+    assert_eq!(*err.lhs, Number::from(2));
+    assert_eq!(err.rhs, None);
+}
+
+#[test]
+fn pow_with_integer_exponent_is_exact() {
+    assert_eq!(
+        Number::from(2).checked_pow(&Number::from(10)).unwrap(),
+        Number::from(1024)
+    );
+    assert!(Number::from(2).checked_pow(&n("0.5")).is_err());
+}
+
+#[test]
+fn approximate_operand_is_reported() {
+    let root = Number::from(2).sqrt();
+    let err = root.checked_mul(&Number::from(3)).unwrap_err();
+    assert_eq!(err.op, "mul");
+    assert_eq!(
+        err.kind,
+        ExactnessErrorKind::Approximation(ApproximationType::Transcendental)
+    );
+    assert_eq!(*err.lhs, root);
+    assert_eq!(err.rhs.as_deref(), Some(&Number::from(3)));
+
+    let msg = err.to_string();
+    assert!(msg.starts_with("mul(1.41"), "{}", msg);
+    assert!(
+        msg.ends_with("would be approximate (Transcendental)"),
+        "{}",
+        msg
+    );
+}
+
+#[test]
+fn division_by_zero_is_an_error() {
+    let err = Number::from(1).checked_div(&Number::from(0)).unwrap_err();
+    assert_eq!(err.kind, ExactnessErrorKind::Infinity);
+
+    let err = Number::from(0).checked_div(&Number::from(0)).unwrap_err();
+    assert_eq!(err.kind, ExactnessErrorKind::NaN);
+
+    let err = Number::from(1).checked_rem(&Number::from(0)).unwrap_err();
+    assert_eq!(err.kind, ExactnessErrorKind::NaN);
+    assert_eq!(err.to_string(), "rem(1, 0) would be NaN");
+}
+
+#[test]
+fn non_finite_inputs_pass_through() {
+    let inf = Number::POSITIVE_INFINITY;
+    assert!(
+        inf.checked_add(&Number::from(1))
+            .unwrap()
+            .is_positive_infinity()
+    );
+    assert!(Number::NAN.checked_mul(&Number::from(2)).unwrap().is_nan());
+    assert!(inf.checked_sub(&inf).unwrap().is_nan());
+}
+
+#[test]
+fn works_with_question_mark() {
+    fn total(prices: &[&str], qty: i64) -> Result<Number, faithful_number::ExactnessError> {
+        let mut sum = Number::from(0);
+        for p in prices {
+            sum = sum.checked_add(&n(p).checked_mul(&Number::from(qty))?)?;
+        }
+        Ok(sum)
+    }
+    assert_eq!(total(&["1.10", "2.20"], 3).unwrap(), n("9.90"));
+}
@@ -0,0 +1,176 @@
+//! factorial, falling_factorial, binomial, gamma and ln_gamma.
+
+mod common;
+use common::rational;
+use faithful_number::{Number, Repr};
+
+fn close(x: &Number, expected: f64) -> bool {
+    (x.to_f64() - expected).abs() <= 1e-13 * expected.abs().max(1.0)
+}
+
+#[test]
+fn factorials_are_exact() {
+    assert_eq!(Number::from(0).factorial(), Number::from(1));
+    assert_eq!(Number::from(5).factorial(), Number::from(120));
+    assert_eq!(
+        Number::from(20).factorial(),
+        Number::from(2_432_902_008_176_640_000i64)
+    );
+
+    // Past i64: a BigDecimal integer, still exact
+    let f25 = Number::from(25).factorial();
+    assert_eq!(f25.to_string(), "15511210043330985984000000");
+    assert!(f25.is_exact());
+    let f100 = Number::from(100).factorial();
+    assert_eq!(f100.representation(), Repr::BigDecimal);
+    assert!(f100.is_exact());
+    assert_eq!(f100.to_string().trim_end_matches('0').len(), 134);
+
+    assert!(Number::from(-1).factorial().is_nan());
+    assert!(rational(1, 2).factorial().is_nan());
+}
+
+#[test]
+fn binomials_and_falling_factorials() {
+    assert_eq!(
+        Number::from(52).binomial(Number::from(5)),
+        Number::from(2_598_960)
+    );
+    assert_eq!(Number::from(5).binomial(Number::from(0)), Number::from(1));
+    assert_eq!(Number::from(5).binomial(Number::from(6)), Number::from(0));
+    assert_eq!(Number::from(5).binomial(Number::from(-1)), Number::from(0));
+    // Generalized: C(-1, k) = (-1)^k, C(1/2, 2) = -1/8
+    assert_eq!(Number::from(-1).binomial(Number::from(3)), Number::from(-1));
+    assert_eq!(rational(1, 2).binomial(Number::from(2)), rational(-1, 8));
+
+    // Symmetry keeps huge n with k near n cheap
+    let big = Number::from(1_000_000).binomial(Number::from(999_998));
+    assert_eq!(big, Number::from(499_999_500_000i64));
+
+    let c = Number::from(200).binomial(Number::from(100));
+    assert!(c.is_exact());
+    assert!(
+        c.to_string()
+            .starts_with("90548514656103281165404177077484163874504589675413336841320")
+    );
+
+    assert_eq!(
+        Number::from(10).falling_factorial(Number::from(3)),
+        Number::from(720)
+    );
+    assert_eq!(
+        rational(1, 2).falling_factorial(Number::from(3)),
+        rational(3, 8)
+    );
+    assert_eq!(
+        Number::from(7).falling_factorial(Number::from(0)),
+        Number::from(1)
+    );
+    assert!(Number::from(7).falling_factorial(Number::from(-1)).is_nan());
+    assert!(Number::from(7).binomial(rational(1, 2)).is_nan());
+}
+
+#[test]
+fn large_binomials() {
+    // 5 000 factors after symmetry: exact, and quick enough for a unit test
+    let c = Number::from(20_000).binomial(Number::from(15_000));
+    assert!(c.is_exact());
+    let s = c.to_string();
+    assert_eq!(s.len(), 4883);
+    assert!(
+        s.starts_with("156636277995780223349297889336"),
+        "{}",
+        &s[..30]
+    );
+
+    // Past 10 000 factors: from ln Γ like factorial, with a relative bound
+    let c = Number::from(30_000).binomial(Number::from(12_000));
+    assert!(c.is_finite() && c.is_transcendental(), "{:?}", c);
+    assert!(c.to_string().starts_with("1697606"), "{}", c);
+    let rel = c.relative_error_bound().unwrap();
+    assert!(rel > 0.0 && rel < 1e-6, "{}", rel);
+    let next = Number::from(30_000).binomial(Number::from(12_001));
+    let ratio = (next / c).to_f64();
+    assert!((ratio - 18_000.0 / 12_001.0).abs() < 1e-6, "{}", ratio);
+}
+
+#[test]
+fn gamma_exact_at_positive_integers() {
+    assert_eq!(Number::from(1).gamma(), Number::from(1));
+    assert_eq!(Number::from(6).gamma(), Number::from(120));
+    let g = Number::from(30).gamma();
+    assert!(g.is_exact());
+    assert_eq!(g, Number::from(29).factorial());
+
+    assert_eq!(Number::from(1).ln_gamma(), Number::from(0));
+    assert_eq!(Number::from(2).ln_gamma(), Number::from(0));
+    assert!(Number::from(2).ln_gamma().is_exact());
+}
+
+#[test]
+fn gamma_elsewhere_is_transcendental() {
+    let sqrt_pi = std::f64::consts::PI.sqrt();
+    let cases = [
+        (rational(1, 2), sqrt_pi),
+        (rational(5, 2), 0.75 * sqrt_pi),
+        (rational(-1, 2), -2.0 * sqrt_pi),
+        (rational(-3, 2), 4.0 / 3.0 * sqrt_pi),
+        (rational(1, 3), 2.678_938_534_707_747_6),
+        // Γ(0.1) / ((-0.9)(-1.9))
+        (rational(-19, 10), 9.513_507_698_668_732 / 1.71),
+    ];
+    for (x, expected) in cases {
+        let g = x.clone().gamma();
+        assert!(g.is_transcendental(), "gamma({})", x);
+        assert!(close(&g, expected), "gamma({}) = {}", x, g);
+        let bound = g.error_bound().unwrap();
+        assert!(bound > 0.0 && bound < 1e-11, "gamma({}) bound {}", x, bound);
+    }
+
+    let l = Number::from(200).ln_gamma();
+    assert!(close(&l, 857.933_669_825_857_5), "{}", l);
+    assert!(l.error_bound().unwrap() < 1e-9);
+    let l = rational(-5, 2).ln_gamma();
+    assert!(close(&l, (8.0 * std::f64::consts::PI.sqrt() / 15.0).ln()));
+}
+
+#[test]
+fn past_f64_range_stays_finite_and_approximate() {
+    // 10 001! has 35 664 digits: no longer computed exactly, but never an exact ∞
+    let f = Number::from(10_001).factorial();
+    assert!(f.is_finite() && f.is_transcendental(), "{:?}", f);
+    assert!(f.to_string().starts_with("2846544"), "{}", f);
+    // Its bound is kept relative, since the absolute one is past f64 as well
+    assert_eq!(f.error_bound(), Some(f64::INFINITY));
+    let rel = f.relative_error_bound().unwrap();
+    assert!(rel > 0.0 && rel < 1e-6, "{}", rel);
+    let exact = Number::from(10_000).factorial() * Number::from(10_001);
+    let actual = ((f.clone() - exact.clone()) / exact).abs();
+    assert!(actual.to_f64() <= rel, "{} > {}", actual, rel);
+    let ratio = f / Number::from(10_000).factorial();
+    assert!((ratio.to_f64() - 10_001.0).abs() < 1e-6, "{}", ratio);
+
+    // Γ(200.5) ≈ 5.57e373 through c·√π
+    let (g, below) = (rational(401, 2).gamma(), rational(399, 2).gamma());
+    assert!(g.is_finite() && g.is_transcendental(), "{:?}", g);
+    assert!(g.relative_error_bound().unwrap() < 1e-6);
+    assert!(close(&(g / below), 199.5));
+    assert!(Number::from(1000).gamma().is_exact());
+}
+
+#[test]
+fn gamma_special_values() {
+    assert_eq!(Number::from(0).gamma(), Number::POSITIVE_INFINITY);
+    assert_eq!(Number::neg_zero().gamma(), Number::NEGATIVE_INFINITY);
+    assert!(Number::from(-3).gamma().is_nan());
+    assert!(Number::NEGATIVE_INFINITY.gamma().is_nan());
+    assert_eq!(Number::POSITIVE_INFINITY.gamma(), Number::POSITIVE_INFINITY);
+
+    assert_eq!(Number::from(0).ln_gamma(), Number::POSITIVE_INFINITY);
+    assert_eq!(Number::from(-3).ln_gamma(), Number::POSITIVE_INFINITY);
+    assert_eq!(
+        Number::NEGATIVE_INFINITY.ln_gamma(),
+        Number::POSITIVE_INFINITY
+    );
+    assert!(Number::NAN.ln_gamma().is_nan());
+}
@@ -1,9 +1,26 @@
+#![allow(dead_code)]
+
 use faithful_number::{ApproximationType, Number};
 use num_rational::Ratio;
-use rust_decimal::Decimal;
+use std::str::FromStr;
 
 pub type Rational64 = Ratio<i64>;
 
+/// Exact `n/d`
+pub fn rational(n: i64, d: i64) -> Number {
+    Number::from_rational(Ratio::new(n, d))
+}
+
+/// A `Decimal`-backed number parsed from a decimal string
+pub fn decimal(s: &str) -> Number {
+    Number::from_decimal(rust_decimal::Decimal::from_str(s).unwrap())
+}
+
+/// A `BigDecimal`-backed number parsed from a decimal string
+pub fn big(s: &str) -> Number {
+    Number::from_bigdecimal(bigdecimal::BigDecimal::from_str(s).unwrap())
+}
+
 /// Core meta-test structure with #[track_caller] for proper panic location
 pub struct ArithmeticTestCase {
     pub name: &'static str,
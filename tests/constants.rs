@@ -0,0 +1,58 @@
+//! Number::PI and friends: values, flags, error bounds and requested digits.
+
+use faithful_number::{Constant, Number};
+
+const PI_60: &str = "3.14159265358979323846264338327950288419716939937510582097494";
+
+#[test]
+fn constants_are_transcendental_with_bounds() {
+    let cases = [
+        (Number::PI(), std::f64::consts::PI),
+        (Number::E(), std::f64::consts::E),
+        (Number::LN2(), std::f64::consts::LN_2),
+        (Number::LN10(), std::f64::consts::LN_10),
+        (Number::SQRT2(), std::f64::consts::SQRT_2),
+    ];
+    for (c, expected) in cases {
+        assert!(c.is_transcendental(), "{}", c);
+        assert!((c.to_f64() - expected).abs() <= 2.0 * f64::EPSILON, "{}", c);
+        let bound = c.error_bound().unwrap();
+        assert!(bound > 0.0 && bound < 1e-26, "{} bound {}", c, bound);
+    }
+}
+
+#[test]
+fn at_least_decimal_precision() {
+    // Better than f64 even without a high-precision feature
+    assert!(Number::PI().to_string().starts_with(&PI_60[..29]));
+    assert!(
+        Number::E()
+            .to_string()
+            .starts_with("2.718281828459045235360287471")
+    );
+}
+
+#[test]
+fn requested_digits() {
+    let pi = Number::constant_with_digits(Constant::Pi, 10);
+    assert_eq!(pi.to_string(), "3.141592654");
+    assert!(pi.is_transcendental());
+    assert!(pi.error_bound().unwrap() >= (pi.to_f64() - std::f64::consts::PI).abs());
+
+    assert_eq!(
+        Number::constant_with_digits(Constant::Sqrt2, 5).to_string(),
+        "1.4142"
+    );
+    assert_eq!(
+        Number::constant_with_digits(Constant::Ln2, 3).to_string(),
+        "0.693"
+    );
+}
+
+#[test]
+fn arithmetic_keeps_the_flag() {
+    let tau = Number::PI() * Number::from(2);
+    assert!(tau.is_transcendental());
+    assert!((tau.to_f64() - std::f64::consts::TAU).abs() < 1e-15);
+    assert!(tau.error_bound().unwrap() > 0.0);
+}
@@ -0,0 +1,129 @@
+//! Tests for the thread-local arithmetic context.
+
+use faithful_number::{
+    ApproximationPolicy, ApproximationType, ArithmeticContext, Number, approximation_flags,
+    get_arithmetic_context, take_approximation_flags,
+};
+use num_rational::Ratio;
+
+fn third() -> Number {
+    Number::from_rational(Ratio::new(1, 3))
+}
+
+#[test]
+fn default_context_allows_everything() {
+    assert_eq!(get_arithmetic_context(), ArithmeticContext::new());
+    let _ = Number::from(2).sqrt();
+    assert!(approximation_flags().is_clear());
+}
+
+#[test]
+fn exact_block_raises_nothing() {
+    let guard = ArithmeticContext::strict().enter();
+    let a = Number::from(7);
+    let b = third();
+    let c = Number::from(i64::MAX);
+    let result = a.clone() + b.clone() * c.clone() - a / b;
+    assert!(result.is_exact());
+    assert_eq!(Number::from(16).sqrt(), Number::from(4));
+    assert!(guard.flags().is_clear());
+}
+
+#[test]
+fn flag_policy_is_sticky() {
+    let guard = ArithmeticContext::uniform(ApproximationPolicy::Flag).enter();
+    let root = Number::from(2).sqrt();
+    let _ = Number::from(1) + Number::from(1);
+
+    let flags = guard.flags();
+    assert!(flags.transcendental);
+    assert!(!flags.rational_approximation);
+    assert!(flags.is_raised(&ApproximationType::Transcendental));
+
+    let first = flags.first.unwrap();
+    assert_eq!(first.op, "sqrt");
+    assert_eq!(*first.lhs, Number::from(2));
+
+    // Propagating the approximate value keeps the first report
+    let _ = root * Number::from(3);
+    assert_eq!(guard.flags().first.unwrap().op, "sqrt");
+}
+
+#[test]
+#[should_panic(expected = "mul(")]
+fn panic_policy_on_operator() {
+    let root = Number::from(2).sqrt();
+    let _guard = ArithmeticContext::strict().enter();
+    let _ = root * Number::from(3);
+}
+
+#[test]
+#[should_panic(expected = "exp(1) would be approximate (Transcendental)")]
+fn panic_policy_on_math_function() {
+    let _guard = ArithmeticContext::strict().enter();
+    let _ = Number::from(1).exp();
+}
+
+#[test]
+fn policy_per_approximation_type() {
+    let ctx = ArithmeticContext::strict().with_policy(
+        ApproximationType::Transcendental,
+        ApproximationPolicy::Allow,
+    );
+    assert_eq!(
+        ctx.policy_for(&ApproximationType::RationalApproximation),
+        ApproximationPolicy::Panic
+    );
+
+    let guard = ctx.enter();
+    let _ = Number::from(2).sqrt().sin();
+    assert!(guard.flags().is_clear());
+}
+
+#[test]
+fn guard_restores_previous_context() {
+    let outer = ArithmeticContext::uniform(ApproximationPolicy::Flag).enter();
+    {
+        let _inner = ArithmeticContext::strict().enter();
+        assert_eq!(get_arithmetic_context(), ArithmeticContext::strict());
+    }
+    assert_eq!(
+        get_arithmetic_context(),
+        ArithmeticContext::uniform(ApproximationPolicy::Flag)
+    );
+    drop(outer);
+    assert_eq!(get_arithmetic_context(), ArithmeticContext::new());
+}
+
+#[test]
+fn inner_flags_merge_into_outer_scope() {
+    let outer = ArithmeticContext::uniform(ApproximationPolicy::Flag).enter();
+    {
+        let inner = ArithmeticContext::uniform(ApproximationPolicy::Flag).enter();
+        let _ = Number::from(3).log();
+        assert!(inner.flags().transcendental);
+    }
+    assert!(outer.flags().transcendental);
+    drop(outer);
+
+    assert!(take_approximation_flags().transcendental);
+    assert!(approximation_flags().is_clear());
+}
+
+#[test]
+fn checked_calls_ignore_the_context() {
+    let _guard = ArithmeticContext::strict().enter();
+    assert!(Number::from(2).checked_sqrt().is_err());
+    assert!(Number::from(2).checked_pow(&Number::from(3)).is_ok());
+}
+
+#[test]
+fn assignment_operators_are_audited() {
+    let root = Number::from(2).sqrt();
+    let guard = ArithmeticContext::uniform(ApproximationPolicy::Flag).enter();
+    let mut x = Number::from(10);
+    x /= Number::from(4);
+    assert!(guard.flags().is_clear());
+    x *= root;
+    assert_eq!(guard.flags().first.unwrap().op, "mul");
+}
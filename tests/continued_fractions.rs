@@ -1,6 +1,11 @@
-//! Adversarial tests for continued fractions algorithm.
-
-use faithful_number::Number;
+//! Adversarial tests for continued fractions algorithm, and the
+//! continued_fraction, convergents and limit_denominator API built on it.
+
+mod common;
+use bigdecimal::BigDecimal;
+use common::rational;
+use faithful_number::{Number, Repr};
+use rust_decimal::Decimal;
 use std::str::FromStr;
 
 #[test]
@@ -91,3 +96,127 @@ fn exact_decimal_recovery() {
     // Should be exact (no approximation flag)
     assert!(exact.is_exact());
 }
+
+// ============================================================================
+// PUBLIC API
+// ============================================================================
+
+fn ints(terms: &[Number]) -> Vec<i64> {
+    terms.iter().map(|t| t.to_i64().unwrap()).collect()
+}
+
+#[test]
+fn terms_of_exact_values() {
+    assert_eq!(
+        ints(&rational(415, 93).continued_fraction(10)),
+        [4, 2, 6, 7]
+    );
+    assert_eq!(ints(&rational(415, 93).continued_fraction(2)), [4, 2]);
+    // Negative values start with the floor
+    assert_eq!(ints(&rational(-7, 3).continued_fraction(10)), [-3, 1, 2]);
+    assert_eq!(ints(&Number::from(5).continued_fraction(10)), [5]);
+    assert_eq!(ints(&Number::neg_zero().continued_fraction(10)), [0]);
+
+    let d = Number::from_decimal(Decimal::from_str("3.245").unwrap());
+    assert_eq!(ints(&d.continued_fraction(10)), [3, 4, 12, 4]);
+    assert!(rational(1, 3).continued_fraction(10)[1].is_exact());
+
+    assert!(Number::NAN.continued_fraction(5).is_empty());
+    assert!(Number::POSITIVE_INFINITY.convergents().is_empty());
+}
+
+#[test]
+fn terms_of_approximate_values() {
+    let root2 = Number::from(2).sqrt();
+    let terms = root2.continued_fraction(12);
+    assert_eq!(ints(&terms), [1, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2, 2]);
+    assert!(terms.iter().all(|t| t.is_transcendental()));
+}
+
+#[test]
+fn convergents_end_at_the_value() {
+    let x = rational(415, 93);
+    let c = x.convergents();
+    assert_eq!(
+        c,
+        [
+            Number::from(4),
+            rational(9, 2),
+            rational(58, 13),
+            rational(415, 93)
+        ]
+    );
+    assert!(c[..3].iter().all(|c| c.is_rational_approximation()));
+    assert!(c[3].is_exact());
+    // 9/2 is 7/186 away
+    let bound = c[1].error_bound().unwrap();
+    assert!((7.0 / 186.0..7.01 / 186.0).contains(&bound));
+
+    let root2 = Number::from(2).sqrt().convergents();
+    assert_eq!(root2[5], rational(99, 70));
+    assert!(
+        root2
+            .iter()
+            .all(|c| c.is_rational_approximation() || c.is_transcendental())
+    );
+}
+
+#[test]
+fn limit_denominator_matches_python() {
+    let root2 = Number::from(2).sqrt();
+    let cases = [
+        (1, rational(1, 1)),
+        (10, rational(7, 5)),
+        // The semiconvergent 140/99 beats the convergent 99/70
+        (100, rational(140, 99)),
+        (1000, rational(1393, 985)),
+        (1_000_000, rational(941_664, 665_857)),
+    ];
+    for (max_denom, expected) in cases {
+        let approx = root2.clone().limit_denominator(max_denom);
+        assert_eq!(approx, expected, "max_denom {}", max_denom);
+        assert!(approx.is_rational_approximation());
+        let distance = (approx.clone() - root2.clone()).abs().to_f64();
+        assert!(approx.error_bound().unwrap() >= distance);
+    }
+
+    let pi = Number::from_str("3.1415926535897932").unwrap();
+    assert_eq!(pi.clone().limit_denominator(10), rational(22, 7));
+    assert_eq!(pi.clone().limit_denominator(100), rational(311, 99));
+    assert_eq!(pi.limit_denominator(1000), rational(355, 113));
+
+    // Ties go to the convergent
+    assert_eq!(rational(5, 2).limit_denominator(1), Number::from(2));
+    assert_eq!(rational(-5, 2).limit_denominator(1), Number::from(-3));
+    assert_eq!(rational(-7, 3).limit_denominator(2), rational(-5, 2));
+}
+
+#[test]
+fn limit_denominator_in_decimal_tiers() {
+    let d = Number::from_decimal(Decimal::from_str("0.3333333333333333333333333334").unwrap());
+    assert_eq!(d.representation(), Repr::Decimal);
+    let third = d.limit_denominator(100);
+    assert_eq!(third, rational(1, 3));
+    assert!(third.is_rational_approximation());
+
+    let big = Number::from_bigdecimal(
+        BigDecimal::from_str("0.14285714285714285714285714285714285714285714").unwrap(),
+    );
+    assert_eq!(big.representation(), Repr::BigDecimal);
+    assert_eq!(big.limit_denominator(1000), rational(1, 7));
+}
+
+#[test]
+fn limit_denominator_keeps_exact_matches() {
+    let x = rational(3, 8);
+    let same = x.clone().limit_denominator(8);
+    assert_eq!(same, x);
+    assert!(same.is_exact());
+
+    let d = Number::from_str("0.125").unwrap();
+    assert!(d.limit_denominator(1000).is_exact());
+    assert!(Number::neg_zero().limit_denominator(5).is_neg_zero());
+    assert!(Number::NAN.limit_denominator(5).is_nan());
+    // A limit below 1 is treated as 1
+    assert_eq!(rational(7, 3).limit_denominator(0), Number::from(2));
+}
@@ -50,13 +50,12 @@ fn non_terminating_small() {
     assert!(result.is_exact()); // Exact representation
 }
 
-/// Test large non-terminating rational preservation of flag
+/// Test large non-terminating rational stays exact
 ///
-/// Current behavior: Promotes to Decimal with RationalApproximation flag
-/// Desired behavior: Should promote to BigDecimal with RationalApproximation flag
+/// Overflowing i64 with a non-terminating value promotes to BigRational (exact)
 #[test]
-fn non_terminating_large_preserves_flag() {
-    // Large non-terminating: BigDecimal with flag
+fn non_terminating_large_stays_exact() {
+    // Large non-terminating: BigRational, no flag
     let third = Number::from_rational(Ratio::new(1, 3));
     let large = Number::from(i64::MAX);
     let result = large + third;
@@ -70,10 +69,8 @@ fn non_terminating_large_preserves_flag() {
         result.is_rational_approximation()
     );
 
-    // CURRENT: Will be Decimal with RationalApproximation
-    // DESIRED: Should be BigDecimal with RationalApproximation
-    assert_eq!(result.representation(), "BigDecimal");
-    assert!(result.is_rational_approximation());
+    assert_eq!(result.representation(), "BigRational");
+    assert!(result.is_exact());
 }
 
 /// Test that magnitude prevents wasteful conversion attempts
@@ -164,44 +161,28 @@ fn transcendental_clears_rational_flag() {
     assert!(!result.is_rational_approximation());
 }
 
-/// Test flag propagates through arithmetic operations
+/// Test BigRational stays exact through arithmetic operations
 ///
-/// Current behavior: Flag propagates through operations
-/// Desired behavior: Same
+/// Results that still overflow i64 stay BigRational; no flag is ever set
 #[test]
-fn flag_propagates_through_arithmetic() {
+fn big_rational_stays_exact_through_arithmetic() {
     let max = Number::from(i64::MAX);
     let third = Number::from_rational(Ratio::new(1, 3));
     let large = max + third;
 
-    println!(
-        "Initial is_rational_approximation: {}",
-        large.is_rational_approximation()
-    );
+    assert_eq!(large.representation(), "BigRational");
 
-    // Addition should preserve the flag
     let result = large.clone() + Number::from(1000);
-    println!(
-        "After +1000 - is_rational_approximation: {}",
-        result.is_rational_approximation()
-    );
-    assert!(result.is_rational_approximation());
+    assert_eq!(result.representation(), "BigRational");
+    assert!(result.is_exact());
 
-    // Subtraction should preserve the flag
     let result = large.clone() - Number::from(1000);
-    println!(
-        "After -1000 - is_rational_approximation: {}",
-        result.is_rational_approximation()
-    );
-    assert!(result.is_rational_approximation());
+    assert_eq!(result.representation(), "BigRational");
+    assert!(result.is_exact());
 
-    // Multiplication should preserve the flag
     let result = large.clone() * Number::from(2);
-    println!(
-        "After *2 - is_rational_approximation: {}",
-        result.is_rational_approximation()
-    );
-    assert!(result.is_rational_approximation());
+    assert_eq!(result.representation(), "BigRational");
+    assert!(result.is_exact());
 }
 
 /// Test exact rational equality after operations
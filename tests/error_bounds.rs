@@ -0,0 +1,99 @@
+//! Tests for error-bound tracking on approximate Numbers.
+
+use bigdecimal::BigDecimal;
+use faithful_number::Number;
+use std::str::FromStr;
+
+// Reference values to 80 digits (more than the default high-precision setting delivers)
+const SQRT_6: &str =
+    "2.4494897427831780981972840747058913919659474806566701284326925672509603774573150";
+const E: &str = "2.7182818284590452353602874713526624977572470936999595749669676277240766303535476";
+const E_SQUARED: &str =
+    "7.3890560989306502272304274605750078131803155705518473240871278225225737960790578";
+const PI_OVER_4: &str =
+    "0.78539816339744830961566084581987572104929234984377645524373614807695410157155225";
+
+#[track_caller]
+fn assert_within_bound(approx: &Number, reference: &str) {
+    let reference = Number::from_bigdecimal(BigDecimal::from_str(reference).unwrap());
+    let bound = approx.error_bound().expect("bound should be tracked");
+    let actual = (approx.clone() - reference).abs().to_f64();
+    assert!(
+        actual <= bound,
+        "error {} exceeds bound {} for {}",
+        actual,
+        bound,
+        approx
+    );
+}
+
+#[test]
+fn exact_numbers_have_zero_bound() {
+    assert_eq!(Number::from(5).error_bound(), Some(0.0));
+    assert_eq!(Number::from_str("0.1").unwrap().error_bound(), Some(0.0));
+    assert_eq!((Number::from(1) / Number::from(3)).error_bound(), Some(0.0));
+}
+
+#[test]
+fn transcendentals_seed_a_bound() {
+    let e = Number::from(1).exp();
+    assert!(e.is_transcendental());
+    assert_within_bound(&e, E);
+
+    let quarter_pi = Number::from(1).atan();
+    assert_within_bound(&quarter_pi, PI_OVER_4);
+}
+
+#[test]
+fn bound_propagates_through_multiplication() {
+    let x = Number::from(2).sqrt() * Number::from(3).sqrt();
+    assert_within_bound(&x, SQRT_6);
+
+    let e = Number::from(1).exp();
+    let e_squared = e.clone() * e;
+    assert_within_bound(&e_squared, E_SQUARED);
+}
+
+#[test]
+fn bound_propagates_through_addition_and_division() {
+    let e = Number::from(1).exp();
+    let sum = e.clone() + e.clone() + Number::from(1);
+    let bound_sum = sum.error_bound().unwrap();
+    assert!(bound_sum >= 2.0 * e.error_bound().unwrap());
+
+    let half = e.clone() / Number::from(2);
+    assert!(half.error_bound().unwrap() > 0.0);
+    assert!(half.error_bound().unwrap() < e.error_bound().unwrap());
+}
+
+#[test]
+#[cfg(not(any(feature = "high_precision", feature = "high_precision_pure")))]
+fn division_by_interval_containing_zero_is_unbounded() {
+    // sin of a huge argument: the bound exceeds the value itself
+    let zeroish = Number::from(1_000_000_000_000_000i64).sin();
+    assert!(zeroish.error_bound().unwrap() > zeroish.to_f64().abs());
+    let result = Number::from(1) / zeroish;
+    assert_eq!(result.error_bound(), Some(f64::INFINITY));
+}
+
+#[test]
+fn remainder_drops_the_bound() {
+    let r = Number::from(2).sqrt() % Number::from(1);
+    assert!(!r.is_exact());
+    assert_eq!(r.error_bound(), None);
+}
+
+#[test]
+fn rounding_clears_the_bound() {
+    let r = Number::from(2).sqrt().round_dp(3);
+    assert!(r.is_exact());
+    assert_eq!(r.error_bound(), Some(0.0));
+}
+
+#[test]
+fn relative_bound() {
+    let e = Number::from(1).exp();
+    let rel = e.relative_error_bound().unwrap();
+    assert!(rel > 0.0 && rel < 1e-14);
+    assert_eq!(Number::from(0).relative_error_bound(), None);
+}
@@ -0,0 +1,105 @@
+//! pow with rational exponents stays exact for perfect powers.
+
+mod common;
+use bigdecimal::num_bigint::BigInt;
+use common::{decimal, rational};
+use faithful_number::Number;
+use num_rational::BigRational;
+
+#[test]
+fn perfect_powers_are_exact() {
+    let cases = vec![
+        ("8^(2/3)", Number::from(8), rational(2, 3), Number::from(4)),
+        (
+            "(27/8)^(1/3)",
+            rational(27, 8),
+            rational(1, 3),
+            rational(3, 2),
+        ),
+        ("4^(-1/2)", Number::from(4), rational(-1, 2), rational(1, 2)),
+        (
+            "(9/4)^(3/2)",
+            rational(9, 4),
+            rational(3, 2),
+            rational(27, 8),
+        ),
+        (
+            "32^(-3/5)",
+            Number::from(32),
+            rational(-3, 5),
+            rational(1, 8),
+        ),
+        ("(1/3)^2", rational(1, 3), Number::from(2), rational(1, 9)),
+        (
+            "(-2/3)^3",
+            rational(-2, 3),
+            Number::from(3),
+            rational(-8, 27),
+        ),
+        ("(2/3)^-2", rational(2, 3), Number::from(-2), rational(9, 4)),
+        (
+            "16^0.75",
+            Number::from(16),
+            decimal("0.75"),
+            Number::from(8),
+        ),
+        ("2.25^0.5", decimal("2.25"), decimal("0.5"), rational(3, 2)),
+        ("0^(1/3)", Number::from(0), rational(1, 3), Number::from(0)),
+        ("1^(7/9)", Number::from(1), rational(7, 9), Number::from(1)),
+    ];
+
+    for (name, base, exponent, expected) in cases {
+        let result = base.pow(exponent);
+        assert_eq!(result, expected, "{}", name);
+        assert!(result.is_exact(), "{} should be exact", name);
+    }
+}
+
+#[test]
+fn big_rational_bases() {
+    // (10^30 / 7^3)^(1/3) = 10^10 / 7
+    let base = Number::from_big_rational(BigRational::new(
+        BigInt::from(10).pow(30),
+        BigInt::from(343),
+    ));
+    let result = base.pow(rational(1, 3));
+    assert_eq!(result, rational(10_000_000_000, 7));
+    assert!(result.is_exact());
+}
+
+#[test]
+fn irrational_results_stay_transcendental() {
+    let cases = vec![
+        ("2^(1/2)", Number::from(2), rational(1, 2)),
+        ("9^(1/3)", Number::from(9), rational(1, 3)),
+        ("(27/7)^(1/3)", rational(27, 7), rational(1, 3)),
+        ("8^(1/2)", Number::from(8), rational(1, 2)),
+        ("(4/3)^(1/2)", rational(4, 3), rational(1, 2)),
+        ("3^0.5", Number::from(3), decimal("0.5")),
+    ];
+
+    for (name, base, exponent) in cases {
+        let result = base.pow(exponent);
+        assert!(result.is_transcendental(), "{} should be approximate", name);
+    }
+}
+
+#[test]
+fn negative_bases_with_fractional_exponents_are_nan() {
+    assert!(Number::from(-8).pow(rational(1, 3)).is_nan());
+}
+
+#[test]
+fn approximate_operands_keep_their_flag() {
+    let root = Number::from(2).sqrt();
+    assert!(root.pow(Number::from(2)).is_transcendental());
+}
+
+#[test]
+fn checked_pow_accepts_perfect_powers() {
+    assert_eq!(
+        Number::from(8).checked_pow(&rational(2, 3)).unwrap(),
+        Number::from(4)
+    );
+    assert!(Number::from(2).checked_pow(&rational(1, 3)).is_err());
+}
@@ -0,0 +1,112 @@
+//! Transcendental functions return exact results at exactly-representable points.
+
+mod common;
+use common::{decimal, rational};
+use faithful_number::{Number, Repr};
+
+type UnaryFn = fn(Number) -> Number;
+
+#[test]
+fn exact_points() {
+    let cases: Vec<(&str, UnaryFn, Number, Number)> = vec![
+        ("log(1)", Number::log, Number::from(1), Number::from(0)),
+        ("log(1.0)", Number::log, decimal("1.0"), Number::from(0)),
+        ("log10(1)", Number::log10, Number::from(1), Number::from(0)),
+        (
+            "log10(1000)",
+            Number::log10,
+            Number::from(1000),
+            Number::from(3),
+        ),
+        (
+            "log10(0.001)",
+            Number::log10,
+            decimal("0.001"),
+            Number::from(-3),
+        ),
+        (
+            "log10(1/100)",
+            Number::log10,
+            rational(1, 100),
+            Number::from(-2),
+        ),
+        (
+            "log10(10^18)",
+            Number::log10,
+            Number::from(1_000_000_000_000_000_000i64),
+            Number::from(18),
+        ),
+        ("log2(1)", Number::log2, Number::from(1), Number::from(0)),
+        ("log2(8)", Number::log2, Number::from(8), Number::from(3)),
+        ("log2(1/4)", Number::log2, rational(1, 4), Number::from(-2)),
+        (
+            "log2(0.125)",
+            Number::log2,
+            decimal("0.125"),
+            Number::from(-3),
+        ),
+        (
+            "log2(2^62)",
+            Number::log2,
+            Number::from(1i64 << 62),
+            Number::from(62),
+        ),
+        ("exp(0)", Number::exp, Number::from(0), Number::from(1)),
+        ("exp(0.0)", Number::exp, decimal("0.0"), Number::from(1)),
+        ("sin(0)", Number::sin, Number::from(0), Number::from(0)),
+        ("cos(0)", Number::cos, Number::from(0), Number::from(1)),
+        ("tan(0)", Number::tan, Number::from(0), Number::from(0)),
+        ("asin(0)", Number::asin, Number::from(0), Number::from(0)),
+        ("acos(1)", Number::acos, Number::from(1), Number::from(0)),
+        ("atan(0)", Number::atan, Number::from(0), Number::from(0)),
+    ];
+
+    for (name, f, input, expected) in cases {
+        let result = f(input);
+        assert_eq!(result, expected, "{}", name);
+        assert!(result.is_exact(), "{} should be exact", name);
+        assert_eq!(result.representation(), Repr::Rational, "{}", name);
+        assert_eq!(result.error_bound(), Some(0.0), "{}", name);
+    }
+}
+
+#[test]
+fn atan2_on_the_positive_axis() {
+    for x in [Number::from(1), rational(1, 3), decimal("2.5")] {
+        let result = Number::from(0).atan2(x);
+        assert_eq!(result, Number::from(0));
+        assert!(result.is_exact());
+    }
+}
+
+#[test]
+fn irrational_points_stay_transcendental() {
+    let cases: Vec<(&str, UnaryFn, Number)> = vec![
+        ("log(2)", Number::log, Number::from(2)),
+        ("log10(2)", Number::log10, Number::from(2)),
+        ("log10(20)", Number::log10, Number::from(20)),
+        ("log10(1/3)", Number::log10, rational(1, 3)),
+        ("log2(6)", Number::log2, Number::from(6)),
+        ("log2(3/4)", Number::log2, rational(3, 4)),
+        ("exp(1)", Number::exp, Number::from(1)),
+        ("sin(1)", Number::sin, Number::from(1)),
+        ("cos(1)", Number::cos, Number::from(1)),
+        ("acos(0)", Number::acos, Number::from(0)),
+        ("atan(1)", Number::atan, Number::from(1)),
+    ];
+
+    for (name, f, input) in cases {
+        let result = f(input);
+        assert!(result.is_transcendental(), "{} should be approximate", name);
+    }
+    assert!(Number::from(0).atan2(Number::from(-1)).is_transcendental());
+}
+
+#[test]
+fn approximate_inputs_stay_approximate() {
+    // cos of a tiny argument rounds to 1 (in f64), but is still approximate
+    let almost_one = decimal("0.00000000000000000001").cos().demote();
+    assert!(almost_one.is_transcendental());
+    assert!(almost_one.clone().log().is_transcendental());
+    assert!(almost_one.log10().is_transcendental());
+}
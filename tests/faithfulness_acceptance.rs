@@ -79,6 +79,7 @@ fn ceil_large_rational_no_f64_overflow() {
 }
 
 #[test]
+#[cfg(not(feature = "js_rounding"))]
 fn round_exact_half_away_from_zero() {
     // 3/2 = 1.5 should round to 2 (away from zero)
     let n = Number::from(3) / Number::from(2);
@@ -0,0 +1,110 @@
+//! expm1, log1p, hypot and mul_add: small-argument accuracy, exact cases, single rounding.
+
+mod common;
+use common::{decimal, rational};
+use faithful_number::{Number, Repr};
+
+#[test]
+fn expm1_and_log1p_keep_small_arguments() {
+    let x = decimal("0.00000000000000000001");
+
+    let e = x.clone().expm1();
+    assert!(e.is_transcendental());
+    assert!((e.to_f64() / 1e-20 - 1.0).abs() < 1e-15, "{}", e);
+
+    let l = x.log1p();
+    assert!(l.is_transcendental());
+    assert!((l.to_f64() / 1e-20 - 1.0).abs() < 1e-15, "{}", l);
+
+    let y = rational(-1, 3).expm1();
+    assert!((y.to_f64() - (-1f64 / 3.0).exp_m1()).abs() < 1e-15);
+    assert!(y.error_bound().unwrap() > 0.0);
+}
+
+#[test]
+fn expm1_and_log1p_special_values() {
+    assert_eq!(Number::from(0).expm1(), Number::from(0));
+    assert!(Number::from(0).expm1().is_exact());
+    assert_eq!(Number::from(0).log1p(), Number::from(0));
+    assert!(Number::from(0).log1p().is_exact());
+    assert!(Number::neg_zero().expm1().is_neg_zero());
+    assert!(Number::neg_zero().log1p().is_neg_zero());
+
+    assert_eq!(Number::NEGATIVE_INFINITY.expm1(), Number::from(-1));
+    assert_eq!(Number::POSITIVE_INFINITY.expm1(), Number::POSITIVE_INFINITY);
+    assert_eq!(Number::POSITIVE_INFINITY.log1p(), Number::POSITIVE_INFINITY);
+    assert_eq!(Number::from(-1).log1p(), Number::NEGATIVE_INFINITY);
+    assert!(Number::from(-2).log1p().is_nan());
+    assert!(Number::NAN.expm1().is_nan());
+}
+
+#[test]
+fn hypot_of_perfect_squares_is_exact() {
+    let cases = vec![
+        (Number::from(3), Number::from(4), Number::from(5)),
+        (Number::from(-5), Number::from(12), Number::from(13)),
+        (rational(3, 5), rational(4, 5), Number::from(1)),
+        (decimal("0.3"), decimal("0.4"), decimal("0.5")),
+        (Number::from(0), Number::from(-7), Number::from(7)),
+    ];
+
+    for (a, b, expected) in cases {
+        let h = a.clone().hypot(b.clone());
+        assert_eq!(h, expected, "hypot({}, {})", a, b);
+        assert!(h.is_exact(), "hypot({}, {})", a, b);
+    }
+}
+
+#[test]
+fn hypot_otherwise_transcendental() {
+    let h = Number::from(1).hypot(Number::from(1));
+    assert!(h.is_transcendental());
+    assert!((h.to_f64() - std::f64::consts::SQRT_2).abs() < 1e-15);
+    assert!(h.error_bound().unwrap() > 0.0);
+
+    assert_eq!(
+        Number::NAN.hypot(Number::NEGATIVE_INFINITY),
+        Number::POSITIVE_INFINITY
+    );
+    assert!(Number::NAN.hypot(Number::from(1)).is_nan());
+}
+
+#[test]
+fn mul_add_is_exact_for_exact_inputs() {
+    let r = rational(1, 3).mul_add(Number::from(3), rational(-1, 2));
+    assert_eq!(r, rational(1, 2));
+    assert!(r.is_exact());
+
+    // The separate product rounds to 28 digits in Decimal; mul_add does not
+    let a = decimal("1.000000000000000000000000001");
+    let separate = a.clone() * a.clone() - Number::from(1);
+    let fused = a.clone().mul_add(a, Number::from(-1));
+    assert_eq!(separate.representation(), Repr::Decimal);
+    assert_eq!(fused.to_string(), "2.000000000000000000000000001E-27");
+    assert_ne!(fused, separate);
+    assert!(fused.is_exact());
+}
+
+#[test]
+fn mul_add_propagates_approximation() {
+    let root2 = Number::from(2).sqrt();
+    let r = root2.clone().mul_add(root2, Number::from(-2));
+    assert!(r.is_transcendental());
+    assert!(r.to_f64().abs() < 1e-14);
+    assert!(r.error_bound().unwrap() >= r.to_f64().abs());
+
+    assert!(
+        Number::NAN
+            .mul_add(Number::from(1), Number::from(1))
+            .is_nan()
+    );
+    assert_eq!(
+        Number::from(2).mul_add(Number::POSITIVE_INFINITY, Number::from(1)),
+        Number::POSITIVE_INFINITY
+    );
+    assert!(
+        Number::neg_zero()
+            .mul_add(Number::from(1), Number::neg_zero())
+            .is_neg_zero()
+    );
+}
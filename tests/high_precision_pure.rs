@@ -0,0 +1,178 @@
+#![cfg(all(feature = "high_precision_pure", not(feature = "high_precision")))]
+
+//! Digit-level checks for the pure-Rust BigDecimal transcendentals.
+
+mod common;
+use common::big;
+use faithful_number::Number;
+
+const PI_60: &str = "3.14159265358979323846264338327950288419716939937510582097494";
+const E_60: &str = "2.71828182845904523536028747135266249775724709369995957496696";
+const LN2_60: &str = "0.693147180559945309417232121458176568075500134360255254120680";
+
+#[test]
+fn matches_known_constants() {
+    Number::set_default_precision(256);
+    let pi = Number::from(1).atan() * Number::from(4);
+    assert!(pi.to_string().starts_with(PI_60), "{}", pi);
+    assert!(Number::from(1).exp().to_string().starts_with(E_60));
+    assert!(Number::from(2).log().to_string().starts_with(LN2_60));
+    assert!(Number::from(-1).acos().to_string().starts_with(PI_60));
+}
+
+#[test]
+fn honours_default_precision() {
+    Number::set_default_precision(100);
+    let short = Number::from(3).sqrt().to_string();
+    Number::set_default_precision(1000);
+    let long = Number::from(3).sqrt().to_string();
+    Number::set_default_precision(256);
+
+    // 100 bits ≈ 31 digits, 1000 bits ≈ 302 digits
+    assert_eq!(short.len(), 32);
+    assert_eq!(long.len(), 303);
+    assert!(long.starts_with(&short[..30]));
+}
+
+#[test]
+fn keeps_relative_precision_for_small_results() {
+    Number::set_default_precision(256);
+    // ln(1 + 1e-30) = 1e-30 - 5e-61 + ...
+    let x = big("1.000000000000000000000000000001").log();
+    assert!(
+        x.to_string()
+            .starts_with("9.999999999999999999999999999995000000000000000000000000000003333"),
+        "{}",
+        x
+    );
+    assert_eq!(big("1e-40").sin(), big("1e-40"));
+}
+
+#[test]
+fn keeps_precision_below_f64_range() {
+    Number::set_default_precision(256);
+    let tiny = big("1e-400");
+    assert_eq!(tiny.clone().sqrt(), big("1e-200"));
+    assert_eq!(big("-1e-900").cbrt(), big("-1e-300"));
+    for result in [
+        tiny.clone().sin(),
+        tiny.clone().tan(),
+        tiny.clone().atan(),
+        tiny.clone().asin(),
+        tiny.clone().sinh(),
+        tiny.clone().tanh(),
+        tiny.clone().asinh(),
+        tiny.clone().atanh(),
+        tiny.clone().atan2(Number::from(1)),
+    ] {
+        assert_eq!(result, tiny);
+    }
+}
+
+#[test]
+fn reduces_large_arguments() {
+    Number::set_default_precision(256);
+    // sin(10^22) = -0.8522008497671888017727...
+    let x = big("1e22").sin();
+    assert!(
+        x.to_string().starts_with("-0.852200849767188801772"),
+        "{}",
+        x
+    );
+    let tiny = Number::from(-50).exp();
+    assert!(
+        tiny.to_string()
+            .starts_with("1.928749847963917783017342816527")
+    );
+}
+
+#[test]
+fn hyperbolic_functions() {
+    Number::set_default_precision(256);
+    let cases = [
+        (
+            Number::from(1).sinh(),
+            "1.17520119364380145688238185059560081515571798133",
+        ),
+        (
+            big("0.5").tanh(),
+            "0.46211715726000975850231848364367254873028928033",
+        ),
+        (
+            Number::from(2).acosh(),
+            "1.31695789692481670862504634730796844402698197146",
+        ),
+        (
+            big("-0.25").atanh(),
+            "-0.25541281188299534160275704815183096743905539822",
+        ),
+    ];
+    for (x, expected) in cases {
+        assert!(x.to_string().starts_with(expected), "{} vs {}", x, expected);
+    }
+    assert_eq!(big("1e-40").sinh(), big("1e-40"));
+}
+
+#[test]
+fn small_argument_functions() {
+    Number::set_default_precision(256);
+    assert!(
+        big("1e-20")
+            .expm1()
+            .to_string()
+            .starts_with("1.00000000000000000000500000000000000000001666666666666666666670833")
+    );
+    assert!(
+        big("1e-20")
+            .log1p()
+            .to_string()
+            .starts_with("9.99999999999999999995000000000000000000033333333333333333333083")
+    );
+    assert!(
+        Number::from(1)
+            .hypot(Number::from(1))
+            .to_string()
+            .starts_with("1.41421356237309504880168872420969807856967187537694807317667973799")
+    );
+}
+
+#[test]
+fn gamma_at_half_integers() {
+    Number::set_default_precision(256);
+    let half = Number::from(1) / Number::from(2);
+    assert!(
+        half.clone()
+            .gamma()
+            .to_string()
+            .starts_with("1.77245385090551602729816748334114518279754945612238712821380")
+    );
+    // Γ(-1/2) = -2√π
+    assert!(
+        (half - Number::from(1))
+            .gamma()
+            .to_string()
+            .starts_with("-3.5449077018110320545963349666822903655950989122447742564276")
+    );
+}
+
+#[test]
+fn constants_follow_precision() {
+    use faithful_number::Constant;
+
+    Number::set_default_precision(256);
+    assert!(Number::PI().to_string().starts_with(PI_60));
+    assert!(Number::E().to_string().starts_with(E_60));
+    assert!(Number::LN2().to_string().starts_with(LN2_60));
+
+    // Fewer digits at a lower precision, from a separate cache entry
+    Number::set_default_precision(100);
+    assert_eq!(Number::PI().to_string(), "3.14159265358979323846264338328");
+    Number::set_default_precision(256);
+    assert!(Number::PI().to_string().starts_with(PI_60));
+
+    let pi = Number::constant_with_digits(Constant::Pi, 100);
+    assert_eq!(
+        pi.to_string(),
+        "3.141592653589793238462643383279502884197169399375105820974944592307816406286208998628034825342117068"
+    );
+}
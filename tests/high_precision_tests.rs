@@ -1,4 +1,4 @@
-#![cfg(feature = "high_precision")]
+#![cfg(any(feature = "high_precision", feature = "high_precision_pure"))]
 
 use faithful_number::Number;
 use std::str::FromStr;
@@ -198,10 +198,13 @@ fn test_high_precision_log10() {
     let ten = Number::from(10);
     let log10_10 = ten.log10();
 
-    // log10(10) = 1 exactly
-    let result = log10_10.to_f64();
-    assert!((result - 1.0).abs() < 1e-15);
-    assert_eq!(log10_10.representation(), "BigDecimal");
+    // log10(10) = 1 exactly, without going through the high-precision path
+    assert_eq!(log10_10, Number::from(1));
+    assert_eq!(log10_10.representation(), "Rational");
+
+    let log10_2 = Number::from(2).log10();
+    assert!((log10_2.to_f64() - 2.0_f64.log10()).abs() < 1e-15);
+    assert_eq!(log10_2.representation(), "BigDecimal");
 }
 
 #[test]
@@ -211,10 +214,13 @@ fn test_high_precision_log2() {
     let eight = Number::from(8);
     let log2_8 = eight.log2();
 
-    // log2(8) = 3 exactly
-    let result = log2_8.to_f64();
-    assert!((result - 3.0).abs() < 1e-15);
-    assert_eq!(log2_8.representation(), "BigDecimal");
+    // log2(8) = 3 exactly, without going through the high-precision path
+    assert_eq!(log2_8, Number::from(3));
+    assert_eq!(log2_8.representation(), "Rational");
+
+    let log2_3 = Number::from(3).log2();
+    assert!((log2_3.to_f64() - 3.0_f64.log2()).abs() < 1e-15);
+    assert_eq!(log2_3.representation(), "BigDecimal");
 }
 
 #[test]
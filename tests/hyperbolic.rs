@@ -0,0 +1,92 @@
+//! Hyperbolic functions and their inverses.
+
+mod common;
+use common::rational;
+use faithful_number::{Number, Repr};
+
+type UnaryFn = fn(Number) -> Number;
+
+#[test]
+fn exact_points() {
+    let cases: Vec<(&str, UnaryFn, Number, Number)> = vec![
+        ("sinh(0)", Number::sinh, Number::from(0), Number::from(0)),
+        ("cosh(0)", Number::cosh, Number::from(0), Number::from(1)),
+        ("tanh(0)", Number::tanh, Number::from(0), Number::from(0)),
+        ("asinh(0)", Number::asinh, Number::from(0), Number::from(0)),
+        ("acosh(1)", Number::acosh, Number::from(1), Number::from(0)),
+        ("atanh(0)", Number::atanh, Number::from(0), Number::from(0)),
+    ];
+
+    for (name, f, input, expected) in cases {
+        let result = f(input);
+        assert_eq!(result, expected, "{}", name);
+        assert!(result.is_exact(), "{} should be exact", name);
+        assert_eq!(result.representation(), Repr::Rational, "{}", name);
+    }
+}
+
+#[test]
+fn special_values() {
+    let neg_zero = Number::neg_zero();
+    assert!(neg_zero.clone().sinh().is_neg_zero());
+    assert!(neg_zero.clone().tanh().is_neg_zero());
+    assert!(neg_zero.clone().asinh().is_neg_zero());
+    assert!(neg_zero.clone().atanh().is_neg_zero());
+    assert_eq!(neg_zero.clone().cosh(), Number::from(1));
+    assert!(neg_zero.acosh().is_nan());
+
+    assert_eq!(Number::POSITIVE_INFINITY.sinh(), Number::POSITIVE_INFINITY);
+    assert_eq!(Number::NEGATIVE_INFINITY.sinh(), Number::NEGATIVE_INFINITY);
+    assert_eq!(Number::NEGATIVE_INFINITY.cosh(), Number::POSITIVE_INFINITY);
+    assert_eq!(Number::POSITIVE_INFINITY.tanh(), Number::from(1));
+    assert_eq!(Number::NEGATIVE_INFINITY.tanh(), Number::from(-1));
+    assert_eq!(Number::NEGATIVE_INFINITY.asinh(), Number::NEGATIVE_INFINITY);
+    assert_eq!(Number::POSITIVE_INFINITY.acosh(), Number::POSITIVE_INFINITY);
+    assert!(Number::POSITIVE_INFINITY.atanh().is_nan());
+
+    // Domain edges
+    assert!(rational(1, 2).acosh().is_nan());
+    assert!(Number::from(-1).acosh().is_nan());
+    assert!(Number::from(2).atanh().is_nan());
+    assert_eq!(Number::from(1).atanh(), Number::POSITIVE_INFINITY);
+    assert_eq!(Number::from(-1).atanh(), Number::NEGATIVE_INFINITY);
+    assert!(Number::NAN.sinh().is_nan());
+}
+
+#[test]
+fn irrational_points_are_transcendental() {
+    let cases: Vec<(&str, UnaryFn, Number, f64)> = vec![
+        ("sinh(1)", Number::sinh, Number::from(1), 1f64.sinh()),
+        ("cosh(-2)", Number::cosh, Number::from(-2), 2f64.cosh()),
+        ("tanh(1/2)", Number::tanh, rational(1, 2), 0.5f64.tanh()),
+        ("asinh(3)", Number::asinh, Number::from(3), 3f64.asinh()),
+        ("acosh(2)", Number::acosh, Number::from(2), 2f64.acosh()),
+        (
+            "atanh(-1/3)",
+            Number::atanh,
+            rational(-1, 3),
+            (-1f64 / 3.0).atanh(),
+        ),
+    ];
+
+    for (name, f, input, expected) in cases {
+        let result = f(input);
+        assert!(result.is_transcendental(), "{} should be approximate", name);
+        let actual = result.to_f64();
+        assert!(
+            (actual - expected).abs() <= 4.0 * f64::EPSILON * expected.abs(),
+            "{}: {} vs {}",
+            name,
+            actual,
+            expected
+        );
+    }
+}
+
+#[test]
+fn inverses_round_trip() {
+    let x = rational(3, 4);
+    assert!((x.clone().sinh().asinh().to_f64() - 0.75).abs() < 1e-15);
+    assert!((x.clone().tanh().atanh().to_f64() - 0.75).abs() < 1e-15);
+    assert!((x.clone().cosh().acosh().to_f64() - 0.75).abs() < 1e-15);
+}
@@ -0,0 +1,162 @@
+//! div_euclid, rem_euclid, div_floor, mod_floor, div_trunc and num_traits::Euclid.
+
+mod common;
+use common::{big, rational};
+use faithful_number::Number;
+use num_traits::Euclid;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+#[test]
+fn signs_match_integer_semantics() {
+    // (a, b, div_euclid, rem_euclid, div_floor, mod_floor, div_trunc)
+    let cases = [
+        (7, 2, 3, 1, 3, 1, 3),
+        (-7, 2, -4, 1, -4, 1, -3),
+        (7, -2, -3, 1, -4, -1, -3),
+        (-7, -2, 4, 1, 3, -1, 3),
+        (6, -3, -2, 0, -2, 0, -2),
+    ];
+    for (a, b, de, re, df, mf, dt) in cases {
+        let (x, y) = (Number::from(a), Number::from(b));
+        assert_eq!(
+            x.clone().div_euclid(y.clone()),
+            Number::from(de),
+            "{a} div_euclid {b}"
+        );
+        assert_eq!(
+            x.clone().rem_euclid(y.clone()),
+            Number::from(re),
+            "{a} rem_euclid {b}"
+        );
+        assert_eq!(
+            x.clone().div_floor(y.clone()),
+            Number::from(df),
+            "{a} div_floor {b}"
+        );
+        assert_eq!(
+            x.clone().mod_floor(y.clone()),
+            Number::from(mf),
+            "{a} mod_floor {b}"
+        );
+        assert_eq!(
+            x.clone().div_trunc(y.clone()),
+            Number::from(dt),
+            "{a} div_trunc {b}"
+        );
+
+        // The primitive integers agree
+        assert_eq!(i64::div_euclid(a, b), de);
+        assert_eq!(i64::rem_euclid(a, b), re);
+        assert_eq!(a / b, dt);
+        // Quotient and remainder recombine
+        assert_eq!(Number::from(df) * y.clone() + Number::from(mf), x);
+        assert_eq!(Number::from(dt) * y + x.clone() % Number::from(b), x);
+    }
+}
+
+#[test]
+fn exact_in_every_tier() {
+    // 7/2 = 10.5 thirds
+    let a = rational(7, 2);
+    let third = rational(1, 3);
+    assert_eq!(a.clone().div_floor(third.clone()), Number::from(10));
+    assert_eq!(a.clone().mod_floor(third.clone()), rational(1, 6));
+    assert_eq!((-a.clone()).mod_floor(third.clone()), rational(1, 6));
+    assert_eq!((-a).rem_euclid(-third), rational(1, 6));
+
+    let d = Number::from_decimal(Decimal::from_str("-1.2345678901234567890123456789").unwrap());
+    let tenth = Number::from_decimal(Decimal::from_str("0.1").unwrap());
+    assert_eq!(d.clone().div_floor(tenth.clone()), Number::from(-13));
+    let r = d.rem_euclid(tenth);
+    assert_eq!(r.to_string(), "0.0654321098765432109876543211");
+    assert!(r.is_exact());
+
+    let n = big("123456789012345678901234567890123");
+    let m = big("1000000000000000000000");
+    assert_eq!(
+        n.clone().div_trunc(m.clone()),
+        Number::from(123_456_789_012i64)
+    );
+    assert_eq!(
+        (-n.clone()).div_euclid(m.clone()),
+        Number::from(-123_456_789_013i64)
+    );
+    assert_eq!(n.mod_floor(-m), big("-654321098765432109877"));
+}
+
+#[test]
+fn special_values() {
+    let five = Number::from(5);
+
+    // Division by zero follows `/`; remainders are NaN
+    assert_eq!(
+        five.clone().div_floor(Number::from(0)),
+        Number::POSITIVE_INFINITY
+    );
+    assert_eq!(
+        five.clone().div_euclid(Number::neg_zero()),
+        Number::NEGATIVE_INFINITY
+    );
+    assert!(Number::from(0).div_trunc(Number::from(0)).is_nan());
+    assert!(five.clone().rem_euclid(Number::from(0)).is_nan());
+    assert!(five.clone().mod_floor(Number::neg_zero()).is_nan());
+
+    // Signed zero dividends
+    assert!(Number::neg_zero().div_floor(five.clone()).is_neg_zero());
+    assert!(Number::neg_zero().rem_euclid(five.clone()).is_neg_zero());
+    assert!(!Number::neg_zero().div_trunc(-five.clone()).is_neg_zero());
+
+    // Infinite dividends
+    assert_eq!(
+        Number::POSITIVE_INFINITY.div_euclid(-five.clone()),
+        Number::NEGATIVE_INFINITY
+    );
+    assert!(Number::POSITIVE_INFINITY.rem_euclid(five.clone()).is_nan());
+    assert!(
+        Number::POSITIVE_INFINITY
+            .div_floor(Number::NEGATIVE_INFINITY)
+            .is_nan()
+    );
+
+    // Infinite divisors, as f64 and Python do it
+    let inf = Number::POSITIVE_INFINITY;
+    assert!(five.clone().div_floor(inf.clone()).is_zero());
+    assert_eq!(five.clone().mod_floor(inf.clone()), five);
+    assert_eq!((-five.clone()).div_floor(inf.clone()), Number::from(-1));
+    assert_eq!((-five.clone()).mod_floor(inf.clone()), inf);
+    assert_eq!((-five.clone()).div_euclid(inf.clone()), Number::from(-1));
+    assert_eq!((-five.clone()).rem_euclid(inf.clone()), inf);
+    assert_eq!(
+        (-five.clone()).div_euclid(Number::NEGATIVE_INFINITY),
+        Number::from(1)
+    );
+    assert!((-five.clone()).div_trunc(inf.clone()).is_zero());
+    assert_eq!(f64::div_euclid(-5.0, f64::INFINITY), -1.0);
+    assert_eq!(f64::rem_euclid(-5.0, f64::INFINITY), f64::INFINITY);
+
+    assert!(Number::NAN.div_floor(five.clone()).is_nan());
+    assert!(five.mod_floor(Number::NAN).is_nan());
+}
+
+#[test]
+fn euclid_trait() {
+    fn wrap<T: Euclid>(x: &T, n: &T) -> T {
+        x.rem_euclid(n)
+    }
+    assert_eq!(wrap(&Number::from(-1), &Number::from(24)), Number::from(23));
+    assert_eq!(
+        Euclid::div_euclid(&rational(-1, 2), &Number::from(1)),
+        Number::from(-1)
+    );
+}
+
+#[test]
+fn approximate_operands_stay_flagged() {
+    let root2 = Number::from(2).sqrt();
+    let q = Number::from(10).div_floor(root2.clone());
+    assert_eq!(q, Number::from(7));
+    assert!(q.is_transcendental());
+    assert!(Number::from(10).rem_euclid(root2).is_transcendental());
+    assert!(Number::from(10).div_floor(Number::from(3)).is_exact());
+}
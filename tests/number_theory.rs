@@ -0,0 +1,143 @@
+//! gcd, lcm, mod_pow, isqrt, is_integer and is_probable_prime across representations.
+
+mod common;
+use common::{big, rational};
+use faithful_number::{
+    ApproximationPolicy, ArithmeticContext, Number, Repr, take_approximation_flags,
+};
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+#[test]
+fn is_integer_in_every_tier() {
+    assert!(Number::from(7).is_integer());
+    assert!(Number::neg_zero().is_integer());
+    assert!(Number::from_decimal(Decimal::from_str("12.000").unwrap()).is_integer());
+    assert!(big("123456789012345678901234567890123456789").is_integer());
+    assert!(!rational(1, 3).is_integer());
+    assert!(!big("123456789012345678901234567890.5").is_integer());
+    assert!(!Number::NAN.is_integer());
+    assert!(!Number::POSITIVE_INFINITY.is_integer());
+}
+
+#[test]
+fn gcd_and_lcm() {
+    assert_eq!(Number::from(12).gcd(Number::from(-18)), Number::from(6));
+    assert_eq!(Number::from(12).lcm(Number::from(-18)), Number::from(36));
+    assert_eq!(Number::from(0).gcd(Number::from(5)), Number::from(5));
+    assert_eq!(Number::from(0).lcm(Number::from(5)), Number::from(0));
+
+    // Rationals: gcd of numerators over lcm of denominators
+    assert_eq!(rational(1, 2).gcd(rational(3, 4)), rational(1, 4));
+    assert_eq!(rational(1, 2).lcm(rational(3, 4)), rational(3, 2));
+    assert_eq!(rational(2, 3).gcd(Number::from(1)), rational(1, 3));
+
+    // Beyond i64
+    let a = big("340282366920938463463374607431768211456"); // 2^128
+    let b = big("1020847100762815390390123822295304634368"); // 3 * 2^128
+    let g = a.clone().gcd(b.clone());
+    assert_eq!(g, a);
+    assert_eq!(g.representation(), Repr::BigDecimal);
+    assert_eq!(a.lcm(b.clone()), b);
+
+    assert!(Number::NAN.gcd(Number::from(3)).is_nan());
+    assert!(Number::POSITIVE_INFINITY.lcm(Number::from(3)).is_nan());
+}
+
+#[test]
+fn mod_pow_matches_big_exponents() {
+    assert_eq!(
+        Number::from(4).mod_pow(Number::from(13), Number::from(497)),
+        Number::from(445)
+    );
+    assert_eq!(
+        Number::from(-2).mod_pow(Number::from(3), Number::from(5)),
+        Number::from(2)
+    );
+    assert_eq!(
+        Number::from(2).mod_pow(Number::from(0), Number::from(7)),
+        Number::from(1)
+    );
+
+    // Fermat: 2^(p-1) ≡ 1 mod p for the Mersenne prime 2^127 - 1
+    let p = big("170141183460469231731687303715884105727");
+    let p_minus_1 = big("170141183460469231731687303715884105726");
+    assert_eq!(Number::from(2).mod_pow(p_minus_1, p), Number::from(1));
+
+    assert!(
+        Number::from(2)
+            .mod_pow(Number::from(-1), Number::from(7))
+            .is_nan()
+    );
+    assert!(
+        Number::from(2)
+            .mod_pow(Number::from(3), Number::from(0))
+            .is_nan()
+    );
+    assert!(
+        rational(1, 2)
+            .mod_pow(Number::from(3), Number::from(7))
+            .is_nan()
+    );
+}
+
+#[test]
+fn isqrt_floors() {
+    assert_eq!(Number::from(0).isqrt(), Number::from(0));
+    assert_eq!(Number::from(15).isqrt(), Number::from(3));
+    assert_eq!(Number::from(16).isqrt(), Number::from(4));
+    assert_eq!(
+        big("100000000000000000000000000000000000000000").isqrt(),
+        big("316227766016837933199")
+    );
+    assert!(Number::from(-4).isqrt().is_nan());
+    assert!(rational(9, 4).isqrt().is_nan());
+    assert!(Number::from(17).isqrt().is_exact());
+}
+
+#[test]
+fn probable_primes() {
+    let primes: Vec<i64> = (0..100)
+        .filter(|&n| Number::from(n).is_probable_prime())
+        .collect();
+    assert_eq!(
+        primes,
+        vec![
+            2, 3, 5, 7, 11, 13, 17, 19, 23, 29, 31, 37, 41, 43, 47, 53, 59, 61, 67, 71, 73, 79, 83,
+            89, 97
+        ]
+    );
+
+    // Strong pseudoprime to every prime base up to 23
+    assert!(!big("3825123056546413051").is_probable_prime());
+    // Carmichael number
+    assert!(!Number::from(561).is_probable_prime());
+    assert!(big("170141183460469231731687303715884105727").is_probable_prime());
+    assert!(!big("170141183460469231731687303715884105729").is_probable_prime());
+
+    assert!(!Number::from(-7).is_probable_prime());
+    assert!(!rational(7, 2).is_probable_prime());
+}
+
+#[test]
+fn approximate_operands_stay_flagged() {
+    let root2 = Number::from(2).sqrt();
+    let g = root2.clone().gcd(Number::from(1));
+    assert!(g.is_transcendental());
+    assert!(g > Number::from(0) && g < Number::from(1));
+    assert!(root2.isqrt().is_nan());
+}
+
+#[test]
+fn approximate_modulus_is_recorded() {
+    let guard = ArithmeticContext::uniform(ApproximationPolicy::Flag).enter();
+    let root2 = Number::from(2).sqrt();
+    let _ = take_approximation_flags();
+    let result = Number::from(3).mod_pow(Number::from(2), root2.clone());
+    assert!(result.is_transcendental());
+
+    let first = guard.flags().first.unwrap();
+    assert_eq!(first.op, "mod_pow");
+    assert_eq!(*first.lhs, Number::from(3));
+    assert_eq!(first.rhs.as_deref(), Some(&root2));
+}
@@ -91,3 +91,82 @@ fn negative_zero_arithmetic_boundaries() {
     let result = neg_zero + large.clone();
     assert_eq!(result, large);
 }
+
+// ============================================================================
+// i128 FAST PATH: cross-multiplication overflows, reduced result fits
+// ============================================================================
+
+use num_rational::Ratio;
+
+// Odd, so x/2 is already reduced; 3x overflows i64
+const X: i64 = (1 << 62) + 1;
+
+#[test]
+fn addition_with_cancelling_cross_products_stays_rational() {
+    // x/2 + y/3 with 3x + 2y = 1: lcm-scaled numerators overflow i64, the sum is 1/6.
+    // Now handled in i128 without allocating a BigRational.
+    let y = (1 - 3 * (X as i128)) / 2;
+    let a = Number::from_rational(Ratio::new(X, 2));
+    let b = Number::from_rational(Ratio::new(y as i64, 3));
+
+    let result = a + b;
+
+    assert_eq!(result.representation(), "Rational");
+    assert!(result.is_exact());
+    assert_eq!(result.to_rational64(), Some(Ratio::new(1, 6)));
+}
+
+#[test]
+fn subtraction_with_cancelling_cross_products_stays_rational() {
+    // x/2 - z/3 with 3x - 2z = 1: the difference is 1/6
+    let z = (3 * (X as i128) - 1) / 2;
+    let a = Number::from_rational(Ratio::new(X, 2));
+    let b = Number::from_rational(Ratio::new(z as i64, 3));
+
+    let result = a - b;
+
+    assert_eq!(result.representation(), "Rational");
+    assert!(result.is_exact());
+    assert_eq!(result.to_rational64(), Some(Ratio::new(1, 6)));
+}
+
+#[test]
+fn terminating_addition_with_cancelling_cross_products_stays_rational() {
+    // x/2 + y/5 with 5x + 2y = 1: the sum is 1/10. Previously promoted to Decimal.
+    let x: i64 = (1 << 61) + 1;
+    let y = (1 - 5 * (x as i128)) / 2;
+    let a = Number::from_rational(Ratio::new(x, 2));
+    let b = Number::from_rational(Ratio::new(y as i64, 5));
+
+    let result = a + b;
+
+    assert_eq!(result.representation(), "Rational");
+    assert_eq!(result.to_rational64(), Some(Ratio::new(1, 10)));
+}
+
+#[test]
+fn terminating_subtraction_with_cancelling_cross_products_stays_rational() {
+    // x/2 - z/5 with 5x - 2z = 1: the difference is 1/10.
+    // Previously detoured through Decimal before try_demote recovered it.
+    let x: i64 = (1 << 61) + 1;
+    let z = (5 * (x as i128) - 1) / 2;
+    let a = Number::from_rational(Ratio::new(x, 2));
+    let b = Number::from_rational(Ratio::new(z as i64, 5));
+
+    let result = a - b;
+
+    assert_eq!(result.representation(), "Rational");
+    assert_eq!(result.to_rational64(), Some(Ratio::new(1, 10)));
+}
+
+#[test]
+fn reduced_result_that_still_overflows_promotes() {
+    // x/2 + 1/3 = (3x + 2)/6: reduced numerator still doesn't fit in i64
+    let a = Number::from_rational(Ratio::new(X, 2));
+    let b = Number::from_rational(Ratio::new(1, 3));
+
+    let result = a + b;
+
+    assert_ne!(result.representation(), "Rational");
+    assert!(result.is_exact());
+}
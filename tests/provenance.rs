@@ -0,0 +1,71 @@
+#![cfg(feature = "provenance")]
+
+use faithful_number::{ApproximationType, Number};
+
+#[test]
+fn exact_values_have_no_provenance() {
+    let n = Number::from(1) / Number::from(3) + Number::from(16).sqrt();
+    assert!(n.explain().is_none());
+}
+
+#[test]
+fn records_the_introducing_step() {
+    let line = line!() + 1;
+    let root = Number::from(2).sqrt();
+    let origin = root.explain().unwrap();
+    assert_eq!(origin.op(), "sqrt");
+    assert_eq!(origin.kind(), &ApproximationType::Transcendental);
+    assert_eq!(origin.operands(), &[Number::from(2)]);
+    assert_eq!(origin.location().file(), file!());
+    assert_eq!(origin.location().line(), line);
+}
+
+#[test]
+fn later_steps_keep_the_first_origin() {
+    let rate = Number::from(1).exp();
+    let total = Number::from(100) * rate.clone() + Number::from(7) - rate.sin();
+    let origin = total.explain().unwrap();
+    assert_eq!(origin.op(), "exp");
+    assert_eq!(origin.operands(), &[Number::from(1)]);
+}
+
+#[test]
+fn binary_operations_record_both_operands() {
+    let a = Number::from(3);
+    let b = Number::from(2);
+    let line = line!() + 1;
+    let p = a.clone().pow(b.clone() / Number::from(4));
+    let origin = p.explain().unwrap();
+    assert_eq!(origin.op(), "pow");
+    assert_eq!(origin.operands().len(), 2);
+    assert_eq!(origin.location().line(), line);
+    assert!(
+        origin.to_string().starts_with("pow(3, 0.5) at "),
+        "{}",
+        origin
+    );
+}
+
+#[test]
+fn assignment_operators_propagate() {
+    let root = Number::from(2).sqrt();
+    let mut x = Number::from(1);
+    x += root;
+    assert_eq!(x.explain().unwrap().op(), "sqrt");
+}
+
+#[test]
+fn snapping_back_to_exact_clears_provenance() {
+    // Exactly 0 at any working precision, unlike sqrt(2)·sqrt(2)
+    let root = Number::from(2).sqrt();
+    let zero = root.clone() - root;
+    assert!(zero.is_exact());
+    assert!(zero.explain().is_none());
+}
+
+#[test]
+fn representation_changes_keep_provenance() {
+    let root = Number::from(2).sqrt();
+    assert_eq!((-root.clone()).explain().unwrap().op(), "sqrt");
+    assert_eq!(root.abs().demote().explain().unwrap().op(), "sqrt");
+}
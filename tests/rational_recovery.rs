@@ -0,0 +1,91 @@
+//! Tests for the configurable continued-fraction denominator limit.
+
+use bigdecimal::BigDecimal;
+use faithful_number::{ArithmeticContext, Number, Repr, get_arithmetic_context};
+use num_rational::Ratio;
+use std::str::FromStr;
+
+const TWO_POW_40: i64 = 1 << 40;
+
+/// 3 / 2^40 as its exact 40-digit decimal expansion
+fn three_over_two_pow_40() -> Number {
+    Number::from_bigdecimal(
+        BigDecimal::from_str("0.0000000000027284841053187847137451171875").unwrap(),
+    )
+}
+
+#[test]
+fn default_limit_is_one_billion() {
+    assert_eq!(
+        get_arithmetic_context().max_recovery_denominator,
+        1_000_000_000
+    );
+    let x = three_over_two_pow_40().recover_rational_with_limit(1_000_000_000);
+    assert_eq!(x.representation(), "BigDecimal");
+}
+
+#[test]
+fn per_call_limit_recovers_large_denominator() {
+    let x = three_over_two_pow_40().recover_rational_with_limit(TWO_POW_40);
+    assert_eq!(x.representation(), "Rational");
+    assert_eq!(x.to_rational64(), Some(Ratio::new(3, TWO_POW_40)));
+    assert!(x.is_exact());
+}
+
+#[test]
+fn context_limit_applies_to_demotion_after_arithmetic() {
+    let before = three_over_two_pow_40() * Number::from(1);
+    assert_eq!(before.representation(), "BigDecimal");
+
+    let _guard = ArithmeticContext::new()
+        .with_max_recovery_denominator(TWO_POW_40)
+        .enter();
+    let after = three_over_two_pow_40() * Number::from(1);
+    assert_eq!(after.to_rational64(), Some(Ratio::new(3, TWO_POW_40)));
+}
+
+#[test]
+fn smaller_limit_skips_recovery() {
+    let third = Number::from_bigdecimal(BigDecimal::from(1) / BigDecimal::from(3));
+    assert_eq!(
+        third.clone().recover_rational_with_limit(1_000_000_000),
+        Number::from_rational(Ratio::new(1, 3))
+    );
+    assert_eq!(
+        third.recover_rational_with_limit(2).representation(),
+        "BigDecimal"
+    );
+}
+
+#[test]
+fn huge_limit_does_not_invent_rationals() {
+    // A 28-digit Decimal doesn't carry enough digits to trust denominators above 10^9
+    let root = Number::from(2).sqrt();
+    let recovered = root.clone().recover_rational_with_limit(i64::MAX);
+    assert_eq!(recovered.representation(), root.representation());
+    assert!(recovered.is_transcendental());
+
+    let almost_third = Number::from_str("0.3333333333333333333333333334").unwrap();
+    let recovered = almost_third.clone().recover_rational_with_limit(i64::MAX);
+    assert_eq!(recovered.representation(), "Decimal");
+    assert_eq!(recovered, almost_third);
+}
+
+#[test]
+fn failed_recovery_keeps_the_representation() {
+    // Fits a Decimal, but has no rational with a small denominator
+    let digits = Number::from_str("0.1234567890123456789").unwrap();
+    let big = digits.to_repr(Repr::BigDecimal).unwrap();
+    let recovered = big.clone().recover_rational_with_limit(1_000);
+    assert_eq!(recovered.representation(), Repr::BigDecimal);
+    assert_eq!(recovered, big);
+}
+
+#[test]
+fn non_positive_limit_only_recovers_integers() {
+    let x = three_over_two_pow_40().recover_rational_with_limit(0);
+    assert_eq!(x.representation(), "BigDecimal");
+
+    let ctx = ArithmeticContext::new().with_max_recovery_denominator(-5);
+    assert_eq!(ctx.max_recovery_denominator, 1);
+}
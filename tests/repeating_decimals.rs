@@ -0,0 +1,140 @@
+//! to_exact_string and parsing of repeating decimals.
+
+mod common;
+use bigdecimal::BigDecimal;
+use bigdecimal::num_bigint::BigInt;
+use common::rational;
+use faithful_number::{Number, RepeatingNotation, Repr};
+use num_rational::BigRational;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+/// 1 / (3·10^20)
+fn big_third() -> Number {
+    Number::from_big_rational(BigRational::new(
+        BigInt::from(1),
+        BigInt::from(3) * BigInt::from(10u64).pow(20),
+    ))
+}
+
+fn exact(n: &Number) -> String {
+    n.to_exact_string().unwrap()
+}
+
+#[test]
+fn repeating_rationals() {
+    assert_eq!(exact(&rational(1, 3)), "0.(3)");
+    assert_eq!(exact(&rational(1, 6)), "0.1(6)");
+    assert_eq!(exact(&rational(1, 7)), "0.(142857)");
+    assert_eq!(exact(&rational(1, 12)), "0.08(3)");
+    assert_eq!(exact(&rational(22, 7)), "3.(142857)");
+    assert_eq!(exact(&rational(-5, 6)), "-0.8(3)");
+    assert_eq!(exact(&rational(1, 81)), "0.(012345679)");
+
+    // BigRational
+    assert_eq!(big_third().representation(), Repr::BigRational);
+    assert_eq!(exact(&big_third()), format!("0.{}(3)", "0".repeat(20)));
+}
+
+#[test]
+fn terminating_values() {
+    assert_eq!(exact(&Number::from(42)), "42");
+    assert_eq!(exact(&rational(1, 8)), "0.125");
+    assert_eq!(exact(&rational(-3, 4)), "-0.75");
+    // 2^-60 has 60 digits, more than Decimal holds
+    assert_eq!(
+        exact(&rational(1, 1 << 60)),
+        format!(
+            "0.{}867361737988403547205962240695953369140625",
+            "0".repeat(18)
+        )
+    );
+
+    let d = Number::from_decimal(Decimal::from_str("1.2345678901234567890123456780").unwrap());
+    assert_eq!(exact(&d), "1.234567890123456789012345678");
+    let bd = Number::from_bigdecimal(BigDecimal::from_str("-1.5E+40").unwrap());
+    assert_eq!(exact(&bd), format!("-15{}", "0".repeat(39)));
+    let bd =
+        Number::from_bigdecimal(BigDecimal::from_str("2.000000000000000000000000001E-27").unwrap());
+    assert_eq!(
+        exact(&bd),
+        format!("0.{}2{}1", "0".repeat(26), "0".repeat(26))
+    );
+
+    assert_eq!(exact(&Number::neg_zero()), "-0");
+    assert_eq!(exact(&Number::NAN), "NaN");
+    assert_eq!(exact(&Number::NEGATIVE_INFINITY), "-Infinity");
+}
+
+#[test]
+fn overline_notation() {
+    let s = rational(1, 6)
+        .to_exact_string_with(RepeatingNotation::Overline)
+        .unwrap();
+    assert_eq!(s, "0.16\u{305}");
+    let s = rational(-1, 7)
+        .to_exact_string_with(RepeatingNotation::Overline)
+        .unwrap();
+    assert_eq!(s, "-0.1\u{305}4\u{305}2\u{305}8\u{305}5\u{305}7\u{305}");
+    assert_eq!(Number::from_str(&s).unwrap(), rational(-1, 7));
+}
+
+#[test]
+fn parse_repeating() {
+    let cases = [
+        ("0.(3)", rational(1, 3)),
+        ("0.1(6)", rational(1, 6)),
+        ("0.(142857)", rational(1, 7)),
+        ("-2.08(3)", rational(-25, 12)),
+        ("+.(9)", Number::from(1)),
+        ("1.2(0)", rational(6, 5)),
+        ("0.3\u{305}", rational(1, 3)),
+    ];
+    for (s, expected) in cases {
+        let n = Number::from_str(s).unwrap();
+        assert_eq!(n, expected, "{}", s);
+        assert!(n.is_exact());
+    }
+    assert_eq!(
+        Number::from_str("0.(142857)").unwrap().representation(),
+        Repr::Rational
+    );
+
+    for bad in [
+        "0.(3",
+        "0.()",
+        "0.(3)4",
+        "(3)",
+        "0.(a)",
+        "0.\u{305}3",
+        "1.2\u{305}3",
+    ] {
+        assert!(Number::from_str(bad).is_err(), "{}", bad);
+    }
+}
+
+#[test]
+fn round_trips() {
+    let values = [
+        rational(1, 7),
+        rational(-355, 113),
+        rational(123_456_789, 9_999_000),
+        rational(1, 1024),
+        big_third(),
+    ];
+    for x in values {
+        for notation in [RepeatingNotation::Parentheses, RepeatingNotation::Overline] {
+            let s = x.to_exact_string_with(notation).unwrap();
+            assert_eq!(Number::from_str(&s).unwrap(), x, "{}", s);
+        }
+    }
+}
+
+#[test]
+fn very_long_periods_are_refused() {
+    // 1/1000003 repeats every 166667 digits
+    assert!(rational(1, 1_000_003).to_exact_string().is_none());
+    // 1/65537 repeats every 65536 digits
+    let s = exact(&rational(1, 65537));
+    assert_eq!(s.len(), "0.()".len() + 65536);
+}
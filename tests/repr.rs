@@ -0,0 +1,139 @@
+//! Tests for explicit representation control: Repr, to_repr and demote.
+
+use faithful_number::{ApproximationType, ExactnessErrorKind, Number, OrderedNumber, Repr};
+use num_rational::Ratio;
+use std::collections::HashSet;
+use std::str::FromStr;
+
+fn half() -> Number {
+    Number::from_rational(Ratio::new(1, 2))
+}
+
+fn third() -> Number {
+    Number::from_rational(Ratio::new(1, 3))
+}
+
+#[test]
+fn representation_is_typed() {
+    assert_eq!(Number::from(1).representation(), Repr::Rational);
+    assert_eq!(Number::NAN.representation(), Repr::NaN);
+    assert_eq!(Number::from(1).representation(), "Rational");
+    assert_eq!(Repr::BigDecimal.to_string(), "BigDecimal");
+    assert_eq!(third().info().representation, Repr::Rational);
+}
+
+#[test]
+fn exact_conversions_round_trip() {
+    for target in [
+        Repr::Decimal,
+        Repr::BigDecimal,
+        Repr::BigRational,
+        Repr::Rational,
+    ] {
+        let converted = half().to_repr(target).unwrap();
+        assert_eq!(converted.representation(), target);
+        assert_eq!(converted, half());
+        assert!(converted.is_exact());
+        assert_eq!(converted.demote().representation(), Repr::Rational);
+    }
+}
+
+#[test]
+fn non_terminating_to_decimal_is_lossy() {
+    let err = third().to_repr(Repr::Decimal).unwrap_err();
+    assert_eq!(err.op, "to_repr");
+    assert_eq!(
+        err.kind,
+        ExactnessErrorKind::Approximation(ApproximationType::RationalApproximation)
+    );
+    assert!(third().to_repr(Repr::BigDecimal).is_err());
+    assert_eq!(
+        third().to_repr(Repr::BigRational).unwrap().representation(),
+        Repr::BigRational
+    );
+}
+
+#[test]
+fn out_of_range_is_unrepresentable() {
+    let huge = Number::from_str("1e40").unwrap();
+    let err = huge.to_repr(Repr::Decimal).unwrap_err();
+    assert_eq!(err.kind, ExactnessErrorKind::Unrepresentable(Repr::Decimal));
+    assert!(huge.to_repr(Repr::Rational).is_err());
+    assert!(huge.to_repr(Repr::BigRational).is_ok());
+
+    let err = Number::NAN.to_repr(Repr::Rational).unwrap_err();
+    assert_eq!(
+        err.to_string(),
+        "to_repr(NaN) would not be representable as Rational"
+    );
+    assert!(Number::neg_zero().to_repr(Repr::Decimal).is_err());
+    assert!(Number::from(0).to_repr(Repr::NegativeZero).is_err());
+    assert!(Number::NAN.to_repr(Repr::NaN).unwrap().is_nan());
+}
+
+#[test]
+fn approximation_flags_carry_over() {
+    let root = Number::from(2).sqrt();
+    let big = root.to_repr(Repr::BigDecimal).unwrap();
+    assert!(big.is_transcendental());
+    assert_eq!(big.error_bound(), root.error_bound());
+
+    let demoted = big.demote();
+    assert_eq!(demoted, root);
+    assert!(demoted.is_transcendental());
+}
+
+#[test]
+fn converted_values_hash_like_the_original() {
+    let mut set = HashSet::new();
+    set.insert(OrderedNumber::from(half()));
+    set.insert(OrderedNumber::from(
+        half().to_repr(Repr::BigRational).unwrap(),
+    ));
+    set.insert(OrderedNumber::from(
+        half().to_repr(Repr::BigDecimal).unwrap(),
+    ));
+    set.insert(OrderedNumber::from(half().to_repr(Repr::Decimal).unwrap()));
+    assert_eq!(set.len(), 1);
+}
+
+#[test]
+fn terminating_big_rationals_hash_like_their_decimals() {
+    use std::collections::hash_map::DefaultHasher;
+    use std::hash::{Hash, Hasher};
+    let hash = |n: &Number| {
+        let mut h = DefaultHasher::new();
+        n.hash(&mut h);
+        h.finish()
+    };
+
+    // 1/2^70 is too big for Rational and terminates, so it normally lives in BigDecimal
+    let tiny = Number::from_str(&format!("1/{}", 1u128 << 70)).unwrap();
+    assert_eq!(tiny.representation(), Repr::BigDecimal);
+    let as_big_rational = tiny.to_repr(Repr::BigRational).unwrap();
+    assert_eq!(as_big_rational, tiny);
+    assert_eq!(hash(&as_big_rational), hash(&tiny));
+
+    let big_third = third().to_repr(Repr::BigRational).unwrap();
+    assert_eq!(hash(&big_third), hash(&third()));
+    assert_eq!(hash(&Number::neg_zero()), hash(&Number::from(0)));
+}
+
+#[test]
+fn terminating_big_rational_converts_to_decimal() {
+    let half = half().to_repr(Repr::BigRational).unwrap();
+    assert_eq!(half.to_decimal(), Some(rust_decimal::Decimal::new(5, 1)));
+    assert_eq!(
+        third().to_repr(Repr::BigRational).unwrap().to_decimal(),
+        None
+    );
+}
+
+#[test]
+fn zero_is_not_a_big_rational() {
+    let err = Number::from(0).to_repr(Repr::BigRational).unwrap_err();
+    assert_eq!(
+        err.kind,
+        ExactnessErrorKind::Unrepresentable(Repr::BigRational)
+    );
+}
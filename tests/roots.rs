@@ -0,0 +1,59 @@
+//! cbrt and nth_root: exact perfect powers, real odd roots, sqrt-style special values.
+
+mod common;
+use common::rational;
+use faithful_number::Number;
+
+#[test]
+fn perfect_powers_are_exact() {
+    let cases = vec![
+        (Number::from(27), 3, Number::from(3)),
+        (Number::from(-27), 3, Number::from(-3)),
+        (rational(27, 8), 3, rational(3, 2)),
+        (rational(-1, 125), 3, rational(-1, 5)),
+        (Number::from(81), 4, Number::from(3)),
+        (Number::from(1024), 10, Number::from(2)),
+        (Number::from(-32), 5, Number::from(-2)),
+        (Number::from(7), 1, Number::from(7)),
+        (Number::from(0), 7, Number::from(0)),
+    ];
+
+    for (x, n, expected) in cases {
+        let root = x.clone().nth_root(n);
+        assert_eq!(root, expected, "root {} of {}", n, x);
+        assert!(root.is_exact(), "root {} of {}", n, x);
+    }
+    assert_eq!(Number::from(-27).cbrt(), Number::from(-3));
+    assert!(Number::from(-27).cbrt().is_exact());
+}
+
+#[test]
+fn other_roots_are_transcendental() {
+    let x = Number::from(2).cbrt();
+    assert!(x.is_transcendental());
+    assert!((x.to_f64() - 2f64.cbrt()).abs() < 1e-15);
+    assert!(x.error_bound().unwrap() > 0.0);
+
+    let y = Number::from(-3).nth_root(5);
+    assert!(y.is_transcendental());
+    assert!((y.to_f64() + 3f64.powf(0.2)).abs() < 1e-15);
+}
+
+#[test]
+fn special_values_follow_sqrt() {
+    // Even roots behave like sqrt
+    assert!(Number::from(-16).nth_root(4).is_nan());
+    assert!(Number::NEGATIVE_INFINITY.nth_root(4).is_nan());
+    let zero = Number::neg_zero().nth_root(4);
+    assert!(zero.is_zero() && !zero.is_neg_zero());
+    assert_eq!(Number::from(2).nth_root(2), Number::from(2).sqrt());
+
+    // Odd roots keep the sign
+    assert!(Number::NEGATIVE_INFINITY.cbrt().is_negative_infinity());
+    assert!(Number::neg_zero().cbrt().is_neg_zero());
+    assert!(Number::POSITIVE_INFINITY.cbrt().is_positive_infinity());
+    assert!(Number::NAN.cbrt().is_nan());
+
+    // The zeroth root is undefined
+    assert!(Number::from(5).nth_root(0).is_nan());
+}
@@ -0,0 +1,359 @@
+//! round_with, round_dp_with, round_sf and round_to_increment: every RoundingMode on
+//! every representation.
+
+use bigdecimal::BigDecimal;
+use faithful_number::{Number, Repr, RoundingMode};
+use num_rational::Ratio;
+use rust_decimal::Decimal;
+use std::str::FromStr;
+
+const MODES: [RoundingMode; 9] = [
+    RoundingMode::HalfEven,
+    RoundingMode::HalfUp,
+    RoundingMode::HalfDown,
+    RoundingMode::HalfTowardZero,
+    RoundingMode::HalfAwayFromZero,
+    RoundingMode::Up,
+    RoundingMode::Down,
+    RoundingMode::Ceiling,
+    RoundingMode::Floor,
+];
+
+/// Tenths, and the expected integer under each mode in `MODES` order
+const MATRIX: [(i64, [i64; 9]); 12] = [
+    (25, [2, 3, 2, 2, 3, 3, 2, 3, 2]),
+    (-25, [-2, -2, -3, -2, -3, -3, -2, -2, -3]),
+    (15, [2, 2, 1, 1, 2, 2, 1, 2, 1]),
+    (-15, [-2, -1, -2, -1, -2, -2, -1, -1, -2]),
+    (24, [2, 2, 2, 2, 2, 3, 2, 3, 2]),
+    (-24, [-2, -2, -2, -2, -2, -3, -2, -2, -3]),
+    (26, [3, 3, 3, 3, 3, 3, 2, 3, 2]),
+    (-26, [-3, -3, -3, -3, -3, -3, -2, -2, -3]),
+    (5, [0, 1, 0, 0, 1, 1, 0, 1, 0]),
+    (-5, [0, 0, -1, 0, -1, -1, 0, 0, -1]),
+    (30, [3, 3, 3, 3, 3, 3, 3, 3, 3]),
+    (0, [0, 0, 0, 0, 0, 0, 0, 0, 0]),
+];
+
+/// `tenths / 10` as a Rational, a Decimal and a BigDecimal
+fn representations(tenths: i64) -> [Number; 3] {
+    let rational = Number::from_rational(Ratio::new(tenths, 10));
+    let decimal = Number::from_decimal(Decimal::new(tenths, 1));
+    let big = Number::from_bigdecimal(BigDecimal::new(tenths.into(), 1));
+    [rational, decimal, big]
+}
+
+#[test]
+fn round_with_matrix() {
+    for (tenths, expected) in MATRIX {
+        for x in representations(tenths) {
+            for (mode, expected) in MODES.iter().zip(expected) {
+                let rounded = x.clone().round_with(*mode);
+                assert_eq!(
+                    rounded,
+                    Number::from(expected),
+                    "{:?} of {} ({})",
+                    mode,
+                    x,
+                    x.representation()
+                );
+                assert!(rounded.is_exact());
+            }
+        }
+    }
+}
+
+#[test]
+fn round_dp_with_matrix() {
+    // The same table shifted three places right: x.xx5 to two places
+    for (tenths, expected) in MATRIX {
+        let x = Number::from_rational(Ratio::new(tenths, 1000));
+        for (mode, expected) in MODES.iter().zip(expected) {
+            let rounded = x.clone().round_dp_with(2, *mode);
+            assert_eq!(
+                rounded,
+                Number::from_rational(Ratio::new(expected, 100)),
+                "{:?} of {}",
+                mode,
+                x
+            );
+        }
+    }
+}
+
+#[test]
+fn negative_zero_is_preserved() {
+    for mode in MODES {
+        assert!(
+            Number::neg_zero().round_with(mode).is_neg_zero(),
+            "{:?}",
+            mode
+        );
+        assert!(Number::neg_zero().round_dp_with(3, mode).is_neg_zero());
+    }
+    assert!(Number::NAN.round_with(RoundingMode::Up).is_nan());
+    assert_eq!(
+        Number::NEGATIVE_INFINITY.round_dp_with(2, RoundingMode::Ceiling),
+        Number::NEGATIVE_INFINITY
+    );
+}
+
+#[test]
+fn negative_values_round_to_negative_zero() {
+    for x in representations(-1) {
+        for mode in [
+            RoundingMode::HalfEven,
+            RoundingMode::Down,
+            RoundingMode::Ceiling,
+        ] {
+            assert!(
+                x.clone().round_with(mode).is_neg_zero(),
+                "{:?} of {}",
+                mode,
+                x
+            );
+        }
+        assert!(x.clone().round_with(RoundingMode::Floor) == Number::from(-1));
+    }
+    let hundredth = Number::from_rational(Ratio::new(-1, 100));
+    assert!(
+        hundredth
+            .clone()
+            .round_dp_with(1, RoundingMode::HalfEven)
+            .is_neg_zero()
+    );
+    assert!(Number::from_str("-0.4").unwrap().round().is_neg_zero());
+    // Positive values still round to +0
+    assert!(!Number::from_str("0.4").unwrap().round().is_neg_zero());
+}
+
+#[test]
+fn rationals_round_without_losing_digits() {
+    // 2/3 to 40 places used to pass through Decimal's 28 digits
+    let x = Number::from_rational(Ratio::new(2, 3));
+    let rounded = x.clone().round_dp_with(40, RoundingMode::HalfEven);
+    assert_eq!(rounded.to_string(), format!("0.{}7", "6".repeat(39)));
+    assert!(rounded.is_exact());
+    let truncated = x.clone().round_dp_with(40, RoundingMode::Down);
+    assert_eq!(truncated.to_string(), format!("0.{}", "6".repeat(40)));
+
+    // round_dp is half-even and exact too
+    assert_eq!(x.round_dp(40), rounded);
+    assert_eq!(
+        Number::from_rational(Ratio::new(1, 8)).round_dp(2),
+        Number::from_rational(Ratio::new(3, 25))
+    );
+}
+
+#[test]
+fn decimal_tiers_keep_their_representation() {
+    let d = Number::from_decimal(Decimal::from_str("1.23456789012345678901234567").unwrap());
+    let rounded = d.round_dp_with(5, RoundingMode::Up);
+    assert_eq!(rounded.representation(), Repr::Decimal);
+    assert_eq!(rounded.to_string(), "1.23457");
+
+    let big = Number::from_bigdecimal(
+        BigDecimal::from_str("-123456789012345678901234567890.125").unwrap(),
+    );
+    let rounded = big.clone().round_dp_with(2, RoundingMode::HalfEven);
+    assert_eq!(rounded.representation(), Repr::BigDecimal);
+    assert_eq!(rounded.to_string(), "-123456789012345678901234567890.12");
+    let rounded = big.round_dp_with(2, RoundingMode::HalfDown);
+    assert_eq!(rounded.to_string(), "-123456789012345678901234567890.13");
+
+    // Rounding up at the largest mantissa still fits in a Decimal
+    let max = Number::from_decimal(Decimal::from_str("7922816251426433759354395033.5").unwrap());
+    let rounded = max.round_with(RoundingMode::Up);
+    assert_eq!(rounded.to_string(), "7922816251426433759354395034");
+}
+
+#[test]
+fn approximate_input_rounds_to_exact_result() {
+    let root2 = Number::from(2).sqrt();
+    let rounded = root2.round_dp_with(3, RoundingMode::Floor);
+    assert_eq!(rounded, Number::from_rational(Ratio::new(1414, 1000)));
+    assert!(rounded.is_exact());
+}
+
+fn number(s: &str) -> Number {
+    Number::from_str(s).unwrap()
+}
+
+#[test]
+fn significant_figures() {
+    let n = Number::from(123_456);
+    assert_eq!(
+        n.clone().round_sf(2, RoundingMode::HalfEven),
+        Number::from(120_000)
+    );
+    assert_eq!(
+        n.clone().round_sf(2, RoundingMode::Ceiling),
+        Number::from(130_000)
+    );
+    assert_eq!(n.round_sf(9, RoundingMode::Up), Number::from(123_456));
+
+    let small = number("0.001235");
+    assert_eq!(
+        small.clone().round_sf(3, RoundingMode::HalfUp),
+        number("0.00124")
+    );
+    assert_eq!(
+        small.clone().round_sf(3, RoundingMode::HalfEven),
+        number("0.00124")
+    );
+    assert_eq!(
+        small.clone().round_sf(3, RoundingMode::HalfTowardZero),
+        number("0.00123")
+    );
+    let negative = -small;
+    assert_eq!(
+        negative.clone().round_sf(3, RoundingMode::HalfUp),
+        number("-0.00123")
+    );
+    assert_eq!(
+        negative.round_sf(3, RoundingMode::HalfDown),
+        number("-0.00124")
+    );
+
+    // Carrying into the next power of ten
+    assert_eq!(
+        number("9.99").round_sf(2, RoundingMode::HalfEven),
+        Number::from(10)
+    );
+
+    // Rationals keep every requested digit
+    let third = Number::from_rational(Ratio::new(2, 3)).round_sf(40, RoundingMode::HalfEven);
+    assert_eq!(third.to_string(), format!("0.{}7", "6".repeat(39)));
+    assert!(third.is_exact());
+
+    assert!(Number::from(5).round_sf(0, RoundingMode::HalfEven).is_nan());
+    assert!(
+        Number::neg_zero()
+            .round_sf(3, RoundingMode::Up)
+            .is_neg_zero()
+    );
+    assert_eq!(
+        Number::POSITIVE_INFINITY.round_sf(3, RoundingMode::Up),
+        Number::POSITIVE_INFINITY
+    );
+}
+
+#[test]
+fn significant_figures_in_decimal_tiers() {
+    let d = Number::from_decimal(Decimal::from_str("98765.43210987654321098765432").unwrap());
+    let rounded = d.round_sf(3, RoundingMode::HalfEven);
+    assert_eq!(rounded.representation(), Repr::Decimal);
+    assert_eq!(rounded, Number::from(98_800));
+
+    // Rounding up Decimal::MAX to one digit moves past Decimal's range
+    let max = Number::from_decimal(Decimal::MAX).round_sf(1, RoundingMode::Up);
+    assert_eq!(max, number("80000000000000000000000000000"));
+
+    let big =
+        Number::from_bigdecimal(BigDecimal::from_str("123456789012345678901234567890.5").unwrap());
+    let rounded = big.round_sf(30, RoundingMode::HalfEven);
+    assert_eq!(rounded.representation(), Repr::BigDecimal);
+    assert_eq!(rounded.to_string(), "123456789012345678901234567890");
+}
+
+#[test]
+fn cash_and_lot_increments() {
+    let nickel = number("0.05");
+    let cases = [
+        ("12.43", RoundingMode::HalfAwayFromZero, "12.45"),
+        ("12.425", RoundingMode::HalfEven, "12.4"),
+        ("12.425", RoundingMode::HalfUp, "12.45"),
+        ("-12.425", RoundingMode::HalfUp, "-12.4"),
+        ("-12.425", RoundingMode::HalfAwayFromZero, "-12.45"),
+        ("12.41", RoundingMode::Ceiling, "12.45"),
+        ("-12.41", RoundingMode::Floor, "-12.45"),
+    ];
+    for (x, mode, expected) in cases {
+        let rounded = number(x).round_to_increment(nickel.clone(), mode);
+        assert_eq!(rounded, number(expected), "{} {:?}", x, mode);
+        assert!(rounded.is_exact());
+    }
+
+    let quarter = Number::from_rational(Ratio::new(1, 4));
+    assert_eq!(
+        number("1.3").round_to_increment(quarter.clone(), RoundingMode::Floor),
+        number("1.25")
+    );
+    // The sign of the step does not matter
+    assert_eq!(
+        number("1.3").round_to_increment(-quarter, RoundingMode::Up),
+        number("1.5")
+    );
+}
+
+#[test]
+fn increments_that_are_not_powers_of_ten() {
+    let third = Number::from_rational(Ratio::new(1, 3));
+    let rounded = number("0.5").round_to_increment(third.clone(), RoundingMode::HalfEven);
+    assert_eq!(rounded, Number::from_rational(Ratio::new(2, 3)));
+    assert!(rounded.is_exact());
+
+    let rounded = number("-0.5").round_to_increment(third, RoundingMode::HalfTowardZero);
+    assert_eq!(rounded, Number::from_rational(Ratio::new(-1, 3)));
+}
+
+#[test]
+fn increments_of_exact_inputs_are_exact() {
+    for step in [number("0.05"), Number::from_rational(Ratio::new(1, 3))] {
+        let rounded = number("0.26").round_to_increment(step.clone(), RoundingMode::Up);
+        assert!(rounded.is_exact(), "step {}", step);
+        assert_eq!(rounded.error_bound(), Some(0.0));
+    }
+    // A negative value that rounds to zero keeps its sign, as in round_with
+    let rounded = number("-0.01").round_to_increment(number("0.05"), RoundingMode::HalfEven);
+    assert!(rounded.is_neg_zero());
+    assert!(rounded.is_exact());
+    let rounded = number("0.01").round_to_increment(number("0.05"), RoundingMode::HalfEven);
+    assert!(!rounded.is_neg_zero());
+}
+
+#[test]
+fn increment_special_values() {
+    let step = number("0.25");
+    assert!(
+        Number::from(1)
+            .round_to_increment(Number::from(0), RoundingMode::HalfEven)
+            .is_nan()
+    );
+    assert!(
+        Number::from(1)
+            .round_to_increment(Number::POSITIVE_INFINITY, RoundingMode::HalfEven)
+            .is_nan()
+    );
+    assert!(
+        Number::NAN
+            .round_to_increment(step.clone(), RoundingMode::Up)
+            .is_nan()
+    );
+    assert_eq!(
+        Number::NEGATIVE_INFINITY.round_to_increment(step.clone(), RoundingMode::Up),
+        Number::NEGATIVE_INFINITY
+    );
+    assert!(
+        Number::neg_zero()
+            .round_to_increment(step, RoundingMode::Floor)
+            .is_neg_zero()
+    );
+}
+
+#[test]
+fn increment_flags_follow_the_step() {
+    // An approximate value rounded to an exact step is exact
+    let rounded = Number::from(2)
+        .sqrt()
+        .round_to_increment(number("0.25"), RoundingMode::HalfEven);
+    assert_eq!(rounded, number("1.5"));
+    assert!(rounded.is_exact());
+
+    // A multiple of an approximate step is as approximate as the step
+    let root2 = Number::from(2).sqrt();
+    let rounded = Number::from(3).round_to_increment(root2.clone(), RoundingMode::HalfEven);
+    assert!(rounded.is_transcendental());
+    assert_eq!(rounded, root2.clone() * Number::from(2));
+    assert!(rounded.error_bound().unwrap() >= root2.error_bound().unwrap());
+}
@@ -1,6 +1,7 @@
 //! Adversarial tests for string parsing and display.
 
-use faithful_number::Number;
+use faithful_number::{Number, ParseNumberError};
+use num_traits::Num;
 use std::str::FromStr;
 
 #[test]
@@ -97,7 +98,7 @@ fn parse_invalid_returns_error() {
 #[test]
 #[cfg(not(feature = "js_string_parse"))]
 fn empty_string_is_error_default() {
-    assert!(Number::from_str("").is_err());
+    assert_eq!(Number::from_str(""), Err(ParseNumberError::Empty));
 }
 
 #[test]
@@ -114,3 +115,146 @@ fn display_special_values() {
     // -0 displays as "0" per convention
     assert_eq!(Number::neg_zero().to_string(), "0");
 }
+
+#[test]
+fn parse_fractions_exactly() {
+    let ratio = |n: i64, d: i64| Number::from(n) / Number::from(d);
+    let cases = [
+        ("1/3", ratio(1, 3)),
+        ("-1/3", ratio(-1, 3)),
+        ("+22/7", ratio(22, 7)),
+        ("6/4", ratio(3, 2)),
+        ("2 1/2", ratio(5, 2)),
+        ("-2 1/2", ratio(-5, 2)),
+        ("1.5/2.25", ratio(2, 3)),
+        (".5/3", ratio(1, 6)),
+    ];
+    for (s, expected) in cases {
+        let n = Number::from_str(s).unwrap();
+        assert_eq!(n, expected, "{}", s);
+        assert!(n.is_exact(), "{}", s);
+        assert_eq!(n.representation(), "Rational", "{}", s);
+    }
+
+    // Past i64 the value stays exact in a bigger tier
+    let big = Number::from_str("1/30000000000000000000").unwrap();
+    assert!(big.is_exact());
+    assert_eq!(
+        big * Number::from(30),
+        Number::from_str("1/1000000000000000000").unwrap()
+    );
+    assert_eq!(
+        Number::from_str("99999999999999999999/3").unwrap(),
+        Number::from_str("33333333333333333333").unwrap()
+    );
+}
+
+#[test]
+fn parse_fraction_sign_applies_to_the_numerator() {
+    // As Number::neg_zero() / 5 is -0
+    assert!((Number::neg_zero() / Number::from(5)).is_neg_zero());
+    assert!(Number::from_str("-0/5").unwrap().is_neg_zero());
+    assert!(Number::from_str("-0 0/5").unwrap().is_neg_zero());
+    assert!(!Number::from_str("0/5").unwrap().is_neg_zero());
+    assert!(Number::from_str("-0/0").unwrap().is_nan());
+}
+
+#[test]
+fn parse_unicode_fractions() {
+    let ratio = |n: i64, d: i64| Number::from(n) / Number::from(d);
+    assert_eq!(Number::from_str("1⅓").unwrap(), ratio(4, 3));
+    assert_eq!(Number::from_str("-2 ½").unwrap(), ratio(-5, 2));
+    assert_eq!(Number::from_str("1¹⁷⁄₃₂").unwrap(), ratio(49, 32));
+    assert_eq!(
+        Number::from_str("1⁄₂"),
+        Err(ParseNumberError::InvalidDigit { pos: 1, ch: '⁄' })
+    );
+    assert_eq!(
+        Number::from_str("x½"),
+        Err(ParseNumberError::InvalidDigit { pos: 0, ch: 'x' })
+    );
+}
+
+#[test]
+fn parse_fraction_division_by_zero_matches_div() {
+    assert_eq!(Number::from_str("1/0").unwrap(), Number::POSITIVE_INFINITY);
+    assert_eq!(Number::from_str("-1/0").unwrap(), Number::NEGATIVE_INFINITY);
+    assert_eq!(
+        Number::from_str("2 1/0").unwrap(),
+        Number::POSITIVE_INFINITY
+    );
+    assert!(Number::from_str("0/0").unwrap().is_nan());
+    assert!(Number::from_str("0/0.0").unwrap().is_nan());
+    assert!((Number::from(0) / Number::from(0)).is_nan());
+}
+
+#[test]
+fn parse_fraction_rejects_malformed_input() {
+    for bad in [
+        "/3",
+        "1/",
+        "1//3",
+        "1/-3",
+        "1/2/3",
+        "1/ 3",
+        "1.5 1/2",
+        "1 -1/2",
+        "1e3/2",
+        "NaN/2",
+        "1/Infinity",
+        "1..5/2",
+    ] {
+        assert!(Number::from_str(bad).is_err(), "{}", bad);
+    }
+}
+
+#[test]
+fn parse_errors_point_at_the_problem() {
+    let invalid = |pos, ch| Err(ParseNumberError::InvalidDigit { pos, ch });
+    assert_eq!(Number::from_str("12.34.56"), invalid(5, '.'));
+    assert_eq!(Number::from_str("abc123"), invalid(0, 'a'));
+    assert_eq!(Number::from_str("1e5x"), invalid(3, 'x'));
+    assert_eq!(Number::from_str("1-2"), invalid(1, '-'));
+    assert_eq!(Number::from_str("-"), invalid(0, '-'));
+    assert_eq!(Number::from_str("1e"), invalid(1, 'e'));
+    assert_eq!(Number::from_str("0.(3"), invalid(2, '('));
+
+    // Fractions
+    assert_eq!(Number::from_str("1/x"), invalid(2, 'x'));
+    assert_eq!(Number::from_str("/3"), invalid(0, '/'));
+    assert_eq!(Number::from_str("1/"), invalid(1, '/'));
+    assert_eq!(Number::from_str("1 -1/2"), invalid(2, '-'));
+    assert_eq!(Number::from_str("1.5 1/2"), invalid(1, '.'));
+
+    let err = Number::from_str("12a").unwrap_err();
+    assert_eq!(err.to_string(), "invalid digit 'a' at position 2");
+    let boxed: Box<dyn std::error::Error> = Box::new(err);
+    assert!(boxed.source().is_none());
+}
+
+#[test]
+fn from_str_radix_errors() {
+    assert_eq!(Number::from_str_radix("ff", 16), Ok(Number::from(255)));
+    assert_eq!(Number::from_str_radix(" -101 ", 2), Ok(Number::from(-5)));
+
+    assert_eq!(
+        Number::from_str_radix("12", 37),
+        Err(ParseNumberError::InvalidRadix(37))
+    );
+    assert_eq!(
+        Number::from_str_radix("  ", 10),
+        Err(ParseNumberError::Empty)
+    );
+    assert_eq!(
+        Number::from_str_radix(" 1012", 2),
+        Err(ParseNumberError::InvalidDigit { pos: 4, ch: '2' })
+    );
+    assert_eq!(
+        Number::from_str_radix("-", 10),
+        Err(ParseNumberError::InvalidDigit { pos: 0, ch: '-' })
+    );
+    assert_eq!(
+        Number::from_str_radix("8000000000000000", 16),
+        Err(ParseNumberError::Overflow)
+    );
+}